@@ -1,9 +1,49 @@
 details-developer = Entwicker: {$developer}
 details-played = Spielzeit: {$played}
 details-last-played = Zuletzt gespielt: {$last-played}
+details-sessions = Sitzungen: {$sessions} ({$per-week} pro Woche)
+details-average-session = Durchschnittliche Sitzung: {$average}
+
+details-never = Nie
+details-today = Heute
+details-yesterday = Gestern
+details-days-ago = { $days ->
+        [one] vor {$days} Tag
+       *[other] vor {$days} Tagen
+    }
+details-date-format = %d.%m.%Y
+
+details-duration-seconds = { $seconds ->
+        [one] {$seconds} Sekunde
+       *[other] {$seconds} Sekunden
+    }
+details-duration-minutes = { $minutes ->
+        [one] {$minutes} Minute
+       *[other] {$minutes} Minuten
+    }
+details-duration-hours = { $hours ->
+        [one] {$hours} Stunde
+       *[other] {$hours} Stunden
+    }
+details-duration-hours-minutes = {$hours} Std. {$minutes} Min.
 
 details-play = Spielen
 details-kill = Beenden
 details-verify = Verifizieren
 details-manage-addons = Erweiterungen verwalten
+details-game-settings = Spieleinstellungen
+details-show-launch-command = Startbefehl anzeigen
+details-open-last-log = Letztes Protokoll öffnen
 details-download = Herunterladen
+details-revert-integration = Integration zurücksetzen
+details-use-latest-integration = Neueste Integration verwenden
+details-launch-command = Startbefehl
+
+details-integration-reverted = Integration auf Version {$version} zurückgesetzt
+details-integration-revert-failed = Integration konnte nicht zurückgesetzt werden
+details-integration-unpinned = Die neueste Version der Integration wird verwendet
+details-integration-unpin-failed = Neueste Version der Integration konnte nicht verwendet werden
+details-restart-required = Starte den Launcher neu, um die Änderungen zu übernehmen
+
+details-status-unknown = Der Spielstatus ist unbekannt, da der Launcher offline ist
+details-no-logs = Noch keine Spielprotokolle
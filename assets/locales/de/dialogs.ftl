@@ -5,3 +5,4 @@ dialog-disable  = Deaktivieren
 dialog-continue = Fortsetzen
 dialog-close    = Schließen
 dialog-save     = Speichen
+dialog-copy     = Kopieren
@@ -2,6 +2,9 @@ config-property-update-failed = Aktualisierung fehlgeschalgen
 
 tasks-resolve-queued-failed = Aufgabe in der Warteschlange konnte nicht ausgeführt werden
 tasks-get-status-failed     = Das Erhalten des Status der Aufgaben für {$game-title} ist fehlgeschlagen
+tasks-discard-transition-failed = Dateien unvollendeter Aufgaben konnten nicht verworfen werden
+
+integration-verification-failed = Update der Integration {$game-name} wurde abgelehnt, da die Überprüfung fehlgeschlagen ist
 
 addon-verify-installation-failed = Das Verifizieren der Erweiterung für {$game-title} ist fehlgeschlagen
 addon-find-diff-failed           = Der Versionenpatch für die {$game-title} Erweiterng konnte nicht gefunden werden 
@@ -21,6 +24,8 @@ game-get-integrity-failed       = Integritätsinformationen für {$game-title} s
 game-get-status-failed          = Der Status von {$game-title} ist nicht verfügbar
 game-not-installed              = {$game-title} ist nicht installiert
 game-launch-failed              = {$game-title} konnte nicht gestartet werden
+game-get-launch-command-failed  = Startbefehl von {$game-title} konnte nicht ermittelt werden
+game-open-log-failed            = Protokoll von {$game-title} konnte nicht geöffnet werden
 game-kill-failed                = {$game-title} konnte nicht beendet werden
 
 launch-required-addon-unavailable = Die Erweiterung {$addon-title} der Gruppe {$group-title} ist nicht verfügbar oder veraltet. Es ist möglich das Spiel ohne dies zu starten oder mit der veralteten Version fortzufahren
@@ -29,14 +34,9 @@ loading-preparing-folders-failed     = Standartordner konnten nicht vorbereitet
 loading-initializing-debug-failed    = Die Initializierung der Debuginformationsausgabe ist fehlgeschlagen
 loading-preparing-config-failed      = Die Vorbereitung der Konfigurationsdatei ist fehlgeschlagen
 loading-initializing-locales-failed  = Die Initialisierung der Lokalisierungen ist fehlgeschlagen
-loading-updating-integrations-failed = Die Integrationsskripts konnten nicht aktualisiert werden
 loading-preparing-games-failed       = Die Vorbereitung der Spiele ist fehlgeschlagen
 loading-preparing-games-list-failed  = Die Vorbereitung der Spielliste ist fehlgeschlagen
 loading-registering-styles-failed    = Die Registrierung der Spielstyles ist fehlgeschlagen
-loading-checking-wine-version-failed = Die Überprüfung der Wine Version ist fehlgeschlagen
-loading-checking-dxvk-version-failed = Die Überprüfung der DXVK Version ist fehlgeschlagen
-loading-checking-applied-dxvk-failed = Die Überprüfung der angewandten DXVK Version ist fehlgeschlafen
-loading-checking-games-addons-failed = Die Überprüfung der Spieladdons ist fehlgeschlagen
 
 main-open-launcher-folder-failed = Der Launcher Order konnte nicht geöffnet werden
 main-open-config-file-failed     = Der Konfigurationsordner konnte nicht geöffnet werden
@@ -13,3 +13,4 @@ loading-checking-dxvk-version = Überprüfe DXVK Version
 loading-checking-applied-dxvk = Überprüfe angewandte DXVK Version
 loading-checking-wine-prefix  = Überprüfe Wine Prefix
 loading-checking-games-addons = Überprüfe Spielerweiterungen
+loading-restoring-tasks       = Stelle Aufgabenwarteschlange wieder her
@@ -7,3 +7,5 @@ main-installed-games = Installierte Spiele
 main-queued-games    = Ausstehende Spiele
 main-outdated-games  = Veraltete Spiele
 main-available-games = Verfügbare Spiele
+
+main-offline-banner = Offline-Modus: einige Quellen sind nicht erreichbar, zwischengespeicherte Integrationen und Komponenten werden verwendet
@@ -8,6 +8,17 @@ general-launcher-language-description = Ändert sich mit dem nächsten Neustart
 general-verify-games = Verifiziere Spiele
 general-verify-games-description = Verifiziere Spielinstallationen nach Installation oder einem Update
 
+general-sandbox-integrations = Integrationsskripte isolieren
+general-sandbox-integrations-description = Integrationsskripte auf die in ihren Manifesten angegebenen Berechtigungen beschränken. Wird nach einem Neustart wirksam
+
+preferences--tasks = Aufgaben
+
+tasks-max-downloads = Parallele Downloads
+tasks-max-downloads-description = Maximale Anzahl gleichzeitig laufender Download-Aufgaben
+
+tasks-max-disk-tasks = Parallele Festplattenaufgaben
+tasks-max-disk-tasks-description = Maximale Anzahl gleichzeitig laufender Überprüfungs-, Entpack- und anderer festplattenintensiver Aufgaben
+
 preferences--wine = Wine
 
 wine-language = Sprache
@@ -25,6 +36,12 @@ wine-virtual-desktop-enabled = Aktiviert
 wine-virtual-desktop-width   = Breite
 wine-virtual-desktop-height  = Höhe
 
+preferences--logs = Protokolle
+
+logs-wine-debug = WINEDEBUG-Kanäle (z. B. fixme-all oder +loaddll)
+logs-keep-sessions = Aufzubewahrende Sitzungsprotokolle
+logs-keep-sessions-description = Anzahl der neuesten Sitzungsprotokolle, die pro Spiel gespeichert werden
+
 preferences--gaming = Gaming
 
 game-hud = HUD
@@ -49,6 +66,34 @@ game-fsr-strength-description = Bildverschärfungsstärke, bei welcher die Stär
 game-gamemode = Gamemode
 game-gamemode-description = Prioritisiere das Spiel über allen anderen Prozessen
 
+game-gamescope = Gamescope
+game-gamescope-description = Das Spiel im Gamescope-Mikrocompositor ausführen
+
+game-gamescope-enabled = Aktiviert
+
+game-gamescope-output-width = Ausgabebreite
+game-gamescope-output-height = Ausgabehöhe
+
+game-gamescope-internal-width = Spielbreite
+game-gamescope-internal-height = Spielhöhe
+game-gamescope-internal-description = Auflösung, in der das Spiel vor der Hochskalierung gerendert wird
+
+game-gamescope-refresh-rate = Bildratenbegrenzung
+game-gamescope-refresh-rate-description = 0 bedeutet keine Begrenzung
+
+game-gamescope-upscaler = Hochskalierer
+game-gamescope-filter = Filter
+
+game-gamescope-window-mode = Fenstermodus
+game-gamescope-window-mode-windowed = Fenster
+game-gamescope-window-mode-borderless = Randlos
+game-gamescope-window-mode-fullscreen = Vollbild
+
+game-gamescope-hdr = HDR
+game-gamescope-extra-args = Zusätzliche Argumente
+
+game-wrappers = Start-Wrapper, durch Semikolons getrennt (z. B. prime-run; mangohud)
+
 preferences--components = Komponenten
 
 components-wine = Wine Version
@@ -62,3 +107,18 @@ components-dxvk-latest = Neuste
 
 components-install-corefonts = Installiere corefonts
 components-install-corefonts-description = Installiere die standart Windows Schriftarten in den Wine Prefix
+
+game-preferences = Spieleinstellungen
+game-preferences-inherited = Geerbt: {$value}
+game-preferences-overridden = Für dieses Spiel überschrieben
+game-preferences-enabled = Aktiviert
+game-preferences-disabled = Deaktiviert
+
+game-preferences-environment = Umgebung
+game-preferences-environment-description = Variablen, die nach den globalen gesetzt werden
+game-preferences-environment-add = Variable hinzufügen (KEY=VALUE)
+
+game-preferences-hooks = Start-Hooks
+game-preferences-hooks-description = Shell-Befehle, die mit der Umgebung des Spiels ausgeführt werden. Das Spiel wird nicht gestartet, wenn der Befehl vor dem Start fehlschlägt
+game-preferences-hooks-pre-launch = Vor dem Start
+game-preferences-hooks-post-exit = Nach dem Beenden
@@ -22,5 +22,25 @@ tasks-installing-fonts     = Installiere Schriftarten...
 tasks-verifying-files      = Verifiziere Dateien...
 tasks-repairing-files      = Repariere Dateien...
 tasks-finished             = Fertig
+tasks-paused               = Pausiert
+tasks-cancelling           = Breche ab...
 
 tasks-delete-files = Lösche Dateien
+
+tasks-pause   = Pausieren
+tasks-resume  = Fortsetzen
+tasks-cancel  = Abbrechen und heruntergeladene Dateien behalten
+tasks-discard = Abbrechen und heruntergeladene Dateien verwerfen
+
+tasks-move-backward = Zurück verschieben
+tasks-move-forward  = Vor verschieben
+tasks-remove        = Aus der Warteschlange entfernen
+
+tasks-cancelled = Aufgabe für {$game-title} abgebrochen
+
+tasks-interrupted             = Unvollendete Aufgaben
+tasks-interrupted-description = Der Launcher wurde geschlossen, während Aufgaben liefen. Sollen sie dort fortgesetzt werden, wo sie angehalten wurden, oder sollen ihre heruntergeladenen Dateien verworfen werden?
+tasks-interrupted-discard     = Verwerfen
+tasks-interrupted-resume      = Fortsetzen
+
+tasks-waiting-download-window = Warte auf das Zeitfenster für große Downloads...
@@ -1,9 +1,49 @@
 details-developer = Developer: {$developer}
 details-played = Played: {$played}
 details-last-played = Last played: {$last-played}
+details-sessions = Sessions: {$sessions} ({$per-week} per week)
+details-average-session = Average session: {$average}
+
+details-never = Never
+details-today = Today
+details-yesterday = Yesterday
+details-days-ago = { $days ->
+        [one] {$days} day ago
+       *[other] {$days} days ago
+    }
+details-date-format = %m/%d/%Y
+
+details-duration-seconds = { $seconds ->
+        [one] {$seconds} second
+       *[other] {$seconds} seconds
+    }
+details-duration-minutes = { $minutes ->
+        [one] {$minutes} minute
+       *[other] {$minutes} minutes
+    }
+details-duration-hours = { $hours ->
+        [one] {$hours} hour
+       *[other] {$hours} hours
+    }
+details-duration-hours-minutes = {$hours} h {$minutes} min
 
 details-play = Play
 details-kill = Kill
 details-verify = Verify
 details-manage-addons = Manage addons
+details-game-settings = Game settings
+details-show-launch-command = Show launch command
+details-open-last-log = Open last log
 details-download = Download
+details-revert-integration = Revert integration
+details-use-latest-integration = Use latest integration
+details-launch-command = Launch command
+
+details-integration-reverted = Integration reverted to version {$version}
+details-integration-revert-failed = Failed to revert integration
+details-integration-unpinned = Latest integration version will be used
+details-integration-unpin-failed = Failed to use latest integration version
+details-restart-required = Restart the launcher to apply changes
+
+details-status-unknown = Game status is unknown because the launcher is offline
+details-no-logs = No game logs yet
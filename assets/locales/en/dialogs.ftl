@@ -5,3 +5,4 @@ dialog-disable  = Disable
 dialog-continue = Continue
 dialog-close    = Close
 dialog-save     = Save
+dialog-copy     = Copy
@@ -2,6 +2,9 @@ config-property-update-failed = Failed to update property
 
 tasks-resolve-queued-failed = Failed to resolve queued task
 tasks-get-status-failed     = Failed to get {$game-title} task status
+tasks-discard-transition-failed = Failed to discard unfinished task files
+
+integration-verification-failed = {$game-name} integration update was refused because it failed verification
 
 addon-verify-installation-failed = Unable to verify addon installation for {$game-title}
 addon-find-diff-failed           = Unable to find {$game-title} addon version diff
@@ -21,6 +24,8 @@ game-get-integrity-failed       = Unable to get {$game-title} integrity info
 game-get-status-failed          = Unable to get {$game-title} status
 game-not-installed              = {$game-title} is not installed
 game-launch-failed              = Failed to launch {$game-title}
+game-get-launch-command-failed  = Unable to get {$game-title} launch command
+game-open-log-failed            = Unable to open {$game-title} log
 game-kill-failed                = Failed to kill {$game-title}
 
 launch-required-addon-unavailable = Addon {$addon-title} from group {$group-title} is unavailable or outdated. You can launch the game without it or continue to use old version
@@ -29,14 +34,9 @@ loading-preparing-folders-failed     = Failed to prepare default folders
 loading-initializing-debug-failed    = Failed to initialize debug output
 loading-preparing-config-failed      = Failed to prepare config file
 loading-initializing-locales-failed  = Failed to initialize locales
-loading-updating-integrations-failed = Failed to update integration scripts
 loading-preparing-games-failed       = Failed to prepare games
 loading-preparing-games-list-failed  = Failed to prepare games list
 loading-registering-styles-failed    = Failed to register games styles
-loading-checking-wine-version-failed = Failed to check wine version
-loading-checking-dxvk-version-failed = Failed to check dxvk version
-loading-checking-applied-dxvk-failed = Failed to check applied dxvk version
-loading-checking-games-addons-failed = Failed to check games addons
 
 main-open-launcher-folder-failed = Failed to open launcher folder
 main-open-config-file-failed     = Failed to open config file
@@ -13,3 +13,4 @@ loading-checking-dxvk-version = Checking dxvk version
 loading-checking-applied-dxvk = Checking applied dxvk version
 loading-checking-wine-prefix  = Checking wine prefix
 loading-checking-games-addons = Checking games addons
+loading-restoring-tasks       = Restoring tasks queue
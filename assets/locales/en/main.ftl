@@ -7,3 +7,5 @@ main-installed-games = Installed games
 main-queued-games    = Queued games
 main-outdated-games  = Outdated games
 main-available-games = Available games
+
+main-offline-banner = Offline mode: some sources are unreachable, cached integrations and components are used
@@ -8,6 +8,17 @@ general-launcher-language-description = Changes after restart
 general-verify-games = Verify games
 general-verify-games-description = Verify games installations after installation or updating
 
+general-sandbox-integrations = Sandbox integration scripts
+general-sandbox-integrations-description = Restrict integration scripts to the capabilities listed in their manifests. Changes after restart
+
+preferences--tasks = Tasks
+
+tasks-max-downloads = Parallel downloads
+tasks-max-downloads-description = Maximal amount of downloading tasks running at the same time
+
+tasks-max-disk-tasks = Parallel disk tasks
+tasks-max-disk-tasks-description = Maximal amount of verifying, unpacking and other disk heavy tasks running at the same time
+
 preferences--wine = Wine
 
 wine-language = Language
@@ -25,6 +36,12 @@ wine-virtual-desktop-enabled = Enabled
 wine-virtual-desktop-width   = Width
 wine-virtual-desktop-height  = Height
 
+preferences--logs = Logs
+
+logs-wine-debug = WINEDEBUG channels (e.g. fixme-all or +loaddll)
+logs-keep-sessions = Sessions logs to keep
+logs-keep-sessions-description = Amount of latest game sessions logs stored per game
+
 preferences--gaming = Gaming
 
 game-hud = HUD
@@ -49,6 +66,34 @@ game-fsr-strength-description = Image sharpening strength where 0 is maximal sha
 game-gamemode = Gamemode
 game-gamemode-description = Prioritize the game over the rest of the processes
 
+game-gamescope = Gamescope
+game-gamescope-description = Run the game inside of gamescope micro-compositor
+
+game-gamescope-enabled = Enabled
+
+game-gamescope-output-width = Output width
+game-gamescope-output-height = Output height
+
+game-gamescope-internal-width = Game width
+game-gamescope-internal-height = Game height
+game-gamescope-internal-description = Resolution the game is rendered in before upscaling
+
+game-gamescope-refresh-rate = Frame rate limit
+game-gamescope-refresh-rate-description = 0 means no limit
+
+game-gamescope-upscaler = Upscaler
+game-gamescope-filter = Filter
+
+game-gamescope-window-mode = Window mode
+game-gamescope-window-mode-windowed = Windowed
+game-gamescope-window-mode-borderless = Borderless
+game-gamescope-window-mode-fullscreen = Fullscreen
+
+game-gamescope-hdr = HDR
+game-gamescope-extra-args = Extra arguments
+
+game-wrappers = Launch wrappers, separated by semicolons (e.g. prime-run; mangohud)
+
 preferences--components = Components
 
 components-wine = Wine version
@@ -62,3 +107,18 @@ components-dxvk-latest = Latest
 
 components-install-corefonts = Install corefonts
 components-install-corefonts-description = Install default windows fonts to the wine prefix
+
+game-preferences = Game settings
+game-preferences-inherited = Inherited: {$value}
+game-preferences-overridden = Overridden for this game
+game-preferences-enabled = Enabled
+game-preferences-disabled = Disabled
+
+game-preferences-environment = Environment
+game-preferences-environment-description = Variables set after the global ones
+game-preferences-environment-add = Add variable (KEY=VALUE)
+
+game-preferences-hooks = Launch hooks
+game-preferences-hooks-description = Shell commands run with the game's environment. Game is not launched if the pre-launch command fails
+game-preferences-hooks-pre-launch = Before launch
+game-preferences-hooks-post-exit = After exit
@@ -22,5 +22,25 @@ tasks-installing-fonts     = Intalling fonts...
 tasks-verifying-files      = Verifying files...
 tasks-repairing-files      = Repairing files...
 tasks-finished             = Finished
+tasks-paused               = Paused
+tasks-cancelling           = Cancelling...
 
 tasks-delete-files = Delete files
+
+tasks-pause   = Pause
+tasks-resume  = Resume
+tasks-cancel  = Cancel and keep downloaded files
+tasks-discard = Cancel and discard downloaded files
+
+tasks-move-backward = Move backward
+tasks-move-forward  = Move forward
+tasks-remove        = Remove from the queue
+
+tasks-cancelled = {$game-title} task cancelled
+
+tasks-interrupted             = Unfinished tasks
+tasks-interrupted-description = The launcher was closed while some tasks were running. Resume them from where they stopped or discard their downloaded files?
+tasks-interrupted-discard     = Discard
+tasks-interrupted-resume      = Resume
+
+tasks-waiting-download-window = Waiting for large downloads time...
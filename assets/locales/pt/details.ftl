@@ -1,9 +1,49 @@
 details-developer = Desenvolvedor(a): {$developer}
 details-played = Jogado: {$played}
 details-last-played = Jogado pela última vez: {$last-played}
+details-sessions = Sessões: {$sessions} ({$per-week} por semana)
+details-average-session = Sessão média: {$average}
+
+details-never = Nunca
+details-today = Hoje
+details-yesterday = Ontem
+details-days-ago = { $days ->
+        [one] {$days} dia atrás
+       *[other] {$days} dias atrás
+    }
+details-date-format = %d/%m/%Y
+
+details-duration-seconds = { $seconds ->
+        [one] {$seconds} segundo
+       *[other] {$seconds} segundos
+    }
+details-duration-minutes = { $minutes ->
+        [one] {$minutes} minuto
+       *[other] {$minutes} minutos
+    }
+details-duration-hours = { $hours ->
+        [one] {$hours} hora
+       *[other] {$hours} horas
+    }
+details-duration-hours-minutes = {$hours} h {$minutes} min
 
 details-play = Jogar
 details-kill = Matar processo
 details-verify = Verificar
 details-manage-addons = Gerenciar complementos
+details-game-settings = Configurações do jogo
+details-show-launch-command = Mostrar comando de inicialização
+details-open-last-log = Abrir último registro
 details-download = Baixar
+details-revert-integration = Reverter integração
+details-use-latest-integration = Usar integração mais recente
+details-launch-command = Comando de inicialização
+
+details-integration-reverted = Integração revertida para a versão {$version}
+details-integration-revert-failed = Falha ao reverter integração
+details-integration-unpinned = A versão mais recente da integração será usada
+details-integration-unpin-failed = Falha ao usar a versão mais recente da integração
+details-restart-required = Reinicie o launcher para aplicar as alterações
+
+details-status-unknown = O status do jogo é desconhecido porque o launcher está offline
+details-no-logs = Ainda não há registros do jogo
@@ -5,3 +5,4 @@ dialog-disable  = Desativar
 dialog-continue = Continuar
 dialog-close    = Fechar
 dialog-save     = Salvar
+dialog-copy     = Copiar
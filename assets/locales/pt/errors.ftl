@@ -2,6 +2,9 @@ config-property-update-failed = Erro ao atualizar propriedade
 
 tasks-resolve-queued-failed = Erro ao finalizar a tarefa na fila
 tasks-get-status-failed     = Falha ao pegar o status da tarefa de {$game-title}
+tasks-discard-transition-failed = Falha ao descartar os arquivos de tarefas inacabadas
+
+integration-verification-failed = A atualização da integração {$game-name} foi recusada porque falhou na verificação
 
 addon-verify-installation-failed = Impossível verificar a instalação do complemento para {$game-title}
 addon-find-diff-failed           = Impossível encontrar o diff do complemento de {$game-title}
@@ -21,6 +24,8 @@ game-get-integrity-failed       = Foi impossível conseguir dados de integridade
 game-get-status-failed          = Não foi possível conseguir o status de {$game-title}
 game-not-installed              = O título {$game-title} não está instalado
 game-launch-failed              = Não foi possível iniciar {$game-title}
+game-get-launch-command-failed  = Não foi possível obter o comando de inicialização de {$game-title}
+game-open-log-failed            = Não foi possível abrir o registro de {$game-title}
 game-kill-failed                = Não foi possível matar o processo de {$game-title}
 
 launch-required-addon-unavailable = O complemento {$addon-title} do grupo {$group-title} está indisponível ou desatualizado. Você pode iniciar o jogo sem ele ou permanecer com a versão antiga.
@@ -29,14 +34,9 @@ loading-preparing-folders-failed     = Erro ao preparar os diretórios padrão
 loading-initializing-debug-failed    = Erro ao iniciar a saída de debugging
 loading-preparing-config-failed      = Erro ao preparar arquivo de configuração
 loading-initializing-locales-failed  = Erro ao iniciar os locais
-loading-updating-integrations-failed = Erro ao atualizar scripts de integração
 loading-preparing-games-failed       = Erro ao preparar os jogos
 loading-preparing-games-list-failed  = Erro ao preparar a lista de jogos
 loading-registering-styles-failed    = Erro ao registrar os estilos dos jogos
-loading-checking-wine-version-failed = Erro ao detectar a versão do Wine
-loading-checking-dxvk-version-failed = Erro ao detectar a versão do DXVK
-loading-checking-applied-dxvk-failed = Erro ao verificar versão do DXVK instalado
-loading-checking-games-addons-failed = Erro ao verificar os complementos do jogo
 
 main-open-launcher-folder-failed = Falha ao abrir a pasta do launcher
 main-open-config-file-failed     = Falha ao abrir arquivo de configurações
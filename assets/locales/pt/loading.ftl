@@ -13,3 +13,4 @@ loading-checking-dxvk-version = Verificando a versão do DXVK
 loading-checking-applied-dxvk = Verificando a versão aplicada do DXVK
 loading-checking-wine-prefix  = Verificando o prefixo do Wine
 loading-checking-games-addons = Verificando os complementos de jogo
+loading-restoring-tasks       = Restaurando a fila de tarefas
@@ -7,3 +7,5 @@ main-installed-games = Jogos instalados
 main-queued-games    = Jogos na fila
 main-outdated-games  = Jogos desatualizados
 main-available-games = Jogos disponíveis
+
+main-offline-banner = Modo offline: algumas fontes estão inacessíveis, integrações e componentes em cache estão sendo usados
@@ -8,6 +8,17 @@ general-launcher-language-description = É alterado após a reinicialização
 general-verify-games = Verificar as instalações dos jogos
 general-verify-games-description = Verifica a instalação do jogo após uma atualização ou instalação.
 
+general-sandbox-integrations = Isolar scripts de integração
+general-sandbox-integrations-description = Restringe os scripts de integração às permissões listadas nos seus manifestos. Aplicado após reiniciar
+
+preferences--tasks = Tarefas
+
+tasks-max-downloads = Downloads paralelos
+tasks-max-downloads-description = Quantidade máxima de tarefas de download executadas ao mesmo tempo
+
+tasks-max-disk-tasks = Tarefas de disco paralelas
+tasks-max-disk-tasks-description = Quantidade máxima de tarefas de verificação, extração e outras tarefas pesadas de disco executadas ao mesmo tempo
+
 preferences--wine = Wine
 
 wine-language = Idioma do Wine
@@ -25,6 +36,12 @@ wine-virtual-desktop-enabled = Ativado
 wine-virtual-desktop-width   = Largura
 wine-virtual-desktop-height  = Altura
 
+preferences--logs = Registros
+
+logs-wine-debug = Canais WINEDEBUG (ex. fixme-all ou +loaddll)
+logs-keep-sessions = Registros de sessões a manter
+logs-keep-sessions-description = Quantidade de registros das últimas sessões armazenados por jogo
+
 preferences--gaming = Jogo
 
 game-hud = HUD
@@ -49,6 +66,34 @@ game-fsr-strength-description = Força da pixelização em uma imagem, onde 0 é
 game-gamemode = Modo de Jogo
 game-gamemode-description = Priorize o Jogo sobre todos os outros processos.
 
+game-gamescope = Gamescope
+game-gamescope-description = Executar o jogo dentro do microcompositor gamescope
+
+game-gamescope-enabled = Ativado
+
+game-gamescope-output-width = Largura de saída
+game-gamescope-output-height = Altura de saída
+
+game-gamescope-internal-width = Largura do jogo
+game-gamescope-internal-height = Altura do jogo
+game-gamescope-internal-description = Resolução em que o jogo é renderizado antes do aumento de escala
+
+game-gamescope-refresh-rate = Limite de taxa de quadros
+game-gamescope-refresh-rate-description = 0 significa sem limite
+
+game-gamescope-upscaler = Escalonador
+game-gamescope-filter = Filtro
+
+game-gamescope-window-mode = Modo de janela
+game-gamescope-window-mode-windowed = Janela
+game-gamescope-window-mode-borderless = Sem bordas
+game-gamescope-window-mode-fullscreen = Tela cheia
+
+game-gamescope-hdr = HDR
+game-gamescope-extra-args = Argumentos extras
+
+game-wrappers = Wrappers de inicialização, separados por ponto e vírgula (ex. prime-run; mangohud)
+
 preferences--components = Componentes
 
 components-wine = Versão do Wine
@@ -62,3 +107,18 @@ components-dxvk-latest = Mais recente
 
 components-install-corefonts = Instalar as fontes necessárias.
 components-install-corefonts-description = Instala as fontes padrão do Windows no prefixo do Wine.
+
+game-preferences = Configurações do jogo
+game-preferences-inherited = Herdado: {$value}
+game-preferences-overridden = Substituído para este jogo
+game-preferences-enabled = Ativado
+game-preferences-disabled = Desativado
+
+game-preferences-environment = Ambiente
+game-preferences-environment-description = Variáveis definidas após as globais
+game-preferences-environment-add = Adicionar variável (KEY=VALUE)
+
+game-preferences-hooks = Ganchos de inicialização
+game-preferences-hooks-description = Comandos de shell executados com o ambiente do jogo. O jogo não é iniciado se o comando anterior à inicialização falhar
+game-preferences-hooks-pre-launch = Antes de iniciar
+game-preferences-hooks-post-exit = Após sair
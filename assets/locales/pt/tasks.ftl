@@ -22,5 +22,25 @@ tasks-installing-fonts     = Intalando fontes...
 tasks-verifying-files      = Verificando arquivos...
 tasks-repairing-files      = Reparando arquivos...
 tasks-finished             = Pronto
+tasks-paused               = Pausado
+tasks-cancelling           = Cancelando...
 
 tasks-delete-files = Apagar arquivos
+
+tasks-pause   = Pausar
+tasks-resume  = Retomar
+tasks-cancel  = Cancelar e manter os arquivos baixados
+tasks-discard = Cancelar e descartar os arquivos baixados
+
+tasks-move-backward = Mover para trás
+tasks-move-forward  = Mover para frente
+tasks-remove        = Remover da fila
+
+tasks-cancelled = Tarefa de {$game-title} cancelada
+
+tasks-interrupted             = Tarefas inacabadas
+tasks-interrupted-description = O launcher foi fechado enquanto algumas tarefas estavam em execução. Retomá-las de onde pararam ou descartar os arquivos baixados?
+tasks-interrupted-discard     = Descartar
+tasks-interrupted-resume      = Retomar
+
+tasks-waiting-download-window = Aguardando o horário de downloads grandes...
@@ -1,9 +1,57 @@
 details-developer = Разработчик: {$developer}
 details-played = Время в игре: {$played}
 details-last-played = Последний запуск: {$last-played}
+details-sessions = Сессий: {$sessions} ({$per-week} в неделю)
+details-average-session = Средняя сессия: {$average}
+
+details-never = Никогда
+details-today = Сегодня
+details-yesterday = Вчера
+details-days-ago = { $days ->
+        [one] {$days} день назад
+        [few] {$days} дня назад
+        [many] {$days} дней назад
+       *[other] {$days} дня назад
+    }
+details-date-format = %d.%m.%Y
+
+details-duration-seconds = { $seconds ->
+        [one] {$seconds} секунда
+        [few] {$seconds} секунды
+        [many] {$seconds} секунд
+       *[other] {$seconds} секунды
+    }
+details-duration-minutes = { $minutes ->
+        [one] {$minutes} минута
+        [few] {$minutes} минуты
+        [many] {$minutes} минут
+       *[other] {$minutes} минуты
+    }
+details-duration-hours = { $hours ->
+        [one] {$hours} час
+        [few] {$hours} часа
+        [many] {$hours} часов
+       *[other] {$hours} часа
+    }
+details-duration-hours-minutes = {$hours} ч {$minutes} мин
 
 details-play = Играть
 details-kill = Убить
 details-verify = Проверить
 details-manage-addons = Дополнения
+details-game-settings = Настройки игры
+details-show-launch-command = Показать команду запуска
+details-open-last-log = Открыть последний лог
 details-download = Установить
+details-revert-integration = Откатить интеграцию
+details-use-latest-integration = Использовать последнюю интеграцию
+details-launch-command = Команда запуска
+
+details-integration-reverted = Интеграция откачена до версии {$version}
+details-integration-revert-failed = Не удалось откатить интеграцию
+details-integration-unpinned = Будет использоваться последняя версия интеграции
+details-integration-unpin-failed = Не удалось переключиться на последнюю версию интеграции
+details-restart-required = Перезапустите лаунчер, чтобы применить изменения
+
+details-status-unknown = Статус игры неизвестен, так как лаунчер работает в автономном режиме
+details-no-logs = Логов игры пока нет
@@ -5,3 +5,4 @@ dialog-disable  = Отключить
 dialog-continue = Продолжить
 dialog-close    = Закрыть
 dialog-save     = Сохранить
+dialog-copy     = Копировать
@@ -2,6 +2,9 @@ config-property-update-failed = Не удалось обновить настр
 
 tasks-resolve-queued-failed = Не удалось запустить задачу из очереди
 tasks-get-status-failed     = Не удалось получить статус задачи {$game-title}
+tasks-discard-transition-failed = Не удалось удалить файлы незавершённых задач
+
+integration-verification-failed = Обновление интеграции {$game-name} отклонено, так как она не прошла проверку
 
 addon-verify-installation-failed = Не удалось проверить наличие дополнения для {$game-title}
 addon-find-diff-failed           = Не удалось найти обновление дополнения для {$game-title}
@@ -21,6 +24,8 @@ game-get-integrity-failed       = Не удалось получить инфо
 game-get-status-failed          = Не удалось получить статус {$game-title}
 game-not-installed              = {$game-title} не установлен
 game-launch-failed              = Не удалось запустить {$game-title}
+game-get-launch-command-failed  = Не удалось получить команду запуска {$game-title}
+game-open-log-failed            = Не удалось открыть лог {$game-title}
 game-kill-failed                = Не удалось убить процесс {$game-title}
 
 launch-required-addon-unavailable = Дополнение {$addon-title} из группы {$group-title} недоступно или устарело. Вы можете запустить игру без него или продолжить использовать старую версию
@@ -29,14 +34,9 @@ loading-preparing-folders-failed     = Не удалось подготовит
 loading-initializing-debug-failed    = Не удалось инициализировать отладочный вывод
 loading-preparing-config-failed      = Не удалось подготовить файл настроек
 loading-initializing-locales-failed  = Не удалось инициализировать локализации
-loading-updating-integrations-failed = Не удалось обновить скрипты интеграции
 loading-preparing-games-failed       = Не удалось подготовить игры
 loading-preparing-games-list-failed  = Не удалось подготовить список игр
 loading-registering-styles-failed    = Не удалось зарегистрировать стили игр
-loading-checking-wine-version-failed = Не удалось проверить версию Wine
-loading-checking-dxvk-version-failed = Не удалось проверить версию DXVK
-loading-checking-applied-dxvk-failed = Не удалось проверить установленную версию DXVK
-loading-checking-games-addons-failed = Не удалось проверить дополнения игр
 
 main-open-launcher-folder-failed = Не удалось открыть папку лаунчера
 main-open-config-file-failed     = Не удалось открыть файл настроек
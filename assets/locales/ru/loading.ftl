@@ -13,3 +13,4 @@ loading-checking-dxvk-version = Проверка версии DXVK
 loading-checking-applied-dxvk = Проверка установленной версии DXVK
 loading-checking-wine-prefix  = Проверка префикса Wine
 loading-checking-games-addons = Проверка дополнений игр
+loading-restoring-tasks       = Восстановление очереди задач
@@ -7,3 +7,5 @@ main-installed-games = Установленные игры
 main-queued-games    = Игры в очереди
 main-outdated-games  = Устаревшие игры
 main-available-games = Доступные игры
+
+main-offline-banner = Автономный режим: некоторые источники недоступны, используются сохранённые интеграции и компоненты
@@ -8,6 +8,17 @@ general-launcher-language-description = Изменится после перез
 general-verify-games = Проверять игры
 general-verify-games-description = Проверять файлы игр после установки или обновлений
 
+general-sandbox-integrations = Изолировать скрипты интеграций
+general-sandbox-integrations-description = Ограничить скрипты интеграций разрешениями, указанными в их манифестах. Применяется после перезапуска
+
+preferences--tasks = Задачи
+
+tasks-max-downloads = Параллельные загрузки
+tasks-max-downloads-description = Максимальное количество одновременно выполняемых задач загрузки
+
+tasks-max-disk-tasks = Параллельные дисковые задачи
+tasks-max-disk-tasks-description = Максимальное количество одновременно выполняемых задач проверки, распаковки и других задач, нагружающих диск
+
 preferences--wine = Wine
 
 wine-language = Язык
@@ -25,6 +36,12 @@ wine-virtual-desktop-enabled = Включен
 wine-virtual-desktop-width   = Ширина
 wine-virtual-desktop-height  = Высота
 
+preferences--logs = Логи
+
+logs-wine-debug = Каналы WINEDEBUG (например fixme-all или +loaddll)
+logs-keep-sessions = Хранить логов сессий
+logs-keep-sessions-description = Количество последних логов игровых сессий, хранимых для каждой игры
+
 preferences--gaming = Игра
 
 game-hud = HUD
@@ -49,6 +66,34 @@ game-fsr-strength-description = Резкость изображения. 0 - м
 game-gamemode = Gamemode
 game-gamemode-description = Приоритизация процесса игры над остальными процессами в системе
 
+game-gamescope = Gamescope
+game-gamescope-description = Запускать игру внутри микрокомпозитора gamescope
+
+game-gamescope-enabled = Включено
+
+game-gamescope-output-width = Ширина вывода
+game-gamescope-output-height = Высота вывода
+
+game-gamescope-internal-width = Ширина игры
+game-gamescope-internal-height = Высота игры
+game-gamescope-internal-description = Разрешение, в котором игра отрисовывается перед масштабированием
+
+game-gamescope-refresh-rate = Ограничение частоты кадров
+game-gamescope-refresh-rate-description = 0 - без ограничения
+
+game-gamescope-upscaler = Масштабирование
+game-gamescope-filter = Фильтр
+
+game-gamescope-window-mode = Режим окна
+game-gamescope-window-mode-windowed = Оконный
+game-gamescope-window-mode-borderless = Без рамок
+game-gamescope-window-mode-fullscreen = Полноэкранный
+
+game-gamescope-hdr = HDR
+game-gamescope-extra-args = Дополнительные аргументы
+
+game-wrappers = Обёртки запуска, через точку с запятой (например prime-run; mangohud)
+
 preferences--components = Компоненты
 
 components-wine = Версия Wine
@@ -62,3 +107,18 @@ components-dxvk-latest = Последняя
 
 components-install-corefonts = Устанавливать шрифты Windows
 components-install-corefonts-description = Устанавливать стандартные шрифты Windows при создании префикса Wine
+
+game-preferences = Настройки игры
+game-preferences-inherited = Наследуется: {$value}
+game-preferences-overridden = Переопределено для этой игры
+game-preferences-enabled = Включено
+game-preferences-disabled = Выключено
+
+game-preferences-environment = Окружение
+game-preferences-environment-description = Переменные, устанавливаемые после глобальных
+game-preferences-environment-add = Добавить переменную (KEY=VALUE)
+
+game-preferences-hooks = Хуки запуска
+game-preferences-hooks-description = Команды оболочки, выполняемые с окружением игры. Игра не запускается, если команда перед запуском завершилась с ошибкой
+game-preferences-hooks-pre-launch = Перед запуском
+game-preferences-hooks-post-exit = После выхода
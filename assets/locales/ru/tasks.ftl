@@ -18,5 +18,25 @@ tasks-installing-fonts     = Установка шрифтов...
 tasks-verifying-files      = Проверка файлов...
 tasks-repairing-files      = Восстановление файлов...
 tasks-finished             = Завершена
+tasks-paused               = Приостановлено
+tasks-cancelling           = Отмена...
 
 tasks-delete-files = Удаление файлов
+
+tasks-pause   = Приостановить
+tasks-resume  = Продолжить
+tasks-cancel  = Отменить и сохранить загруженные файлы
+tasks-discard = Отменить и удалить загруженные файлы
+
+tasks-move-backward = Переместить назад
+tasks-move-forward  = Переместить вперёд
+tasks-remove        = Убрать из очереди
+
+tasks-cancelled = Задача {$game-title} отменена
+
+tasks-interrupted             = Незавершённые задачи
+tasks-interrupted-description = Лаунчер был закрыт во время выполнения задач. Продолжить их с места остановки или удалить загруженные файлы?
+tasks-interrupted-discard     = Удалить
+tasks-interrupted-resume      = Продолжить
+
+tasks-waiting-download-window = Ожидание времени для больших загрузок...
@@ -1,9 +1,57 @@
 details-developer = Розробник: {$developer}
 details-played = Зіграно: {$played}
 details-last-played = Востаннє зіграно: {$last-played}
+details-sessions = Сесій: {$sessions} ({$per-week} на тиждень)
+details-average-session = Середня сесія: {$average}
+
+details-never = Ніколи
+details-today = Сьогодні
+details-yesterday = Вчора
+details-days-ago = { $days ->
+        [one] {$days} день тому
+        [few] {$days} дні тому
+        [many] {$days} днів тому
+       *[other] {$days} дня тому
+    }
+details-date-format = %d.%m.%Y
+
+details-duration-seconds = { $seconds ->
+        [one] {$seconds} секунда
+        [few] {$seconds} секунди
+        [many] {$seconds} секунд
+       *[other] {$seconds} секунди
+    }
+details-duration-minutes = { $minutes ->
+        [one] {$minutes} хвилина
+        [few] {$minutes} хвилини
+        [many] {$minutes} хвилин
+       *[other] {$minutes} хвилини
+    }
+details-duration-hours = { $hours ->
+        [one] {$hours} година
+        [few] {$hours} години
+        [many] {$hours} годин
+       *[other] {$hours} години
+    }
+details-duration-hours-minutes = {$hours} год {$minutes} хв
 
 details-play = Грати
 details-kill = Вбити
 details-verify = Перевірити
 details-manage-addons = Manage addons
+details-game-settings = Налаштування гри
+details-show-launch-command = Показати команду запуску
+details-open-last-log = Відкрити останній лог
 details-download = Download
+details-revert-integration = Відкотити інтеграцію
+details-use-latest-integration = Використовувати останню інтеграцію
+details-launch-command = Команда запуску
+
+details-integration-reverted = Інтеграцію відкочено до версії {$version}
+details-integration-revert-failed = Не вдалося відкотити інтеграцію
+details-integration-unpinned = Буде використовуватися остання версія інтеграції
+details-integration-unpin-failed = Не вдалося перейти на останню версію інтеграції
+details-restart-required = Перезапустіть лаунчер, щоб застосувати зміни
+
+details-status-unknown = Статус гри невідомий, оскільки лаунчер працює в автономному режимі
+details-no-logs = Логів гри поки немає
@@ -5,3 +5,4 @@ dialog-disable  = Вимкнути
 dialog-continue = Продовжити
 dialog-close    = Закрити
 dialog-save     = Зберегти
+dialog-copy     = Копіювати
@@ -2,6 +2,9 @@ config-property-update-failed = Не вдалося оновити власти
 
 tasks-resolve-queued-failed = Не вдалося обробити завдання в черзі
 tasks-get-status-failed     = Не вдалося отримати статус завдання для {$game-title}
+tasks-discard-transition-failed = Не вдалося видалити файли незавершених завдань
+
+integration-verification-failed = Оновлення інтеграції {$game-name} відхилено, оскільки вона не пройшла перевірку
 
 addon-verify-installation-failed = Неможливо перевірити встановлення додатку для {$game-title}
 addon-find-diff-failed           = Неможливо знайти різницю версій додатку для {$game-title}
@@ -21,6 +24,8 @@ game-get-integrity-failed       = Неможливо отримати інфор
 game-get-status-failed          = Неможливо отримати статус {$game-title}
 game-not-installed              = {$game-title} не встановлено
 game-launch-failed              = Не вдалося запустити {$game-title}
+game-get-launch-command-failed  = Не вдалося отримати команду запуску {$game-title}
+game-open-log-failed            = Не вдалося відкрити лог {$game-title}
 game-kill-failed                = Не вдалося примусово завершити {$game-title}
 
 launch-required-addon-unavailable = Додаток {$addon-title} з групи {$group-title} недоступний або застарілий. Ви можете запустити гру без нього або продовжити використовувати стару версію
@@ -29,14 +34,9 @@ loading-preparing-folders-failed     = Не вдалося підготуват
 loading-initializing-debug-failed    = Не вдалося ініціалізувати вивід налагодження
 loading-preparing-config-failed      = Не вдалося підготувати файл конфігурації
 loading-initializing-locales-failed  = Не вдалося ініціалізувати локалі
-loading-updating-integrations-failed = Не вдалося оновити інтеграційні скрипти
 loading-preparing-games-failed       = Не вдалося підготувати ігри
 loading-preparing-games-list-failed  = Не вдалося підготувати список ігор
 loading-registering-styles-failed    = Не вдалося зареєструвати стилі ігор
-loading-checking-wine-version-failed = Не вдалося перевірити версію wine
-loading-checking-dxvk-version-failed = Не вдалося перевірити версію dxvk
-loading-checking-applied-dxvk-failed = Не вдалося перевірити застосовану версію dxvk
-loading-checking-games-addons-failed = Не вдалося перевірити додатки ігор
 
 main-open-launcher-folder-failed = Не вдалося відкрити папку лаунчера
 main-open-config-file-failed     = Не вдалося відкрити файл конфігурації
@@ -13,3 +13,4 @@ loading-checking-dxvk-version = Перевірка версії dxvk
 loading-checking-applied-dxvk = Перевірка застосованого dxvk
 loading-checking-wine-prefix  = Перевірка префіксу wine
 loading-checking-games-addons = Перевірка ігрових доповнень
+loading-restoring-tasks       = Відновлення черги завдань
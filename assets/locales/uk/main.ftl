@@ -7,3 +7,5 @@ main-installed-games = Встановлені ігри
 main-queued-games    = Ігри в черзі
 main-outdated-games  = Застарілі ігри
 main-available-games = Доступні ігри
+
+main-offline-banner = Автономний режим: деякі джерела недоступні, використовуються збережені інтеграції та компоненти
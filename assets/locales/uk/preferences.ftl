@@ -8,6 +8,17 @@ general-launcher-language-description = Застосовується після
 general-verify-games = Перевірити ігри
 general-verify-games-description = Перевірити інсталяцію ігор після встановлення чи оновлення
 
+general-sandbox-integrations = Ізолювати скрипти інтеграцій
+general-sandbox-integrations-description = Обмежити скрипти інтеграцій дозволами, вказаними в їх маніфестах. Застосовується після перезапуску
+
+preferences--tasks = Завдання
+
+tasks-max-downloads = Паралельні завантаження
+tasks-max-downloads-description = Максимальна кількість одночасно виконуваних завдань завантаження
+
+tasks-max-disk-tasks = Паралельні дискові завдання
+tasks-max-disk-tasks-description = Максимальна кількість одночасно виконуваних завдань перевірки, розпакування та інших завдань, що навантажують диск
+
 preferences--wine = Wine
 
 wine-language = Мова
@@ -25,6 +36,12 @@ wine-virtual-desktop-enabled = Увімкнено
 wine-virtual-desktop-width   = Ширина
 wine-virtual-desktop-height  = Висота
 
+preferences--logs = Логи
+
+logs-wine-debug = Канали WINEDEBUG (наприклад fixme-all або +loaddll)
+logs-keep-sessions = Зберігати логів сесій
+logs-keep-sessions-description = Кількість останніх логів ігрових сесій, що зберігаються для кожної гри
+
 preferences--gaming = Ігровий процес
 
 game-hud = HUD
@@ -49,6 +66,34 @@ game-fsr-strength-description = Підвищення різкості зобра
 game-gamemode = Gamemode
 game-gamemode-description = Надати пріоритет грі над іншими процесами
 
+game-gamescope = Gamescope
+game-gamescope-description = Запускати гру всередині мікрокомпозитора gamescope
+
+game-gamescope-enabled = Увімкнено
+
+game-gamescope-output-width = Ширина виводу
+game-gamescope-output-height = Висота виводу
+
+game-gamescope-internal-width = Ширина гри
+game-gamescope-internal-height = Висота гри
+game-gamescope-internal-description = Роздільна здатність, у якій гра рендериться перед масштабуванням
+
+game-gamescope-refresh-rate = Обмеження частоти кадрів
+game-gamescope-refresh-rate-description = 0 - без обмеження
+
+game-gamescope-upscaler = Масштабування
+game-gamescope-filter = Фільтр
+
+game-gamescope-window-mode = Режим вікна
+game-gamescope-window-mode-windowed = Віконний
+game-gamescope-window-mode-borderless = Без рамок
+game-gamescope-window-mode-fullscreen = Повноекранний
+
+game-gamescope-hdr = HDR
+game-gamescope-extra-args = Додаткові аргументи
+
+game-wrappers = Обгортки запуску, через крапку з комою (наприклад prime-run; mangohud)
+
 preferences--components = Компоненти
 
 components-wine = Версія Wine
@@ -62,3 +107,18 @@ components-dxvk-latest = Остання
 
 components-install-corefonts = Встановити corefonts
 components-install-corefonts-description = Встановити шрифти Windows за замовчуванням у префікс Wine
+
+game-preferences = Налаштування гри
+game-preferences-inherited = Успадковано: {$value}
+game-preferences-overridden = Перевизначено для цієї гри
+game-preferences-enabled = Увімкнено
+game-preferences-disabled = Вимкнено
+
+game-preferences-environment = Оточення
+game-preferences-environment-description = Змінні, що встановлюються після глобальних
+game-preferences-environment-add = Додати змінну (KEY=VALUE)
+
+game-preferences-hooks = Хуки запуску
+game-preferences-hooks-description = Команди оболонки, що виконуються з оточенням гри. Гра не запускається, якщо команда перед запуском завершилася з помилкою
+game-preferences-hooks-pre-launch = Перед запуском
+game-preferences-hooks-post-exit = Після виходу
@@ -22,5 +22,25 @@ tasks-installing-fonts     = Встановлення шрифтів...
 tasks-verifying-files      = Перевірка файлів...
 tasks-repairing-files      = Відновлення файлів...
 tasks-finished             = Завершено
+tasks-paused               = Призупинено
+tasks-cancelling           = Скасування...
 
 tasks-delete-files = Видалити файли
+
+tasks-pause   = Призупинити
+tasks-resume  = Продовжити
+tasks-cancel  = Скасувати й зберегти завантажені файли
+tasks-discard = Скасувати й видалити завантажені файли
+
+tasks-move-backward = Перемістити назад
+tasks-move-forward  = Перемістити вперед
+tasks-remove        = Прибрати з черги
+
+tasks-cancelled = Завдання {$game-title} скасовано
+
+tasks-interrupted             = Незавершені завдання
+tasks-interrupted-description = Лаунчер було закрито під час виконання завдань. Продовжити їх з місця зупинки чи видалити завантажені файли?
+tasks-interrupted-discard     = Видалити
+tasks-interrupted-resume      = Продовжити
+
+tasks-waiting-download-window = Очікування часу для великих завантажень...
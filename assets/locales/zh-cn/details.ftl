@@ -1,9 +1,45 @@
 details-developer = 开发者: {$developer}
 details-played = 游玩时长: {$played}
 details-last-played = 上次游玩: {$last-played}
+details-sessions = 游戏次数：{$sessions}（每周 {$per-week} 次）
+details-average-session = 平均时长：{$average}
+
+details-never = 从未
+details-today = 今天
+details-yesterday = 昨天
+details-days-ago = { $days ->
+       *[other] {$days} 天前
+    }
+details-date-format = %Y年%m月%d日
+
+details-duration-seconds = { $seconds ->
+       *[other] {$seconds} 秒
+    }
+details-duration-minutes = { $minutes ->
+       *[other] {$minutes} 分钟
+    }
+details-duration-hours = { $hours ->
+       *[other] {$hours} 小时
+    }
+details-duration-hours-minutes = {$hours} 小时 {$minutes} 分钟
 
 details-play = 启动
 details-kill = 杀死进程
 details-verify = 验证
 details-manage-addons = 管理拓展
+details-game-settings = 游戏设置
+details-show-launch-command = 显示启动命令
+details-open-last-log = 打开最近日志
 details-download = 下载
+details-revert-integration = 回滚集成脚本
+details-use-latest-integration = 使用最新集成脚本
+details-launch-command = 启动命令
+
+details-integration-reverted = 集成脚本已回滚到版本 {$version}
+details-integration-revert-failed = 回滚集成脚本失败
+details-integration-unpinned = 将使用最新版本的集成脚本
+details-integration-unpin-failed = 无法切换到最新版本的集成脚本
+details-restart-required = 重启启动器以应用更改
+
+details-status-unknown = 启动器处于离线状态，游戏状态未知
+details-no-logs = 暂无游戏日志
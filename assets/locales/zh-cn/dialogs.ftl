@@ -5,3 +5,4 @@ dialog-disable  = 关闭
 dialog-continue = 继续
 dialog-close    = 关闭
 dialog-save     = 保存
+dialog-copy     = 复制
@@ -2,6 +2,9 @@ config-property-update-error = 更新属性失败
 
 tasks-resolve-queued-failed = 无法解决正在进行中的任务
 tasks-get-status-failed = 无法获取 {$game-title} 任务状态
+tasks-discard-transition-failed = 无法删除未完成任务的文件
+
+integration-verification-failed = {$game-name} 集成的更新未通过验证，已被拒绝
 
 addon-verify-installation-failed = 无法验证 {$game-title} 的语言文件
 addon-find-diff-failed           = 无法找到 {$game-title} addon version diff
@@ -21,6 +24,8 @@ game-get-integrity-failed       = 无法获取 {$game-title} integrity info
 game-get-status-failed          = Unable to get {$game-title} status
 game-not-installed              = {$game-title} 未安装
 game-launch-failed              = Failed to launch {$game-title}
+game-get-launch-command-failed  = 无法获取 {$game-title} 的启动命令
+game-open-log-failed            = 无法打开 {$game-title} 的日志
 game-kill-failed                = Failed to kill {$game-title}
 
 launch-required-addon-unavailable = Addon {$addon-title} from group {$group-title} is unavailable or outdated. You can launch the game without it or continue to use old version
@@ -29,14 +34,9 @@ loading-preparing-folders-failed     = Failed to prepare default folders
 loading-initializing-debug-failed    = Failed to initialize debug output
 loading-preparing-config-failed      = Failed to prepare config file
 loading-initializing-locales-failed  = Failed to initialize locales
-loading-updating-integrations-failed = Failed to update integration scripts
 loading-preparing-games-failed       = Failed to prepare games
 loading-preparing-games-list-failed  = Failed to prepare games list
 loading-registering-styles-failed    = Failed to register games styles
-loading-checking-wine-version-failed = 无法检查 Wine 版本
-loading-checking-dxvk-version-failed = 无法检查 DXVK 版本
-loading-checking-applied-dxvk-failed = Failed to check applied dxvk version
-loading-checking-games-addons-failed = Failed to check games addons
 
 main-open-launcher-folder-failed = Failed to open launcher folder
 main-open-config-file-failed     = Failed to open config file
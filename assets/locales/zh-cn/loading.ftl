@@ -13,3 +13,4 @@ loading-checking-dxvk-version = 正在检查 dxvk 的版本
 loading-checking-applied-dxvk = 正在检查 applied dxvk 的版本
 loading-checking-wine-prefix  = 正在检查 wine prefix
 loading-checking-games-addons = 正在检查游戏拓展
+loading-restoring-tasks       = 正在恢复任务队列
@@ -7,3 +7,5 @@ main-installed-games = Installed games
 main-queued-games    = Queued games
 main-outdated-games  = Outdated games
 main-available-games = Available games
+
+main-offline-banner = 离线模式：部分来源无法访问，正在使用缓存的集成和组件
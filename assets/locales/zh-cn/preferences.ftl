@@ -8,6 +8,17 @@ general-launcher-language-description = 将在下次重启后使用
 general-verify-games = 验证游戏文件
 general-verify-games-description = 安装或更新后验证游戏文件
 
+general-sandbox-integrations = 沙盒运行集成脚本
+general-sandbox-integrations-description = 将集成脚本限制在其清单中声明的权限内。重启后生效
+
+preferences--tasks = 任务
+
+tasks-max-downloads = 并行下载
+tasks-max-downloads-description = 同时运行的下载任务的最大数量
+
+tasks-max-disk-tasks = 并行磁盘任务
+tasks-max-disk-tasks-description = 同时运行的校验、解压等磁盘密集型任务的最大数量
+
 preferences--wine = Wine
 
 wine-language = 语言
@@ -25,6 +36,12 @@ wine-virtual-desktop-enabled = Enabled
 wine-virtual-desktop-width   = Width
 wine-virtual-desktop-height  = Height
 
+preferences--logs = 日志
+
+logs-wine-debug = WINEDEBUG 通道（例如 fixme-all 或 +loaddll）
+logs-keep-sessions = 保留的会话日志数
+logs-keep-sessions-description = 每个游戏保存的最近游戏会话日志数量
+
 preferences--gaming = 游戏
 
 game-hud = HUD
@@ -49,6 +66,34 @@ game-fsr-strength-description = 图像锐化强度, 其中 0 表示最大锐度
 game-gamemode = 游戏模式
 game-gamemode-description = 提升游戏相对其他程序的优先级
 
+game-gamescope = Gamescope
+game-gamescope-description = 在 gamescope 微型合成器中运行游戏
+
+game-gamescope-enabled = 启用
+
+game-gamescope-output-width = 输出宽度
+game-gamescope-output-height = 输出高度
+
+game-gamescope-internal-width = 游戏宽度
+game-gamescope-internal-height = 游戏高度
+game-gamescope-internal-description = 游戏在放大之前的渲染分辨率
+
+game-gamescope-refresh-rate = 帧率限制
+game-gamescope-refresh-rate-description = 0 表示不限制
+
+game-gamescope-upscaler = 缩放方式
+game-gamescope-filter = 滤镜
+
+game-gamescope-window-mode = 窗口模式
+game-gamescope-window-mode-windowed = 窗口化
+game-gamescope-window-mode-borderless = 无边框
+game-gamescope-window-mode-fullscreen = 全屏
+
+game-gamescope-hdr = HDR
+game-gamescope-extra-args = 额外参数
+
+game-wrappers = 启动包装器，用分号分隔（例如 prime-run; mangohud）
+
 preferences--components = 组件
 
 components-wine = Wine 版本
@@ -62,3 +107,18 @@ components-dxvk-latest = 最新
 
 components-install-corefonts = 安装 corefonts
 components-install-corefonts-description = 将默认的 Windows 字体安装到 wine prefix
+
+game-preferences = 游戏设置
+game-preferences-inherited = 继承：{$value}
+game-preferences-overridden = 已为此游戏覆盖
+game-preferences-enabled = 启用
+game-preferences-disabled = 禁用
+
+game-preferences-environment = 环境变量
+game-preferences-environment-description = 在全局变量之后设置的变量
+game-preferences-environment-add = 添加变量 (KEY=VALUE)
+
+game-preferences-hooks = 启动钩子
+game-preferences-hooks-description = 使用游戏环境运行的 Shell 命令。如果启动前命令失败，游戏将不会启动
+game-preferences-hooks-pre-launch = 启动前
+game-preferences-hooks-post-exit = 退出后
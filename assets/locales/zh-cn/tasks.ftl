@@ -22,5 +22,25 @@ tasks-installing-fonts     = 正在安装 fonts...
 tasks-verifying-files      = 正在验证下载好的文件...
 tasks-repairing-files      = 正在修复文件...
 tasks-finished             = 完成
+tasks-paused               = 已暂停
+tasks-cancelling           = 正在取消...
 
 tasks-delete-files = Delete files
+
+tasks-pause   = 暂停
+tasks-resume  = 继续
+tasks-cancel  = 取消并保留已下载的文件
+tasks-discard = 取消并删除已下载的文件
+
+tasks-move-backward = 前移
+tasks-move-forward  = 后移
+tasks-remove        = 从队列中移除
+
+tasks-cancelled = {$game-title} 任务已取消
+
+tasks-interrupted             = 未完成的任务
+tasks-interrupted-description = 启动器在任务运行时被关闭。要从中断处继续这些任务，还是删除已下载的文件？
+tasks-interrupted-discard     = 删除
+tasks-interrupted-resume      = 继续
+
+tasks-waiting-download-window = 正在等待大文件下载时段...
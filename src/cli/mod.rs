@@ -0,0 +1,422 @@
+use std::path::{Path, PathBuf};
+
+use crate::config;
+use crate::games;
+
+use crate::games::integrations::Game;
+use crate::games::integrations::standards::diff::{Diff, DiffStatus};
+
+use crate::components::wine::DownloadWineQueuedTask;
+use crate::components::dxvk::DownloadDxvkQueuedTask;
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::ui::components::tasks_queue::{
+    create_prefix_task::CreatePrefixQueuedTask,
+    apply_dxvk_task::ApplyDxvkQueuedTask
+};
+
+use crate::ui::windows::loading::{
+    check_default_dirs,
+    init_debug,
+    init_config,
+    init_locales,
+    update_integrations,
+    init_games,
+    used_components,
+    check_wine,
+    check_dxvk,
+    check_wine_prefix
+};
+
+use crate::ui::windows::main::{
+    MainAppMsg,
+    launch_game,
+    kill_game,
+    download_game_task,
+    download_addon_task,
+    verify_game_task
+};
+
+pub mod run_task;
+pub mod validate;
+
+pub use run_task::run_task;
+
+pub const USAGE: &str = "Usage: anime-games-launcher [command] [options]
+
+Commands:
+    list                      List available games and their status
+    install <game> <edition>  Install the game, its components and required addons
+    update  <game> <edition>  Update installed game and its addons
+    verify  <game> <edition>  Verify game files and repair broken ones
+    launch  <game> <edition>  Launch the game and wait until it's closed
+    command <game> <edition>  Print the command used to launch the game
+    kill    <game> <edition>  Kill running game process
+    validate <manifest>       Check integration script by calling all its functions
+    help                      Show this message
+
+Options:
+    --debug                   Print debug logs
+    --no-update               Don't update integration scripts before running the command
+
+Run without a command to open the launcher window";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    List,
+    Install(String, String),
+    Update(String, String),
+    Verify(String, String),
+    Launch(String, String),
+    Command(String, String),
+    Kill(String, String),
+    Validate(PathBuf),
+    Help
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliArgs {
+    pub command: CliCommand,
+
+    /// Update integration scripts before running the command
+    pub update_integrations: bool
+}
+
+impl CliArgs {
+    /// Parse command line arguments
+    ///
+    /// Returns `Ok(None)` if no command was given
+    /// and the launcher should be opened in the GUI mode
+    pub fn parse(args: impl IntoIterator<Item = String>) -> anyhow::Result<Option<Self>> {
+        let mut update_integrations = true;
+        let mut values = Vec::new();
+
+        for arg in args {
+            match arg.as_str() {
+                "--no-update" => update_integrations = false,
+
+                // Handled by the APP_DEBUG variable
+                "--debug" => (),
+
+                "-h" | "--help" => values.push(String::from("help")),
+
+                _ if arg.starts_with('-') => anyhow::bail!("Unknown option: {arg}\n\n{USAGE}"),
+
+                _ => values.push(arg)
+            }
+        }
+
+        let Some(command) = values.first() else {
+            return Ok(None);
+        };
+
+        let game_edition = || -> anyhow::Result<(String, String)> {
+            match (values.get(1), values.get(2)) {
+                (Some(game), Some(edition)) => Ok((game.clone(), edition.clone())),
+                _ => anyhow::bail!("Command `{command}` requires game name and edition\n\n{USAGE}")
+            }
+        };
+
+        let command = match command.as_str() {
+            "list" => CliCommand::List,
+            "help" => CliCommand::Help,
+
+            "install" => game_edition().map(|(game, edition)| CliCommand::Install(game, edition))?,
+            "update"  => game_edition().map(|(game, edition)| CliCommand::Update(game, edition))?,
+            "verify"  => game_edition().map(|(game, edition)| CliCommand::Verify(game, edition))?,
+            "launch"  => game_edition().map(|(game, edition)| CliCommand::Launch(game, edition))?,
+            "command" => game_edition().map(|(game, edition)| CliCommand::Command(game, edition))?,
+            "kill"    => game_edition().map(|(game, edition)| CliCommand::Kill(game, edition))?,
+
+            "validate" => match values.get(1) {
+                Some(path) => CliCommand::Validate(PathBuf::from(path)),
+                None => anyhow::bail!("Command `validate` requires path to the manifest file\n\n{USAGE}")
+            },
+
+            _ => anyhow::bail!("Unknown command: {command}\n\n{USAGE}")
+        };
+
+        Ok(Some(Self {
+            command,
+            update_integrations
+        }))
+    }
+}
+
+/// Convert error message used by the main window into a plain error
+pub fn toast_error(message: Box<MainAppMsg>) -> anyhow::Error {
+    match *message {
+        MainAppMsg::ShowToast { title, message: Some(message) } => anyhow::anyhow!("{title}: {message}"),
+        MainAppMsg::ShowToast { title, message: None } => anyhow::anyhow!(title),
+
+        message => anyhow::anyhow!("{message:?}")
+    }
+}
+
+/// Run loading stages which don't require the launcher window
+pub fn init(args: &CliArgs) -> anyhow::Result<config::Config> {
+    check_default_dirs::check_default_dirs()?;
+    init_debug::init_debug()?;
+
+    let config = init_config::init_config()?;
+
+    init_locales::init_locales(&config)?;
+
+    if args.update_integrations {
+        let pool = rusty_pool::Builder::new()
+            .name(String::from("cli"))
+            .core_size(config.general.threads.number as usize)
+            .build();
+
+        update_integrations::update_integrations(&pool)?;
+    }
+
+    init_games::init_games()?;
+
+    Ok(config)
+}
+
+/// Find game integration and build its card info
+pub fn get_game_info(game_name: &str, edition: &str) -> anyhow::Result<(&'static Game, CardInfo)> {
+    let Some(game) = games::get(game_name)? else {
+        anyhow::bail!("Game `{game_name}` not found");
+    };
+
+    let editions = game.driver.get_game_editions_list()?;
+
+    if !editions.iter().any(|game_edition| game_edition.name == edition) {
+        anyhow::bail!("Game `{game_name}` doesn't have `{edition}` edition");
+    }
+
+    let info = CardInfo::Game {
+        name: game.manifest.game_name.clone(),
+        title: game.manifest.game_title.clone(),
+        developer: game.manifest.game_developer.clone(),
+        edition: edition.to_string(),
+        picture_uri: game.driver.get_card_picture(edition)?
+    };
+
+    Ok((game, info))
+}
+
+/// Get game edition installation diff
+fn get_game_diff(game: &Game, info: &CardInfo, config: &config::Config) -> anyhow::Result<Option<Diff>> {
+    let settings = config.games.get_game_settings(game)?;
+
+    let Some(paths) = settings.paths.get(info.get_edition()) else {
+        anyhow::bail!("Unable to find {} paths", info.get_title());
+    };
+
+    game.driver.get_game_diff(&paths.game.to_string_lossy(), info.get_edition())
+}
+
+/// Download wine and DXVK and create wine prefix if needed
+pub fn prepare_components() -> anyhow::Result<()> {
+    let used_components = used_components::get_used_components()?;
+
+    for version in check_wine::get_download(&used_components)? {
+        run_task(Box::new(DownloadWineQueuedTask {
+            card_info: CardInfo::Component {
+                name: version.name.clone(),
+                title: version.title.clone(),
+                developer: String::new()
+            },
+            version
+        }))?;
+    }
+
+    for version in check_dxvk::get_download(&used_components)? {
+        run_task(Box::new(DownloadDxvkQueuedTask {
+            card_info: CardInfo::Component {
+                name: version.name.clone(),
+                title: version.name.clone(),
+                developer: String::new()
+            },
+            version
+        }))?;
+    }
+
+    for entry in check_wine_prefix::check_wine_prefix(&used_components)? {
+        run_task(Box::new(CreatePrefixQueuedTask {
+            path: entry.prefix.path,
+            install_corefonts: entry.prefix.install_corefonts,
+            wine_version: entry.wine,
+            dxvk_version: entry.dxvk
+        }))?;
+    }
+
+    // Prefixes created above already have the selected DXVK version
+    for entry in check_dxvk::get_apply(&used_components)? {
+        run_task(Box::new(ApplyDxvkQueuedTask {
+            card_info: CardInfo::Component {
+                name: entry.dxvk.name.clone(),
+                title: entry.dxvk.title.clone(),
+                developer: String::new()
+            },
+            dxvk_version: entry.dxvk,
+            wine_version: entry.wine,
+            prefix_path: entry.prefix.path
+        }))?;
+    }
+
+    Ok(())
+}
+
+/// Ask user what to do with an unavailable addon from the terminal
+pub fn addon_unavailable(addon_title: &str, group_title: &str) -> anyhow::Result<String> {
+    use std::io::Write;
+
+    loop {
+        print!("Addon \"{addon_title}\" from \"{group_title}\" is not installed or outdated. [s]top, [d]isable or [c]ontinue? ");
+
+        std::io::stdout().flush()?;
+
+        let mut answer = String::new();
+
+        if std::io::stdin().read_line(&mut answer)? == 0 {
+            return Ok(String::from("stop"));
+        }
+
+        match answer.trim().to_ascii_lowercase().as_str() {
+            "s" | "stop"     => return Ok(String::from("stop")),
+            "d" | "disable"  => return Ok(String::from("disable")),
+            "c" | "continue" => return Ok(String::from("continue")),
+
+            _ => continue
+        }
+    }
+}
+
+fn list(config: &config::Config) -> anyhow::Result<()> {
+    let mut games = games::list()?.values().collect::<Vec<_>>();
+
+    games.sort_by(|a, b| a.manifest.game_name.cmp(&b.manifest.game_name));
+
+    for game in games {
+        let settings = config.games.get_game_settings(game)?;
+
+        for edition in game.driver.get_game_editions_list()? {
+            let path = settings.paths[&edition.name].game.to_string_lossy();
+
+            let status = match game.driver.get_game_diff(&path, &edition.name) {
+                Ok(Some(Diff { status: DiffStatus::Latest, current_version, .. })) => format!("installed ({current_version})"),
+                Ok(Some(Diff { status: DiffStatus::Outdated, current_version, latest_version, .. })) => format!("outdated ({current_version} -> {latest_version})"),
+                Ok(Some(Diff { status: DiffStatus::Unavailable, current_version, .. })) => format!("unavailable ({current_version})"),
+                Ok(None) => String::from("not installed"),
+
+                Err(err) => format!("unknown ({err})")
+            };
+
+            println!("{}\t{}\t{} ({})\t{status}", game.manifest.game_name, edition.name, game.manifest.game_title, edition.title);
+        }
+    }
+
+    Ok(())
+}
+
+fn download(game_name: &str, edition: &str, config: &config::Config, update: bool) -> anyhow::Result<()> {
+    let (game, info) = get_game_info(game_name, edition)?;
+
+    match get_game_diff(game, &info, config)? {
+        Some(Diff { status: DiffStatus::Latest, .. }) if !update => anyhow::bail!("{} is already installed", info.get_title()),
+        None if update => anyhow::bail!("{} is not installed", info.get_title()),
+
+        _ => ()
+    }
+
+    prepare_components()?;
+
+    let result = download_game_task::get_download_game_task(&info, config)
+        .map_err(toast_error)?;
+
+    run_task(result.game_task)?;
+
+    if config.general.verify_games {
+        run_task(verify_game_task::get_verify_game_task(&info, config).map_err(toast_error)?)?;
+    }
+
+    for entry in result.download_addons {
+        run_task(download_addon_task::get_download_addon_task(&info, &entry.addon, &entry.group).map_err(toast_error)?)?;
+    }
+
+    Ok(())
+}
+
+/// Validate integration script and print the report
+/// 
+/// Fails if any of the checks failed
+fn validate(manifest_path: &Path) -> anyhow::Result<()> {
+    let temp_path = std::env::temp_dir()
+        .join(format!("anime-games-launcher-validate-{}", std::process::id()));
+
+    let report = validate::validate(manifest_path, &temp_path);
+
+    if temp_path.exists() {
+        std::fs::remove_dir_all(&temp_path)?;
+    }
+
+    let report = report?;
+
+    println!();
+    println!("{} passed, {} failed, {} skipped", report.passed, report.failed, report.skipped);
+
+    if report.failed > 0 {
+        anyhow::bail!("Integration script validation failed");
+    }
+
+    Ok(())
+}
+
+/// Run CLI command
+pub fn run(args: CliArgs) -> anyhow::Result<()> {
+    match &args.command {
+        CliCommand::Help => {
+            println!("{USAGE}");
+
+            return Ok(());
+        }
+
+        // Validation doesn't need the launcher's games
+        CliCommand::Validate(manifest_path) => return validate(manifest_path),
+
+        _ => ()
+    }
+
+    let config = init(&args)?;
+
+    match args.command {
+        CliCommand::List => list(&config),
+
+        CliCommand::Install(game, edition) => download(&game, &edition, &config, false),
+        CliCommand::Update(game, edition)  => download(&game, &edition, &config, true),
+
+        CliCommand::Verify(game, edition) => {
+            let (_, info) = get_game_info(&game, &edition)?;
+
+            run_task(verify_game_task::get_verify_game_task(&info, &config).map_err(toast_error)?)
+        }
+
+        CliCommand::Launch(game, edition) => {
+            let (_, info) = get_game_info(&game, &edition)?;
+
+            launch_game::launch_game(&info, &addon_unavailable)
+        }
+
+        CliCommand::Command(game, edition) => {
+            let (_, info) = get_game_info(&game, &edition)?;
+
+            println!("{}", launch_game::get_launch_command(&info)?.to_shell_string());
+
+            Ok(())
+        }
+
+        CliCommand::Kill(game, edition) => {
+            let (_, info) = get_game_info(&game, &edition)?;
+
+            kill_game::kill_game(&info)
+        }
+
+        CliCommand::Help | CliCommand::Validate(_) => unreachable!()
+    }
+}
@@ -0,0 +1,70 @@
+use std::io::{Write, IsTerminal};
+use std::time::Duration;
+
+use crate::ui::components::tasks_queue::{
+    QueuedTask,
+    TaskStatus
+};
+
+use crate::utils::pretty_bytes;
+
+/// Terminal progress refresh timeout
+pub const PROGRESS_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// Resolve queued task and print its progress
+/// to the terminal until it's finished
+pub fn run_task(task: Box<dyn QueuedTask>) -> anyhow::Result<()> {
+    let info = task.get_info();
+
+    println!("==> {}", info.get_title());
+
+    let mut task = task.resolve()?;
+
+    let is_terminal = std::io::stdout().is_terminal();
+
+    let mut last_status = None;
+
+    while !task.is_finished() {
+        let status = task.get_status()?;
+        let (pulse, title) = status.describe();
+
+        // Redraw progress line in place when attached to a terminal,
+        // otherwise print only status changes to keep logs readable
+        if is_terminal {
+            let line = if pulse {
+                title
+            }
+
+            else if task.get_total() > 1024 * 512 {
+                format!("{title}: {:.2}% ({} / {})", task.get_progress() * 100.0, pretty_bytes(task.get_current()), pretty_bytes(task.get_total()))
+            }
+
+            else {
+                format!("{title}: {:.2}% ({} / {})", task.get_progress() * 100.0, task.get_current(), task.get_total())
+            };
+
+            print!("\r\x1b[2K{line}");
+
+            std::io::stdout().flush()?;
+        }
+
+        else if last_status.as_ref() != Some(&status) {
+            println!("{title}");
+        }
+
+        last_status = Some(status);
+
+        std::thread::sleep(PROGRESS_TIMEOUT);
+    }
+
+    if is_terminal {
+        println!();
+    }
+
+    // Finished task returns its error from the status getter
+    task.get_status()?;
+
+    println!("==> {}: {}", info.get_title(), TaskStatus::Finished.describe().1);
+
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use std::path::{Path, PathBuf};
+
+use crate::games::integrations::Game;
+use crate::games::integrations::standards::IntegrationStandard;
+use crate::games::integrations::standards::addons::AddonType;
+
+/// Result of the integration script validation
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Report {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize
+}
+
+impl Report {
+    /// Print check result and return its output if it passed
+    fn check<T>(&mut self, function: &str, context: &str, result: anyhow::Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => {
+                self.passed += 1;
+
+                println!("PASS\t{function}\t{context}");
+
+                Some(value)
+            }
+
+            Err(err) => {
+                self.failed += 1;
+
+                // Keep the whole error on a single line
+                let err = format!("{err:#}").replace('\n', " ");
+
+                println!("FAIL\t{function}\t{context}\t{err}");
+
+                None
+            }
+        }
+    }
+
+    fn skip(&mut self, function: &str, context: &str, reason: &str) {
+        self.skipped += 1;
+
+        println!("SKIP\t{function}\t{context}\t{reason}");
+    }
+}
+
+/// Get name of the integration function for the given standard
+/// 
+/// Paths are written in the v2 format: `game.get_diff`
+/// is `v1_game_get_diff` and `hooks.game.diff_transition`
+/// is `v1_game_diff_transition` in the v1 standard
+fn function_name(standard: IntegrationStandard, path: &str) -> String {
+    match standard {
+        IntegrationStandard::V1 => format!("v1_{}", path.trim_start_matches("hooks.").replace('.', "_")),
+        IntegrationStandard::V2 => path.to_string()
+    }
+}
+
+/// Call every integration function against empty temp folders
+/// and verify their outputs
+/// 
+/// `game.kill` and transition hooks are not called
+/// because they can modify the system state
+pub fn validate(manifest_path: impl AsRef<Path>, temp_path: impl AsRef<Path>) -> anyhow::Result<Report> {
+    let mut report = Report::default();
+
+    let Some(game) = report.check("manifest", &manifest_path.as_ref().to_string_lossy(), Game::new(manifest_path.as_ref())) else {
+        return Ok(report);
+    };
+
+    let driver = &game.driver;
+    let name = |path: &str| function_name(driver.standard, path);
+
+    let Some(editions) = report.check(&name("game.get_editions_list"), "", driver.get_game_editions_list()) else {
+        return Ok(report);
+    };
+
+    if editions.is_empty() {
+        report.check::<()>(&name("game.get_editions_list"), "", Err(anyhow::anyhow!("Game must have at least one edition")));
+    }
+
+    for edition in editions {
+        let edition = edition.name.as_str();
+
+        let game_path = temp_path.as_ref().join(edition).join("game");
+        let addons_path = temp_path.as_ref().join(edition).join("addons");
+
+        std::fs::create_dir_all(&game_path)?;
+        std::fs::create_dir_all(&addons_path)?;
+
+        let game_path = game_path.to_string_lossy();
+        let addons_path = addons_path.to_string_lossy();
+
+        // Visual
+
+        report.check(&name("visual.get_card_picture"), edition, driver.get_card_picture(edition));
+        report.check(&name("visual.get_background_picture"), edition, driver.get_background_picture(edition));
+
+        match driver.get_details_background_style(edition) {
+            Ok(None) => report.skip(&name("visual.get_details_background_css"), edition, "not implemented"),
+            result => {
+                report.check(&name("visual.get_details_background_css"), edition, result);
+            }
+        }
+
+        // Game
+
+        report.check(&name("game.is_installed"), edition, driver.is_game_installed(&game_path, edition));
+        report.check(&name("game.get_version"), edition, driver.get_game_version(&game_path, edition));
+        report.check(&name("game.get_download"), edition, driver.get_game_download(edition));
+        report.check(&name("game.get_diff"), edition, driver.get_game_diff(&game_path, edition));
+        report.check(&name("game.get_status"), edition, driver.get_game_status(&game_path, edition));
+        report.check(&name("game.get_launch_options"), edition, driver.get_launch_options(&game_path, &addons_path, edition));
+        report.check(&name("game.is_running"), edition, driver.is_process_running(&game_path, edition));
+        report.check(&name("game.get_integrity_info"), edition, driver.get_game_integrity(&game_path, edition));
+
+        report.skip(&name("game.kill"), edition, "not called by the validator");
+
+        // Addons
+
+        let Some(groups) = report.check(&name("addons.get_list"), edition, driver.get_addons_list(edition)) else {
+            continue;
+        };
+
+        for group in groups {
+            for addon in group.addons {
+                let context = format!("{edition}/{}/{}", group.name, addon.name);
+
+                // Same layout as in `Addon::get_installation_path`,
+                // but with temp folders instead of the game settings
+                let addon_path = if addon.r#type == AddonType::Module {
+                    PathBuf::from(game_path.as_ref())
+                } else {
+                    PathBuf::from(addons_path.as_ref())
+                        .join(&group.name)
+                        .join(&addon.name)
+                };
+
+                std::fs::create_dir_all(&addon_path)?;
+
+                let addon_path = addon_path.to_string_lossy();
+
+                report.check(&name("addons.is_installed"), &context, driver.is_addon_installed(&group.name, &addon.name, &addon_path, edition));
+                report.check(&name("addons.get_version"), &context, driver.get_addon_version(&group.name, &addon.name, &addon_path, edition));
+                report.check(&name("addons.get_download"), &context, driver.get_addon_download(&group.name, &addon.name, edition));
+                report.check(&name("addons.get_diff"), &context, driver.get_addon_diff(&group.name, &addon.name, &addon_path, edition));
+                report.check(&name("addons.get_paths"), &context, driver.get_addon_paths(&group.name, &addon.name, &addon_path, edition));
+                report.check(&name("addons.get_integrity_info"), &context, driver.get_addon_integrity(&group.name, &addon.name, &addon_path, edition));
+            }
+        }
+    }
+
+    // Optional hooks are only checked for their presence
+
+    let hooks = [
+        ("hooks.game.diff_pre_transition", driver.has_game_diff_pre_transition()),
+        ("hooks.game.diff_transition", driver.has_game_diff_transition()),
+        ("hooks.game.diff_post_transition", driver.has_game_diff_post_transition()),
+        ("hooks.addons.diff_pre_transition", driver.has_addons_diff_pre_transition()),
+        ("hooks.addons.diff_transition", driver.has_addons_diff_transition()),
+        ("hooks.addons.diff_post_transition", driver.has_addons_diff_post_transition()),
+        ("integrity.hash", driver.has_integrity_hash())
+    ];
+
+    for (path, result) in hooks {
+        match result {
+            Ok(true) => report.skip(&name(path), "", "implemented, not called by the validator"),
+            Ok(false) => report.skip(&name(path), "", "not implemented"),
+
+            Err(err) => {
+                report.check::<()>(&name(path), "", Err(err));
+            }
+        }
+    }
+
+    Ok(report)
+}
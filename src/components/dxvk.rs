@@ -3,12 +3,8 @@ use std::path::PathBuf;
 
 use serde_json::Value as Json;
 
-use anime_game_core::network::minreq;
 use anime_game_core::archive;
 
-use anime_game_core::network::downloader::DownloaderExt;
-use anime_game_core::network::downloader::basic::Downloader;
-
 use anime_game_core::updater::UpdaterExt;
 
 use crate::{
@@ -21,8 +17,12 @@ use crate::components::{
     Status
 };
 
+use crate::network::downloader::Downloader;
+use crate::network::bandwidth::DownloadLimiter;
+use crate::network::retry::{self, RetryPolicy};
+
 use crate::ui::components::game_card::CardInfo;
-use crate::ui::components::tasks_queue::{QueuedTask, ResolvedTask};
+use crate::ui::components::tasks_queue::{QueuedTask, ResolvedTask, TaskKind};
 
 use super::DownloadComponentResolvedTask;
 
@@ -35,12 +35,15 @@ pub struct Dxvk {
 }
 
 impl Dxvk {
-    /// Get selected wine build versions list
+    /// Get selected dxvk build versions list
+    #[inline]
     pub fn versions() -> anyhow::Result<Vec<Self>> {
-        let components = config::get().components;
+        Self::build_versions(&config::get().components.dxvk.build)
+    }
 
-        let dxvk_versions = minreq::get(format!("{}/dxvk/{}.json", &components.channel, &components.dxvk.build))
-            .send()?.json::<Vec<Json>>()?;
+    /// Get dxvk build versions list
+    pub fn build_versions(build: &str) -> anyhow::Result<Vec<Self>> {
+        let dxvk_versions = super::lists::fetch("dxvk", build)?;
 
         let mut versions = Vec::new();
 
@@ -64,10 +67,16 @@ impl Dxvk {
     }
 
     /// Resolve component version from the config file
+    #[inline]
     pub fn from_config() -> anyhow::Result<Self> {
-        let dxvk_info = config::get().components.dxvk;
+        Self::from_components(&config::get().components)
+    }
+
+    /// Resolve component version from the components settings
+    pub fn from_components(components: &config::components::Components) -> anyhow::Result<Self> {
+        let dxvk_info = &components.dxvk;
 
-        for version in Self::versions()? {
+        for version in Self::build_versions(&dxvk_info.build)? {
             if version.name.contains(&dxvk_info.version) || version.version.contains(&dxvk_info.version) || dxvk_info.version == "latest" {
                 return Ok(version);
             }
@@ -105,7 +114,8 @@ impl Dxvk {
             updater: receiver,
 
             worker: Some(std::thread::spawn(move || -> anyhow::Result<()> {
-                let downloader = Downloader::new(download_uri);
+                let downloader = Downloader::new(download_uri)
+                    .with_limiter(DownloadLimiter::new());
 
                 let path = COMPONENTS_FOLDER.join("dxvk");
                 let archive = path.join(downloader.file_name());
@@ -116,15 +126,11 @@ impl Dxvk {
 
                 // Download update archive
 
-                let mut updater = downloader.download(&archive)?;
+                retry::download(&downloader, &archive, &RetryPolicy::from_config(), |current, total| {
+                    sender.send((Status::Downloading, current, total))?;
 
-                while !updater.is_finished() {
-                    sender.send((
-                        Status::Downloading,
-                        updater.current(),
-                        updater.total()
-                    ))?;
-                }
+                    Ok(())
+                })?;
 
                 // Extract archive
 
@@ -170,4 +176,14 @@ impl QueuedTask for DownloadDxvkQueuedTask {
             updater: self.version.download()?
         }))
     }
+
+    #[inline]
+    fn get_kind(&self) -> TaskKind {
+        TaskKind::Download
+    }
+
+    #[inline]
+    fn get_locks(&self) -> Vec<PathBuf> {
+        vec![self.version.get_folder()]
+    }
 }
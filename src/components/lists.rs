@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use crate::{
+    config,
+    network,
+    COMPONENTS_FOLDER
+};
+
+lazy_static::lazy_static! {
+    /// Components lists fetched during this session
+    /// 
+    /// Lists are requested from the channel at most once per session
+    static ref SESSION_CACHE: Mutex<HashMap<String, Vec<Json>>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CacheInfo {
+    /// URI the list was fetched from
+    pub uri: String,
+
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+
+    /// Timestamp of the last list fetch or revalidation
+    pub updated_at: i64
+}
+
+/// Get path to the cached versions list of the component build
+#[inline]
+pub fn get_cache_path(component: &str, build: &str) -> PathBuf {
+    COMPONENTS_FOLDER
+        .join(".lists")
+        .join(component)
+        .join(format!("{build}.json"))
+}
+
+#[inline]
+fn get_cache_info_path(component: &str, build: &str) -> PathBuf {
+    get_cache_path(component, build).with_extension("info.json")
+}
+
+fn read_cache_info(component: &str, build: &str) -> Option<CacheInfo> {
+    let info = std::fs::read(get_cache_info_path(component, build)).ok()?;
+
+    serde_json::from_slice(&info).ok()
+}
+
+fn write_cache_info(component: &str, build: &str, info: &CacheInfo) -> anyhow::Result<()> {
+    std::fs::write(get_cache_info_path(component, build), serde_json::to_string_pretty(info)?)?;
+
+    Ok(())
+}
+
+fn read_cache(component: &str, build: &str) -> anyhow::Result<Vec<Json>> {
+    Ok(serde_json::from_slice(&std::fs::read(get_cache_path(component, build))?)?)
+}
+
+/// Fetch versions list of the component build from the components channel
+/// 
+/// Fetched list is cached for `components.cache_ttl` seconds and revalidated
+/// using `ETag` and `Last-Modified` headers after that. Cached list is also used
+/// when the channel is unreachable
+pub fn fetch(component: &str, build: &str) -> anyhow::Result<Vec<Json>> {
+    let config = config::get().components;
+
+    let uri = format!("{}/{component}/{build}.json", config.channel);
+
+    // Keep the lock while fetching the list so concurrent callers
+    // wait for the first request instead of sending their own
+    let mut session_cache = SESSION_CACHE.lock()
+        .map_err(|err| anyhow::anyhow!("Failed to lock components lists cache: {err}"))?;
+
+    if let Some(versions) = session_cache.get(&uri) {
+        return Ok(versions.clone());
+    }
+
+    let cache_path = get_cache_path(component, build);
+
+    // Cached list is ignored if it was fetched from another channel
+    let cache_info = read_cache_info(component, build)
+        .filter(|info| info.uri == uri && cache_path.exists());
+
+    let now = chrono::Utc::now().timestamp();
+
+    if let Some(info) = &cache_info {
+        if now - info.updated_at < config.cache_ttl as i64 {
+            if let Ok(versions) = read_cache(component, build) {
+                session_cache.insert(uri, versions.clone());
+
+                return Ok(versions);
+            }
+        }
+    }
+
+    let mut request = network::get(&uri);
+
+    if let Some(info) = &cache_info {
+        if let Some(etag) = &info.etag {
+            request = request.with_header("If-None-Match", etag);
+        }
+
+        if let Some(last_modified) = &info.last_modified {
+            request = request.with_header("If-Modified-Since", last_modified);
+        }
+    }
+
+    let versions = match request.send() {
+        Ok(response) if response.status_code == 304 && cache_info.is_some() => {
+            tracing::debug!("Cached {component} {build} versions list is up to date");
+
+            if let Some(mut info) = cache_info {
+                info.updated_at = now;
+
+                write_cache_info(component, build, &info)?;
+            }
+
+            read_cache(component, build)?
+        }
+
+        Ok(response) if response.is_ok() => {
+            let versions = response.json::<Vec<Json>>()?;
+
+            if let Some(parent) = cache_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            write_cache_info(component, build, &CacheInfo {
+                uri: uri.clone(),
+                etag: response.headers.get("etag").cloned(),
+                last_modified: response.headers.get("last-modified").cloned(),
+                updated_at: now
+            })?;
+
+            std::fs::write(&cache_path, response.into_bytes())?;
+
+            versions
+        }
+
+        result => {
+            let err = match result {
+                Ok(response) => anyhow::anyhow!("Components channel responded with {} {}", response.status_code, response.reason_phrase),
+                Err(err) => err
+            };
+
+            // Server which responded with an error is still reachable
+            if network::is_transport_error(&err) {
+                network::set_offline();
+            }
+
+            if !cache_path.exists() {
+                return Err(err.context(format!("Failed to fetch {component} {build} versions list")));
+            }
+
+            tracing::warn!("Failed to fetch {component} {build} versions list, using cached one: {err}");
+
+            read_cache(component, build)?
+        }
+    };
+
+    session_cache.insert(uri, versions.clone());
+
+    Ok(versions)
+}
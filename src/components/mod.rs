@@ -8,6 +8,7 @@ use crate::ui::components::game_card::CardInfo;
 
 pub mod wine;
 pub mod dxvk;
+pub mod lists;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
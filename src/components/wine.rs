@@ -11,16 +11,16 @@ use wincompatlib::wine::{
     WineLoader as WincompatlibWineLoader
 };
 
-use anime_game_core::network::minreq;
 use anime_game_core::archive;
 
-use anime_game_core::network::downloader::DownloaderExt;
-use anime_game_core::network::downloader::basic::Downloader;
-
 use anime_game_core::updater::UpdaterExt;
 
+use crate::network::downloader::Downloader;
+use crate::network::bandwidth::DownloadLimiter;
+use crate::network::retry::{self, RetryPolicy};
+
 use crate::ui::components::game_card::CardInfo;
-use crate::ui::components::tasks_queue::{QueuedTask, ResolvedTask};
+use crate::ui::components::tasks_queue::{QueuedTask, ResolvedTask, TaskKind};
 
 use crate::{
     config,
@@ -43,11 +43,14 @@ pub struct Wine {
 
 impl Wine {
     /// Get selected wine build versions list
+    #[inline]
     pub fn versions() -> anyhow::Result<Vec<Self>> {
-        let components = config::get().components;
+        Self::build_versions(&config::get().components.wine.build)
+    }
 
-        let wine_versions = minreq::get(format!("{}/wine/{}.json", &components.channel, &components.wine.build))
-            .send()?.json::<Vec<Json>>()?;
+    /// Get wine build versions list
+    pub fn build_versions(build: &str) -> anyhow::Result<Vec<Self>> {
+        let wine_versions = super::lists::fetch("wine", build)?;
 
         let mut versions = Vec::new();
 
@@ -69,10 +72,16 @@ impl Wine {
     }
 
     /// Resolve component version from the config file
+    #[inline]
     pub fn from_config() -> anyhow::Result<Self> {
-        let wine_info = config::get().components.wine;
+        Self::from_components(&config::get().components)
+    }
+
+    /// Resolve component version from the components settings
+    pub fn from_components(components: &config::components::Components) -> anyhow::Result<Self> {
+        let wine_info = &components.wine;
 
-        for version in Self::versions()? {
+        for version in Self::build_versions(&wine_info.build)? {
             if version.name.contains(&wine_info.version) || wine_info.version == "latest" {
                 return Ok(version);
             }
@@ -116,7 +125,8 @@ impl Wine {
             updater: receiver,
 
             worker: Some(std::thread::spawn(move || -> anyhow::Result<()> {
-                let downloader = Downloader::new(download_uri);
+                let downloader = Downloader::new(download_uri)
+                    .with_limiter(DownloadLimiter::new());
 
                 let path = COMPONENTS_FOLDER.join("wine");
                 let archive = path.join(downloader.file_name());
@@ -127,15 +137,11 @@ impl Wine {
 
                 // Download update archive
 
-                let mut updater = downloader.download(&archive)?;
+                retry::download(&downloader, &archive, &RetryPolicy::from_config(), |current, total| {
+                    sender.send((Status::Downloading, current, total))?;
 
-                while !updater.is_finished() {
-                    sender.send((
-                        Status::Downloading,
-                        updater.current(),
-                        updater.total()
-                    ))?;
-                }
+                    Ok(())
+                })?;
 
                 // Extract archive
 
@@ -195,4 +201,14 @@ impl QueuedTask for DownloadWineQueuedTask {
             updater: self.version.download()?
         }))
     }
+
+    #[inline]
+    fn get_kind(&self) -> TaskKind {
+        TaskKind::Download
+    }
+
+    #[inline]
+    fn get_locks(&self) -> Vec<PathBuf> {
+        vec![self.version.get_folder()]
+    }
 }
@@ -9,6 +9,11 @@ pub mod dxvk;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Components {
     pub channel: String,
+
+    /// Time in seconds during which cached components lists
+    /// are used without revalidating them in the channel
+    pub cache_ttl: u64,
+
     pub wine: wine::Wine,
     pub dxvk: dxvk::Dxvk
 }
@@ -23,6 +28,8 @@ impl Default for Components {
                 String::from("https://raw.githubusercontent.com/an-anime-team/components/main")
             },
 
+            cache_ttl: 6 * 60 * 60,
+
             wine: wine::Wine::default(),
             dxvk: dxvk::Dxvk::default()
         }
@@ -40,6 +47,10 @@ impl From<&Json> for Components {
                 .map(String::from)
                 .unwrap_or(default.channel),
 
+            cache_ttl: value.get("cache_ttl")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.cache_ttl),
+
             wine: value.get("wine")
                 .map(wine::Wine::from)
                 .unwrap_or(default.wine),
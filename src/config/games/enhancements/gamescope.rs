@@ -0,0 +1,442 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use super::hud::HUD;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GamescopeUpscaler {
+    /// `-S auto`
+    Auto,
+
+    /// `-S integer`
+    Integer,
+
+    /// `-S fit`
+    Fit,
+
+    /// `-S fill`
+    Fill,
+
+    /// `-S stretch`
+    Stretch
+}
+
+impl Default for GamescopeUpscaler {
+    #[inline]
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+impl From<&Json> for GamescopeUpscaler {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+impl GamescopeUpscaler {
+    #[inline]
+    pub fn list() -> [Self; 5] {
+        [
+            Self::Auto,
+            Self::Integer,
+            Self::Fit,
+            Self::Fill,
+            Self::Stretch
+        ]
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Auto    => "auto",
+            Self::Integer => "integer",
+            Self::Fit     => "fit",
+            Self::Fill    => "fill",
+            Self::Stretch => "stretch"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GamescopeFilter {
+    /// `-F linear`
+    Linear,
+
+    /// `-F nearest`
+    Nearest,
+
+    /// `-F fsr`
+    FSR,
+
+    /// `-F nis`
+    NIS,
+
+    /// `-F pixel`
+    Pixel
+}
+
+impl Default for GamescopeFilter {
+    #[inline]
+    fn default() -> Self {
+        Self::Linear
+    }
+}
+
+impl From<&Json> for GamescopeFilter {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+impl GamescopeFilter {
+    #[inline]
+    pub fn list() -> [Self; 5] {
+        [
+            Self::Linear,
+            Self::Nearest,
+            Self::FSR,
+            Self::NIS,
+            Self::Pixel
+        ]
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Linear  => "linear",
+            Self::Nearest => "nearest",
+            Self::FSR     => "fsr",
+            Self::NIS     => "nis",
+            Self::Pixel   => "pixel"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GamescopeWindowMode {
+    Windowed,
+
+    /// `-b`
+    Borderless,
+
+    /// `-f`
+    Fullscreen
+}
+
+impl Default for GamescopeWindowMode {
+    #[inline]
+    fn default() -> Self {
+        Self::Fullscreen
+    }
+}
+
+impl From<&Json> for GamescopeWindowMode {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GamescopeResolution {
+    pub width: u64,
+    pub height: u64
+}
+
+impl Default for GamescopeResolution {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            width: 0,
+            height: 0
+        }
+    }
+}
+
+impl From<&Json> for GamescopeResolution {
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            width: value.get("width")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.width),
+
+            height: value.get("height")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.height)
+        }
+    }
+}
+
+impl GamescopeResolution {
+    /// Check if the resolution is set. Otherwise gamescope will choose it itself
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        self.width > 0 && self.height > 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Gamescope {
+    pub enabled: bool,
+
+    /// Resolution of the gamescope window
+    /// 
+    /// `-W [width] -H [height]`
+    pub output: GamescopeResolution,
+
+    /// Resolution the game is rendered in
+    /// 
+    /// `-w [width] -h [height]`
+    pub internal: GamescopeResolution,
+
+    /// Frame rate limit, 0 if not set
+    /// 
+    /// `-r [refresh_rate]`
+    pub refresh_rate: u64,
+
+    pub upscaler: GamescopeUpscaler,
+    pub filter: GamescopeFilter,
+    pub window_mode: GamescopeWindowMode,
+
+    /// `--hdr-enabled`
+    pub hdr: bool,
+
+    /// Arguments passed to gamescope as is
+    pub extra_args: Vec<String>
+}
+
+impl Default for Gamescope {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            enabled: false,
+
+            output: GamescopeResolution::default(),
+            internal: GamescopeResolution::default(),
+            refresh_rate: 0,
+
+            upscaler: GamescopeUpscaler::default(),
+            filter: GamescopeFilter::default(),
+            window_mode: GamescopeWindowMode::default(),
+
+            hdr: false,
+            extra_args: Vec::new()
+        }
+    }
+}
+
+impl From<&Json> for Gamescope {
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            enabled: value.get("enabled")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.enabled),
+
+            output: value.get("output")
+                .map(GamescopeResolution::from)
+                .unwrap_or(default.output),
+
+            internal: value.get("internal")
+                .map(GamescopeResolution::from)
+                .unwrap_or(default.internal),
+
+            refresh_rate: value.get("refresh_rate")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.refresh_rate),
+
+            upscaler: value.get("upscaler")
+                .map(GamescopeUpscaler::from)
+                .unwrap_or(default.upscaler),
+
+            filter: value.get("filter")
+                .map(GamescopeFilter::from)
+                .unwrap_or(default.filter),
+
+            window_mode: value.get("window_mode")
+                .map(GamescopeWindowMode::from)
+                .unwrap_or(default.window_mode),
+
+            hdr: value.get("hdr")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.hdr),
+
+            extra_args: value.get("extra_args")
+                .and_then(Json::as_array)
+                .map(|args| args.iter()
+                    .filter_map(Json::as_str)
+                    .map(String::from)
+                    .collect())
+                .unwrap_or(default.extra_args)
+        }
+    }
+}
+
+impl Gamescope {
+    /// Get gamescope arguments, excluding the `--` separator
+    /// 
+    /// MangoHUD is replaced by the gamescope's own overlay (`--mangoapp`)
+    /// because the regular one is drawn twice inside of gamescope
+    pub fn get_args(&self, hud: HUD) -> Vec<String> {
+        let mut args = Vec::new();
+
+        if self.output.is_set() {
+            args.extend([
+                String::from("-W"), self.output.width.to_string(),
+                String::from("-H"), self.output.height.to_string()
+            ]);
+        }
+
+        if self.internal.is_set() {
+            args.extend([
+                String::from("-w"), self.internal.width.to_string(),
+                String::from("-h"), self.internal.height.to_string()
+            ]);
+        }
+
+        if self.refresh_rate > 0 {
+            args.extend([
+                String::from("-r"),
+                self.refresh_rate.to_string()
+            ]);
+        }
+
+        args.extend([
+            String::from("-S"), self.upscaler.name().to_string(),
+            String::from("-F"), self.filter.name().to_string()
+        ]);
+
+        match self.window_mode {
+            GamescopeWindowMode::Windowed   => (),
+            GamescopeWindowMode::Borderless => args.push(String::from("-b")),
+            GamescopeWindowMode::Fullscreen => args.push(String::from("-f"))
+        }
+
+        if self.hdr {
+            args.push(String::from("--hdr-enabled"));
+        }
+
+        if hud == HUD::MangoHUD {
+            args.push(String::from("--mangoapp"));
+        }
+
+        args.extend(self.extra_args.iter().cloned());
+
+        args
+    }
+
+    /// `gamescope [args] --`
+    pub fn get_command(&self, hud: HUD) -> Option<Vec<String>> {
+        if self.enabled {
+            let mut command = vec![String::from("gamescope")];
+
+            command.extend(self.get_args(hud));
+            command.push(String::from("--"));
+
+            Some(command)
+        }
+
+        else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn default_args() {
+        let gamescope = Gamescope::default();
+
+        assert_eq!(gamescope.get_args(HUD::None), args(&["-S", "auto", "-F", "linear", "-f"]));
+        assert_eq!(gamescope.get_command(HUD::None), None);
+    }
+
+    #[test]
+    fn resolutions_args() {
+        let gamescope = Gamescope {
+            output: GamescopeResolution { width: 2560, height: 1440 },
+            internal: GamescopeResolution { width: 1920, height: 1080 },
+            refresh_rate: 60,
+            ..Gamescope::default()
+        };
+
+        assert_eq!(gamescope.get_args(HUD::None), args(&[
+            "-W", "2560", "-H", "1440",
+            "-w", "1920", "-h", "1080",
+            "-r", "60",
+            "-S", "auto", "-F", "linear", "-f"
+        ]));
+
+        // Partially set resolution is ignored
+        let gamescope = Gamescope {
+            output: GamescopeResolution { width: 2560, height: 0 },
+            ..Gamescope::default()
+        };
+
+        assert_eq!(gamescope.get_args(HUD::None), args(&["-S", "auto", "-F", "linear", "-f"]));
+    }
+
+    #[test]
+    fn modes_args() {
+        let gamescope = Gamescope {
+            upscaler: GamescopeUpscaler::Integer,
+            filter: GamescopeFilter::FSR,
+            window_mode: GamescopeWindowMode::Borderless,
+            hdr: true,
+            ..Gamescope::default()
+        };
+
+        assert_eq!(gamescope.get_args(HUD::None), args(&["-S", "integer", "-F", "fsr", "-b", "--hdr-enabled"]));
+
+        let gamescope = Gamescope {
+            window_mode: GamescopeWindowMode::Windowed,
+            ..Gamescope::default()
+        };
+
+        assert_eq!(gamescope.get_args(HUD::None), args(&["-S", "auto", "-F", "linear"]));
+    }
+
+    #[test]
+    fn hud_and_extra_args() {
+        let gamescope = Gamescope {
+            extra_args: args(&["--expose-wayland", "--force-grab-cursor"]),
+            ..Gamescope::default()
+        };
+
+        assert_eq!(gamescope.get_args(HUD::MangoHUD), args(&[
+            "-S", "auto", "-F", "linear", "-f",
+            "--mangoapp",
+            "--expose-wayland", "--force-grab-cursor"
+        ]));
+
+        // DXVK HUD is drawn by the game itself
+        assert_eq!(gamescope.get_args(HUD::DXVK), args(&[
+            "-S", "auto", "-F", "linear", "-f",
+            "--expose-wayland", "--force-grab-cursor"
+        ]));
+    }
+
+    #[test]
+    fn command() {
+        let gamescope = Gamescope {
+            enabled: true,
+            window_mode: GamescopeWindowMode::Windowed,
+            ..Gamescope::default()
+        };
+
+        assert_eq!(gamescope.get_command(HUD::None), Some(args(&["gamescope", "-S", "auto", "-F", "linear", "--"])));
+    }
+}
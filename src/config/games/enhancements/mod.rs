@@ -3,6 +3,7 @@ use serde_json::Value as Json;
 
 pub mod hud;
 pub mod fsr;
+pub mod gamescope;
 
 pub mod prelude {
     pub use super::hud::HUD;
@@ -11,6 +12,14 @@ pub mod prelude {
         FsrQuality
     };
 
+    pub use super::gamescope::{
+        Gamescope,
+        GamescopeResolution,
+        GamescopeUpscaler,
+        GamescopeFilter,
+        GamescopeWindowMode
+    };
+
     pub use super::Enhancements;
 }
 
@@ -20,7 +29,13 @@ use prelude::*;
 pub struct Enhancements {
     pub hud: HUD,
     pub fsr: FSR,
-    pub gamemode: bool
+    pub gamemode: bool,
+    pub gamescope: Gamescope,
+
+    /// Commands which run the game, executed in the given order
+    /// 
+    /// Each wrapper is stored with its arguments, e.g. `["prime-run"]`
+    pub wrappers: Vec<Vec<String>>
 }
 
 impl Default for Enhancements {
@@ -29,7 +44,9 @@ impl Default for Enhancements {
         Self {
             hud: HUD::default(),
             fsr: FSR::default(),
-            gamemode: false
+            gamemode: false,
+            gamescope: Gamescope::default(),
+            wrappers: Vec::new()
         }
     }
 }
@@ -50,7 +67,23 @@ impl From<&Json> for Enhancements {
 
             gamemode: value.get("gamemode")
                 .and_then(Json::as_bool)
-                .unwrap_or(default.gamemode)
+                .unwrap_or(default.gamemode),
+
+            gamescope: value.get("gamescope")
+                .map(Gamescope::from)
+                .unwrap_or(default.gamescope),
+
+            wrappers: value.get("wrappers")
+                .and_then(Json::as_array)
+                .map(|wrappers| wrappers.iter()
+                    .filter_map(Json::as_array)
+                    .map(|wrapper| wrapper.iter()
+                        .filter_map(Json::as_str)
+                        .map(String::from)
+                        .collect::<Vec<_>>())
+                    .filter(|wrapper| !wrapper.is_empty())
+                    .collect())
+                .unwrap_or(default.wrappers)
         }
     }
 }
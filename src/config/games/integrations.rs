@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use serde::{Serialize, Deserialize};
@@ -9,7 +10,23 @@ use crate::LAUNCHER_FOLDER;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Integrations {
     pub sources: Vec<String>,
-    pub path: PathBuf
+    pub path: PathBuf,
+
+    /// Run integration scripts in the sandbox
+    /// with capabilities requested by their manifests
+    pub sandbox: bool,
+
+    /// Hex-encoded ed25519 public keys trusted for each source
+    pub trusted_keys: HashMap<String, Vec<String>>,
+
+    /// What to do with unsigned or wrongly signed integrations
+    pub signatures: SignaturePolicy,
+
+    /// Amount of previous integrations versions to keep
+    pub keep_versions: u64,
+
+    /// Integrations versions used instead of the latest ones
+    pub pinned: HashMap<String, String>
 }
 
 impl Default for Integrations {
@@ -26,7 +43,15 @@ impl Default for Integrations {
                 ]
             },
 
-            path: LAUNCHER_FOLDER.join("integrations")
+            path: LAUNCHER_FOLDER.join("integrations"),
+
+            sandbox: false,
+
+            trusted_keys: HashMap::new(),
+            signatures: SignaturePolicy::default(),
+
+            keep_versions: 3,
+            pinned: HashMap::new()
         }
     }
 }
@@ -49,7 +74,82 @@ impl From<&Json> for Integrations {
             path: value.get("path")
                 .and_then(Json::as_str)
                 .map(PathBuf::from)
-                .unwrap_or(default.path)
+                .unwrap_or(default.path),
+
+            sandbox: value.get("sandbox")
+                .and_then(Json::as_bool)
+                .unwrap_or(default.sandbox),
+
+            trusted_keys: value.get("trusted_keys")
+                .and_then(Json::as_object)
+                .map(|sources| sources.iter()
+                    .map(|(source, keys)| {
+                        let keys = keys.as_array()
+                            .map(|keys| keys.iter()
+                                .filter_map(|key| key.as_str().map(String::from))
+                                .collect()
+                            )
+                            .unwrap_or_default();
+
+                        (source.to_string(), keys)
+                    })
+                    .collect::<HashMap<_, _>>()
+                )
+                .unwrap_or(default.trusted_keys),
+
+            signatures: value.get("signatures")
+                .map(SignaturePolicy::from)
+                .unwrap_or(default.signatures),
+
+            keep_versions: value.get("keep_versions")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.keep_versions),
+
+            pinned: value.get("pinned")
+                .and_then(Json::as_object)
+                .map(|pinned| pinned.iter()
+                    .filter_map(|(game, version)| {
+                        version.as_str().map(|version| (game.to_string(), version.to_string()))
+                    })
+                    .collect::<HashMap<_, _>>()
+                )
+                .unwrap_or(default.pinned)
         }
     }
 }
+
+impl Integrations {
+    /// Get trusted keys of the integrations source
+    pub fn get_trusted_keys(&self, source: &str) -> &[String] {
+        self.trusted_keys.get(source.trim_end_matches('/'))
+            .or_else(|| self.trusted_keys.get(source))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SignaturePolicy {
+    /// Don't verify signatures
+    Ignore,
+
+    /// Log a warning and install unsigned or wrongly signed integrations
+    Warn,
+
+    /// Refuse to install unsigned or wrongly signed integrations
+    Require
+}
+
+impl Default for SignaturePolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::Warn
+    }
+}
+
+impl From<&Json> for SignaturePolicy {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        serde_json::from_value(value.clone()).unwrap_or_default()
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Logs {
+    /// Value of the `WINEDEBUG` variable
+    /// 
+    /// Not set if empty
+    pub wine_debug: String,
+
+    /// Amount of game sessions logs to keep
+    pub keep_sessions: u64
+}
+
+impl Default for Logs {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            wine_debug: String::from("fixme-all"),
+            keep_sessions: 10
+        }
+    }
+}
+
+impl From<&Json> for Logs {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            wine_debug: value.get("wine_debug")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.wine_debug),
+
+            keep_sessions: value.get("keep_sessions")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.keep_sessions)
+        }
+    }
+}
@@ -10,13 +10,15 @@ pub mod wine;
 pub mod enhancements;
 pub mod integrations;
 pub mod settings;
+pub mod logs;
 
 pub mod prelude {
     pub use super::wine::prelude::*;
     pub use super::enhancements::prelude::*;
     pub use super::settings::prelude::*;
 
-    pub use super::integrations::Integrations;
+    pub use super::integrations::{Integrations, SignaturePolicy};
+    pub use super::logs::Logs;
 
     pub use super::Games;
 }
@@ -29,6 +31,7 @@ pub struct Games {
     pub enhancements: Enhancements,
     pub environment: HashMap<String, String>,
     pub integrations: Integrations,
+    pub logs: Logs,
 
     settings: Json
 }
@@ -41,6 +44,7 @@ impl Default for Games {
             enhancements: Enhancements::default(),
             environment: HashMap::new(),
             integrations: Integrations::default(),
+            logs: Logs::default(),
             settings: Json::Object(serde_json::Map::default())
         }
     }
@@ -74,6 +78,10 @@ impl From<&Json> for Games {
                 .map(Integrations::from)
                 .unwrap_or(default.integrations),
 
+            logs: value.get("logs")
+                .map(Logs::from)
+                .unwrap_or(default.logs),
+
             settings: value.get("settings")
                 .cloned()
                 .unwrap_or(default.settings)
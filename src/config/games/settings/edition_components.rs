@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use crate::config::components::Components;
+
+/// Components used by the game edition instead of the global ones
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameEditionComponents {
+    pub wine_build: Option<String>,
+    pub wine_version: Option<String>,
+
+    pub prefix_path: Option<PathBuf>,
+    pub install_corefonts: Option<bool>,
+
+    pub dxvk_build: Option<String>,
+    pub dxvk_version: Option<String>
+}
+
+impl GameEditionComponents {
+    /// Check if the game edition overrides any of the global components
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Apply overrides to the global components settings
+    pub fn apply(&self, components: &Components) -> Components {
+        let mut components = components.clone();
+
+        if let Some(build) = &self.wine_build {
+            components.wine.build = build.clone();
+        }
+
+        if let Some(version) = &self.wine_version {
+            components.wine.version = version.clone();
+        }
+
+        if let Some(path) = &self.prefix_path {
+            components.wine.prefix.path = path.clone();
+        }
+
+        if let Some(install_corefonts) = self.install_corefonts {
+            components.wine.prefix.install_corefonts = install_corefonts;
+        }
+
+        if let Some(build) = &self.dxvk_build {
+            components.dxvk.build = build.clone();
+        }
+
+        if let Some(version) = &self.dxvk_version {
+            components.dxvk.version = version.clone();
+        }
+
+        components
+    }
+}
+
+impl From<&Json> for GameEditionComponents {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        Self {
+            wine_build: value.get("wine_build")
+                .and_then(Json::as_str)
+                .map(String::from),
+
+            wine_version: value.get("wine_version")
+                .and_then(Json::as_str)
+                .map(String::from),
+
+            prefix_path: value.get("prefix_path")
+                .and_then(Json::as_str)
+                .map(PathBuf::from),
+
+            install_corefonts: value.get("install_corefonts")
+                .and_then(Json::as_bool),
+
+            dxvk_build: value.get("dxvk_build")
+                .and_then(Json::as_str)
+                .map(String::from),
+
+            dxvk_version: value.get("dxvk_version")
+                .and_then(Json::as_str)
+                .map(String::from)
+        }
+    }
+}
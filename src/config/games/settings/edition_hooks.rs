@@ -0,0 +1,33 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+/// Shell commands executed around the game edition launch
+/// 
+/// Hooks are run by `bash -c` with the game's environment
+/// and `GAME_*` variables exported
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameEditionHooks {
+    /// Command executed before the game launch. Game is not
+    /// launched if the command exits with non-zero code
+    pub pre_launch: Option<String>,
+
+    /// Command executed after the game process is closed
+    pub post_exit: Option<String>
+}
+
+impl From<&Json> for GameEditionHooks {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        Self {
+            pre_launch: value.get("pre_launch")
+                .and_then(Json::as_str)
+                .filter(|command| !command.trim().is_empty())
+                .map(String::from),
+
+            post_exit: value.get("post_exit")
+                .and_then(Json::as_str)
+                .filter(|command| !command.trim().is_empty())
+                .map(String::from)
+        }
+    }
+}
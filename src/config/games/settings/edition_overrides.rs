@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use crate::config::games::Games;
+use crate::config::games::wine::prelude::*;
+use crate::config::games::enhancements::prelude::*;
+
+/// Enhancements and wine settings used by the game edition
+/// instead of the global ones
+/// 
+/// Values which are not set are inherited from the global settings
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameEditionOverrides {
+    pub hud: Option<HUD>,
+    pub fsr: Option<FSR>,
+    pub gamemode: Option<bool>,
+
+    pub sync: Option<WineSync>,
+    pub language: Option<WineLang>,
+    pub borderless: Option<bool>,
+    pub virtual_desktop: Option<VirtualDesktop>,
+
+    /// Environment variables set after the global ones
+    pub environment: HashMap<String, String>
+}
+
+impl GameEditionOverrides {
+    /// Apply overrides to the global games settings
+    pub fn apply(&self, games: &Games) -> Games {
+        let mut games = games.clone();
+
+        if let Some(hud) = self.hud {
+            games.enhancements.hud = hud;
+        }
+
+        if let Some(fsr) = self.fsr {
+            games.enhancements.fsr = fsr;
+        }
+
+        if let Some(gamemode) = self.gamemode {
+            games.enhancements.gamemode = gamemode;
+        }
+
+        if let Some(sync) = self.sync {
+            games.wine.sync = sync;
+        }
+
+        if let Some(language) = self.language {
+            games.wine.language = language;
+        }
+
+        if let Some(borderless) = self.borderless {
+            games.wine.borderless = borderless;
+        }
+
+        if let Some(virtual_desktop) = self.virtual_desktop {
+            games.wine.virtual_desktop = virtual_desktop;
+        }
+
+        games.environment.extend(self.environment.clone());
+
+        games
+    }
+}
+
+/// Get override value unless it's not set or explicitly removed
+#[inline]
+fn get_value<'a>(value: &'a Json, name: &str) -> Option<&'a Json> {
+    value.get(name).filter(|value| !value.is_null())
+}
+
+impl From<&Json> for GameEditionOverrides {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        Self {
+            hud: get_value(value, "hud").map(HUD::from),
+            fsr: get_value(value, "fsr").map(FSR::from),
+            gamemode: get_value(value, "gamemode").and_then(Json::as_bool),
+
+            sync: get_value(value, "sync").map(WineSync::from),
+            language: get_value(value, "language").map(WineLang::from),
+            borderless: get_value(value, "borderless").and_then(Json::as_bool),
+            virtual_desktop: get_value(value, "virtual_desktop").map(VirtualDesktop::from),
+
+            environment: value.get("environment")
+                .and_then(Json::as_object)
+                .map(|object| object.into_iter()
+                    .filter_map(|(key, value)| {
+                        value.as_str().map(|value| (key.to_string(), value.to_string()))
+                    })
+                    .collect::<HashMap<_, _>>()
+                )
+                .unwrap_or_default()
+        }
+    }
+}
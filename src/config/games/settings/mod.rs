@@ -3,12 +3,21 @@ use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 use serde_json::Value as Json;
 
+use crate::config::components::Components;
+use crate::config::games::Games;
+
 pub mod edition_addons;
 pub mod edition_paths;
+pub mod edition_components;
+pub mod edition_overrides;
+pub mod edition_hooks;
 
 pub mod prelude {
     pub use super::edition_addons::GameEditionAddon;
     pub use super::edition_paths::GameEditionPaths;
+    pub use super::edition_components::GameEditionComponents;
+    pub use super::edition_overrides::GameEditionOverrides;
+    pub use super::edition_hooks::GameEditionHooks;
     pub use super::GameSettings;
 }
 
@@ -17,7 +26,10 @@ use prelude::*;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GameSettings {
     pub paths: HashMap<String, GameEditionPaths>,
-    pub addons: HashMap<String, Vec<GameEditionAddon>>
+    pub addons: HashMap<String, Vec<GameEditionAddon>>,
+    pub components: HashMap<String, GameEditionComponents>,
+    pub overrides: HashMap<String, GameEditionOverrides>,
+    pub hooks: HashMap<String, GameEditionHooks>
 }
 
 impl GameSettings {
@@ -34,9 +46,27 @@ impl GameSettings {
                 }).collect(),
 
             addons: edition_names
+                .clone()
                 .into_iter()
                 .map(|edition| (edition.as_ref().to_string(), vec![]))
                 .collect::<HashMap<_, _>>(),
+
+            components: edition_names
+                .clone()
+                .into_iter()
+                .map(|edition| (edition.as_ref().to_string(), GameEditionComponents::default()))
+                .collect::<HashMap<_, _>>(),
+
+            overrides: edition_names
+                .clone()
+                .into_iter()
+                .map(|edition| (edition.as_ref().to_string(), GameEditionOverrides::default()))
+                .collect::<HashMap<_, _>>(),
+
+            hooks: edition_names
+                .into_iter()
+                .map(|edition| (edition.as_ref().to_string(), GameEditionHooks::default()))
+                .collect::<HashMap<_, _>>()
         })
     }
 
@@ -63,9 +93,46 @@ impl GameSettings {
             }
         }
 
+        if let Some(values) = value.get("components").and_then(Json::as_object) {
+            for (edition, components) in values {
+                default.components.insert(edition.clone(), GameEditionComponents::from(components));
+            }
+        }
+
+        if let Some(values) = value.get("overrides").and_then(Json::as_object) {
+            for (edition, overrides) in values {
+                default.overrides.insert(edition.clone(), GameEditionOverrides::from(overrides));
+            }
+        }
+
+        if let Some(values) = value.get("hooks").and_then(Json::as_object) {
+            for (edition, hooks) in values {
+                default.hooks.insert(edition.clone(), GameEditionHooks::from(hooks));
+            }
+        }
+
         Ok(Self {
             paths: default.paths,
-            addons: default.addons
+            addons: default.addons,
+            components: default.components,
+            overrides: default.overrides,
+            hooks: default.hooks
         })
     }
+
+    /// Get components used by the game edition
+    pub fn get_components(&self, edition: impl AsRef<str>, components: &Components) -> Components {
+        match self.components.get(edition.as_ref()) {
+            Some(overrides) => overrides.apply(components),
+            None => components.clone()
+        }
+    }
+
+    /// Get games settings with the game edition overrides applied
+    pub fn apply_overrides(&self, edition: impl AsRef<str>, games: &Games) -> Games {
+        match self.overrides.get(edition.as_ref()) {
+            Some(overrides) => overrides.apply(games),
+            None => games.clone()
+        }
+    }
 }
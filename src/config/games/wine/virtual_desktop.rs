@@ -42,9 +42,12 @@ impl From<&Json> for VirtualDesktop {
 impl VirtualDesktop {
     #[inline]
     /// `explorer /desktop=[desktop_name],[width]x[height]`
-    pub fn get_command(&self, desktop_name: impl AsRef<str>) -> Option<String> {
+    pub fn get_command(&self, desktop_name: impl AsRef<str>) -> Option<Vec<String>> {
         if self.enabled {
-            Some(format!("explorer /desktop={},{}x{}", desktop_name.as_ref(), self.width, self.height))
+            Some(vec![
+                String::from("explorer"),
+                format!("/desktop={},{}x{}", desktop_name.as_ref(), self.width, self.height)
+            ])
         }
 
         else {
@@ -0,0 +1,204 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use chrono::NaiveTime;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bandwidth {
+    /// Max download speed of all the tasks together,
+    /// in bytes per second. `0` means no limit
+    pub global_limit: u64,
+
+    /// Max download speed of a single task,
+    /// in bytes per second. `0` means no limit
+    pub task_limit: u64,
+
+    /// Time of the day when large downloads are allowed to run.
+    /// `None` means they can run at any time
+    pub large_downloads: Option<DownloadWindow>
+}
+
+impl Default for Bandwidth {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            global_limit: 0,
+            task_limit: 0,
+            large_downloads: None
+        }
+    }
+}
+
+impl From<&Json> for Bandwidth {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            global_limit: value.get("global_limit")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.global_limit),
+
+            task_limit: value.get("task_limit")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.task_limit),
+
+            large_downloads: value.get("large_downloads")
+                .filter(|value| !value.is_null())
+                .map(DownloadWindow::from)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownloadWindow {
+    /// Downloads smaller than this size, in bytes, can run at any time
+    pub min_size: u64,
+
+    /// Window start time in `HH:MM` format
+    pub start: String,
+
+    /// Window end time in `HH:MM` format. Can be
+    /// less than the start time to cross midnight
+    pub end: String
+}
+
+impl Default for DownloadWindow {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            min_size: 1024 * 1024 * 1024,
+            start: String::from("01:00"),
+            end: String::from("07:00")
+        }
+    }
+}
+
+impl From<&Json> for DownloadWindow {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            min_size: value.get("min_size")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.min_size),
+
+            start: value.get("start")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.start),
+
+            end: value.get("end")
+                .and_then(Json::as_str)
+                .map(String::from)
+                .unwrap_or(default.end)
+        }
+    }
+}
+
+impl DownloadWindow {
+    /// Check if download of the given size can run at the given time
+    /// 
+    /// Downloads are not restricted if the window has wrong format
+    pub fn allows(&self, size: u64, time: NaiveTime) -> bool {
+        if size < self.min_size {
+            return true;
+        }
+
+        let start = NaiveTime::parse_from_str(&self.start, "%H:%M");
+        let end = NaiveTime::parse_from_str(&self.end, "%H:%M");
+
+        let (Ok(start), Ok(end)) = (start, end) else {
+            tracing::warn!("Wrong large downloads window format: {} - {}", self.start, self.end);
+
+            return true;
+        };
+
+        if start <= end {
+            start <= time && time < end
+        }
+
+        else {
+            time >= start || time < end
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(start: &str, end: &str) -> DownloadWindow {
+        DownloadWindow {
+            min_size: 1000,
+            start: start.to_string(),
+            end: end.to_string()
+        }
+    }
+
+    fn time(time: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(time, "%H:%M").unwrap()
+    }
+
+    #[test]
+    fn small_downloads() {
+        let window = window("01:00", "07:00");
+
+        assert!(window.allows(0, time("12:00")));
+        assert!(window.allows(999, time("12:00")));
+
+        assert!(!window.allows(1000, time("12:00")));
+    }
+
+    #[test]
+    fn same_day_window() {
+        let window = window("01:00", "07:00");
+
+        assert!(window.allows(1000, time("01:00")));
+        assert!(window.allows(1000, time("04:30")));
+        assert!(window.allows(1000, time("06:59")));
+
+        assert!(!window.allows(1000, time("00:59")));
+        assert!(!window.allows(1000, time("07:00")));
+        assert!(!window.allows(1000, time("23:00")));
+    }
+
+    #[test]
+    fn past_midnight_window() {
+        let window = window("23:00", "06:00");
+
+        assert!(window.allows(1000, time("23:00")));
+        assert!(window.allows(1000, time("23:59")));
+        assert!(window.allows(1000, time("00:00")));
+        assert!(window.allows(1000, time("05:59")));
+
+        assert!(!window.allows(1000, time("06:00")));
+        assert!(!window.allows(1000, time("12:00")));
+        assert!(!window.allows(1000, time("22:59")));
+    }
+
+    #[test]
+    fn wrong_format() {
+        assert!(window("1 AM", "07:00").allows(1000, time("12:00")));
+        assert!(window("01:00", "").allows(1000, time("12:00")));
+    }
+
+    #[test]
+    fn from_json() {
+        let window = DownloadWindow::from(&serde_json::json!({
+            "min_size": 1000,
+            "start": "23:00"
+        }));
+
+        assert_eq!(window.min_size, 1000);
+        assert_eq!(window.start, "23:00");
+        assert_eq!(window.end, DownloadWindow::default().end);
+
+        let bandwidth = Bandwidth::from(&serde_json::json!({
+            "large_downloads": null
+        }));
+
+        assert_eq!(bandwidth.large_downloads, None);
+    }
+}
@@ -5,10 +5,16 @@ use crate::i18n;
 
 pub mod transitions;
 pub mod threads;
+pub mod tasks;
+pub mod bandwidth;
+pub mod retries;
 
 pub mod prelude {
     pub use super::transitions::Transitions;
     pub use super::threads::Threads;
+    pub use super::tasks::Tasks;
+    pub use super::bandwidth::{Bandwidth, DownloadWindow};
+    pub use super::retries::Retries;
     pub use super::General;
 }
 
@@ -18,6 +24,9 @@ use prelude::*;
 pub struct General {
     pub transitions: Transitions,
     pub threads: Threads,
+    pub tasks: Tasks,
+    pub bandwidth: Bandwidth,
+    pub retries: Retries,
     pub language: String,
     pub verify_games: bool
 }
@@ -28,6 +37,9 @@ impl Default for General {
         Self {
             transitions: Transitions::default(),
             threads: Threads::default(),
+            tasks: Tasks::default(),
+            bandwidth: Bandwidth::default(),
+            retries: Retries::default(),
             language: i18n::format_language(&i18n::get_default_language()),
             verify_games: true
         }
@@ -48,6 +60,18 @@ impl From<&Json> for General {
                 .map(Threads::from)
                 .unwrap_or(default.threads),
 
+            tasks: value.get("tasks")
+                .map(Tasks::from)
+                .unwrap_or(default.tasks),
+
+            bandwidth: value.get("bandwidth")
+                .map(Bandwidth::from)
+                .unwrap_or(default.bandwidth),
+
+            retries: value.get("retries")
+                .map(Retries::from)
+                .unwrap_or(default.retries),
+
             language: value.get("language")
                 .and_then(Json::as_str)
                 .map(String::from)
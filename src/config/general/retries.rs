@@ -0,0 +1,63 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Retries {
+    /// Max amount of attempts to download a file
+    pub max_attempts: u64,
+
+    /// Delay before the first retry, in milliseconds.
+    /// Doubled after each failed attempt
+    pub backoff_delay: u64,
+
+    /// Max delay between attempts, in milliseconds
+    pub max_backoff_delay: u64,
+
+    /// Max time to wait for the server to start sending a file, in seconds
+    pub request_timeout: u64,
+
+    /// Max time without downloading progress, in seconds
+    pub stall_timeout: u64
+}
+
+impl Default for Retries {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            backoff_delay: 1000,
+            max_backoff_delay: 30000,
+            request_timeout: 30,
+            stall_timeout: 60
+        }
+    }
+}
+
+impl From<&Json> for Retries {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            max_attempts: value.get("max_attempts")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.max_attempts),
+
+            backoff_delay: value.get("backoff_delay")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.backoff_delay),
+
+            max_backoff_delay: value.get("max_backoff_delay")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.max_backoff_delay),
+
+            request_timeout: value.get("request_timeout")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.request_timeout),
+
+            stall_timeout: value.get("stall_timeout")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.stall_timeout)
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tasks {
+    /// Max amount of downloading tasks running at the same time
+    pub max_downloads: u64,
+
+    /// Max amount of disk-heavy tasks (files verification,
+    /// prefix creation, etc.) running at the same time
+    pub max_disk_tasks: u64
+}
+
+impl Default for Tasks {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            max_downloads: 2,
+            max_disk_tasks: 1
+        }
+    }
+}
+
+impl From<&Json> for Tasks {
+    #[inline]
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        Self {
+            max_downloads: value.get("max_downloads")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.max_downloads),
+
+            max_disk_tasks: value.get("max_disk_tasks")
+                .and_then(Json::as_u64)
+                .unwrap_or(default.max_disk_tasks)
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use serde_json::Value as Json;
+
+/// Capabilities requested by the integration script
+/// 
+/// They're enforced only when the sandbox mode is enabled
+/// in the launcher settings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Capabilities {
+    /// List of hosts the script can send requests to
+    /// 
+    /// `*.example.com` allows any subdomain of `example.com`
+    pub network: Vec<String>,
+
+    /// Allow access to the game, addons and transition folders
+    /// given to the called function
+    pub filesystem: bool,
+
+    /// Allow running processes (`os.execute` and `io.popen`)
+    pub process: bool
+}
+
+impl Capabilities {
+    pub fn from_json(value: &Json) -> anyhow::Result<Self> {
+        Ok(Self {
+            network: match value.get("network") {
+                Some(hosts) => hosts.as_array()
+                    .ok_or_else(|| anyhow::anyhow!("Wrong manifest structure: field `capabilities.network` must be a list of hosts"))?
+                    .iter()
+                    .map(|host| host.as_str()
+                        .map(|host| host.to_ascii_lowercase())
+                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest structure: field `capabilities.network` must be a list of hosts")))
+                    .collect::<anyhow::Result<Vec<_>>>()?,
+
+                None => vec![]
+            },
+
+            filesystem: value.get("filesystem")
+                .and_then(Json::as_bool)
+                .unwrap_or_default(),
+
+            process: value.get("process")
+                .and_then(Json::as_bool)
+                .unwrap_or_default()
+        })
+    }
+
+    /// Check if the script can send requests to the given host
+    pub fn is_host_allowed(&self, host: impl AsRef<str>) -> bool {
+        let host = host.as_ref().to_ascii_lowercase();
+
+        self.network.iter().any(|allowed| {
+            match allowed.strip_prefix("*.") {
+                Some(domain) => host.ends_with(&format!(".{domain}")),
+                None => allowed == &host
+            }
+        })
+    }
+}
+
+/// Get host name from the URI
+/// 
+/// `https://user@example.com:8080/path` -> `example.com`
+pub fn get_uri_host(uri: &str) -> Option<&str> {
+    let (_, uri) = uri.split_once("://")?;
+
+    let authority = uri.split(['/', '?', '#'])
+        .next()?;
+
+    let host = authority.rsplit_once('@')
+        .map(|(_, host)| host)
+        .unwrap_or(authority);
+
+    // [::1]:8080
+    if let Some(host) = host.strip_prefix('[') {
+        return host.split_once(']').map(|(host, _)| host);
+    }
+
+    host.split(':').next()
+}
@@ -1,101 +1,278 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, Condvar, PoisonError};
+
 use serde_json::Value as Json;
 
 use mlua::prelude::*;
 
 use super::standards::prelude::*;
+use super::standards::v2;
+use super::capabilities::Capabilities;
+use super::sandbox;
+use super::modules;
 
-#[derive(Debug)]
 pub struct Driver {
     pub game_name: String,
     pub standard: IntegrationStandard,
-    pub lua: Lua
+
+    /// Capabilities granted to the sandboxed script
+    /// 
+    /// Script is not sandboxed if `None`
+    pub sandbox: Option<Capabilities>,
+
+    /// Integration script source used to spawn new lua VMs
+    script: String,
+
+    /// Folder used to resolve `require` calls
+    modules_path: Option<PathBuf>,
+
+    /// Lua VMs pool
+    /// 
+    /// Every call takes its own VM from this pool so concurrent
+    /// calls from different threads never share the same lua state
+    pool: Mutex<LuaPool>,
+
+    /// Notified when a VM is returned to the pool
+    available: Condvar,
+
+    /// Max amount of spawned lua VMs
+    max_vms: usize
 }
 
-impl Driver {
-    pub fn new(game_name: impl ToString, standard: IntegrationStandard, script: impl AsRef<str>) -> anyhow::Result<Self> {
-        let lua = Lua::new();
+struct LuaPool {
+    /// Idle lua VMs
+    idle: Vec<Lua>,
 
-        match standard {
-            IntegrationStandard::V1 => {
-                lua.globals().set("v1_network_fetch", lua.create_function(|lua, (uri, options): (String, Option<RequestOptions>)| {
-                    let (method, headers, body, timeout) = match options {
-                        Some(options) => {
-                            let method = options.method.unwrap_or(RequestMethod::Get);
+    /// Amount of spawned VMs, both idle and busy
+    spawned: usize
+}
 
-                            (method, options.headers, options.body, options.timeout)
-                        }
+impl std::fmt::Debug for Driver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Driver")
+            .field("game_name", &self.game_name)
+            .field("standard", &self.standard)
+            .field("sandbox", &self.sandbox)
+            .finish()
+    }
+}
 
-                        None => (RequestMethod::Get, None, None, None)
-                    };
+/// Perform HTTP request and convert its response to a lua table
+fn network_fetch<'lua>(lua: &'lua Lua, uri: String, options: Option<RequestOptions>, capabilities: Option<&Capabilities>) -> LuaResult<LuaTable<'lua>> {
+    if let Some(capabilities) = capabilities {
+        sandbox::check_uri(capabilities, &uri)?;
+    }
 
-                    let mut request = anime_game_core::network::minreq::Request::new(method, uri);
+    let (method, headers, body, timeout) = match options {
+        Some(options) => {
+            let method = options.method.unwrap_or(RequestMethod::Get);
 
-                    if let Some(headers) = headers {
-                        for (key, value) in headers {
-                            request = request.with_header(key, value);
-                        }
-                    }
+            (method, options.headers, options.body, options.timeout)
+        }
 
-                    if let Some(body) = body {
-                        request = request.with_body(body);
-                    }
+        None => (RequestMethod::Get, None, None, None)
+    };
 
-                    if let Some(timeout) = timeout {
-                        request = request.with_timeout(timeout);
-                    }
+    let mut request = crate::network::Request::new(method, uri);
 
-                    request.send()
-                        .map(|response| {
-                            let result = lua.create_table()?;
-                            let headers = lua.create_table()?;
-
-                            for (key, value) in &response.headers {
-                                headers.set(key.as_str(), value.as_str())?;
-                            }
-
-                            result.set("url", response.url.as_str())?;
-                            result.set("status", response.status_code)?;
-                            result.set("statusText", response.reason_phrase.as_str())?;
-                            result.set("ok", (200..300).contains(&response.status_code))?;
-                            result.set("headers", headers)?;
-                            result.set("body", lua.create_string(response.as_bytes())?)?;
-
-                            result.set("json", lua.create_function(move |lua, _: ()| {
-                                response.json::<Json>()
-                                    .map(|value| lua.to_value(&value))
-                                    .map_err(LuaError::external)
-                            })?)?;
-
-                            Ok::<LuaTable<'_>, mlua::Error>(result)
-                        })
-                        .map_err(LuaError::external)
-                })?)?;
-        
-                lua.globals().set("v1_json_decode", lua.create_function(|lua, json: String| {
-                    serde_json::from_str::<Json>(&json)
-                        .map(|value| lua.to_value(&value))
-                        .map_err(LuaError::external)
-                })?)?;
-            }
+    if let Some(headers) = headers {
+        for (key, value) in headers {
+            request = request.with_header(key, value);
         }
+    }
 
-        lua.load(script.as_ref()).exec()?;
+    if let Some(body) = body {
+        request = request.with_body(body);
+    }
+
+    if let Some(timeout) = timeout {
+        request = request.with_timeout(timeout);
+    }
+
+    request.send()
+        .map(|response| {
+            let result = lua.create_table()?;
+            let headers = lua.create_table()?;
+
+            for (key, value) in &response.headers {
+                headers.set(key.as_str(), value.as_str())?;
+            }
+
+            result.set("url", response.url.as_str())?;
+            result.set("status", response.status_code)?;
+            result.set("statusText", response.reason_phrase.as_str())?;
+            result.set("ok", response.is_ok())?;
+            result.set("headers", headers)?;
+            result.set("body", lua.create_string(response.as_bytes())?)?;
+
+            result.set("json", lua.create_function(move |lua, _: ()| {
+                response.json::<Json>()
+                    .map(|value| lua.to_value(&value))
+                    .map_err(LuaError::external)
+            })?)?;
+
+            Ok::<LuaTable<'_>, mlua::Error>(result)
+        })
+        .map_err(LuaError::external)?
+}
+
+/// Decode JSON string into a lua value
+fn json_decode(lua: &Lua, json: String) -> LuaResult<LuaValue> {
+    serde_json::from_str::<Json>(&json)
+        .map(|value| lua.to_value(&value))
+        .map_err(LuaError::external)?
+}
+
+impl Driver {
+    pub fn new(game_name: impl ToString, standard: IntegrationStandard, sandbox: Option<Capabilities>, script: impl ToString, modules_path: Option<PathBuf>) -> anyhow::Result<Self> {
+        let script = script.to_string();
+
+        // Spawn the first VM right away to verify that the script is correct
+        let lua = Self::spawn_lua(standard, sandbox.as_ref(), &script, modules_path.as_deref())?;
 
         Ok(Self {
             game_name: game_name.to_string(),
             standard,
-            lua
+            sandbox,
+            script,
+            modules_path,
+
+            pool: Mutex::new(LuaPool {
+                idle: vec![lua],
+                spawned: 1
+            }),
+
+            available: Condvar::new(),
+            max_vms: (crate::config::get().general.threads.number as usize).max(1)
         })
     }
-}
 
-// Let (at least for now) lua scripts maintainers resolve
-// possible data races themselves
-// 
-// FIXME: use Mutex or RwLock, or anything else but please remove this shit
+    /// Create new lua VM with loaded integration script
+    fn spawn_lua(standard: IntegrationStandard, sandbox: Option<&Capabilities>, script: &str, modules_path: Option<&Path>) -> anyhow::Result<Lua> {
+        let lua = match sandbox {
+            Some(capabilities) => sandbox::create_lua(capabilities)?,
+            None => Lua::new()
+        };
+
+        if let Some(modules_path) = modules_path {
+            modules::install_searcher(&lua, modules_path)?;
+        }
+
+        let capabilities = sandbox.cloned();
+
+        let fetch = lua.create_function(move |lua, (uri, options): (String, Option<RequestOptions>)| {
+            network_fetch(lua, uri, options, capabilities.as_ref())
+        })?;
+
+        let decode = lua.create_function(json_decode)?;
+
+        match standard {
+            IntegrationStandard::V1 => {
+                lua.globals().set("v1_network_fetch", fetch)?;
+                lua.globals().set("v1_json_decode", decode)?;
+
+                lua.load(script).exec()?;
+            }
+
+            IntegrationStandard::V2 => {
+                let launcher = lua.create_table()?;
+                let network = lua.create_table()?;
+                let json = lua.create_table()?;
+
+                network.set("fetch", fetch)?;
+                json.set("decode", decode)?;
 
-unsafe impl Send for Driver {}
-unsafe impl Sync for Driver {}
+                launcher.set("standard", 2)?;
+                launcher.set("network", network)?;
+                launcher.set("json", json)?;
+
+                lua.globals().set("launcher", launcher)?;
+
+                let LuaValue::Table(module) = lua.load(script).eval::<LuaValue>()? else {
+                    anyhow::bail!("Wrong v2 integration script: module table expected to be returned");
+                };
+
+                v2::validate_module(&module)?;
+
+                lua.set_named_registry_value(v2::MODULE_REGISTRY_KEY, module)?;
+            }
+        }
+
+        Ok(lua)
+    }
+
+    /// Run given callback with an idle lua VM
+    /// 
+    /// New VM is spawned if all the existing ones are busy and their
+    /// amount is less than the threads number from the config. Otherwise
+    /// current thread is blocked until some VM is returned to the pool.
+    /// Sandboxed script can access only paths from the `scope`
+    fn with_lua<T>(&self, scope: &[&str], callback: impl FnOnce(&Lua) -> anyhow::Result<T>) -> anyhow::Result<T> {
+        let lua = self.take_lua()?;
+
+        if self.sandbox.is_some() {
+            sandbox::set_scope(&lua, scope);
+        }
+
+        let result = callback(&lua);
+
+        if self.sandbox.is_some() {
+            sandbox::clear_scope(&lua);
+        }
+
+        // Return VM back to the pool
+        self.lock_pool().idle.push(lua);
+
+        self.available.notify_one();
+
+        result
+    }
+
+    /// Take an idle lua VM from the pool, spawning a new
+    /// one or waiting for a busy one if there are no idle VMs
+    fn take_lua(&self) -> anyhow::Result<Lua> {
+        let mut pool = self.lock_pool();
+
+        loop {
+            if let Some(lua) = pool.idle.pop() {
+                return Ok(lua);
+            }
+
+            if pool.spawned < self.max_vms {
+                pool.spawned += 1;
+
+                // Don't block other threads while the script is loading
+                drop(pool);
+
+                let lua = Self::spawn_lua(self.standard, self.sandbox.as_ref(), &self.script, self.modules_path.as_deref());
+
+                if lua.is_err() {
+                    self.lock_pool().spawned -= 1;
+
+                    // Let waiting threads try to spawn their own VM
+                    self.available.notify_one();
+                }
+
+                return lua;
+            }
+
+            pool = self.available.wait(pool)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Lock lua VMs pool
+    /// 
+    /// Pool is only updated while it's locked, so it's never left
+    /// in a broken state and is used even if the lock was poisoned.
+    /// Otherwise returned VMs would be lost and `take_lua`
+    /// could wait for them forever
+    #[inline]
+    fn lock_pool(&self) -> MutexGuard<'_, LuaPool> {
+        self.pool.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
 
 impl Driver {
     #[inline]
@@ -106,13 +283,14 @@ impl Driver {
             convert = r##"{ format!("{_game}{edition}") }"##,
             result
         )]
-        fn get_card_picture(lua: &Lua, standard: IntegrationStandard, _game: &str, edition: &str) -> anyhow::Result<String> {
-            match standard {
-                IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_visual_get_card_picture", edition)?)
-            }
+        fn get_card_picture(driver: &Driver, _game: &str, edition: &str) -> anyhow::Result<String> {
+            driver.with_lua(&[], |lua| match driver.standard {
+                IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_visual_get_card_picture", edition)?),
+                IntegrationStandard::V2 => v2::call(lua, "visual.get_card_picture", edition)
+            })
         }
 
-        get_card_picture(&self.lua, self.standard, &self.game_name, edition)
+        get_card_picture(self, &self.game_name, edition)
     }
 
     #[inline]
@@ -124,13 +302,14 @@ impl Driver {
             convert = r##"{ format!("{_game}{edition}") }"##,
             result
         )]
-        fn get_background_picture(lua: &Lua, standard: IntegrationStandard, _game: &str, edition: &str) -> anyhow::Result<String> {
-            match standard {
-                IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_visual_get_background_picture", edition)?)
-            }
+        fn get_background_picture(driver: &Driver, _game: &str, edition: &str) -> anyhow::Result<String> {
+            driver.with_lua(&[], |lua| match driver.standard {
+                IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_visual_get_background_picture", edition)?),
+                IntegrationStandard::V2 => v2::call(lua, "visual.get_background_picture", edition)
+            })
         }
 
-        get_background_picture(&self.lua, self.standard, &self.game_name, edition)
+        get_background_picture(self, &self.game_name, edition)
     }
 
     #[inline]
@@ -142,8 +321,8 @@ impl Driver {
             convert = r##"{ format!("{_game}{edition}") }"##,
             result
         )]
-        fn get_details_background_style(lua: &Lua, standard: IntegrationStandard, _game: &str, edition: &str) -> anyhow::Result<Option<String>> {
-            match standard {
+        fn get_details_background_style(driver: &Driver, _game: &str, edition: &str) -> anyhow::Result<Option<String>> {
+            driver.with_lua(&[], |lua| match driver.standard {
                 IntegrationStandard::V1 => {
                     if !lua.globals().contains_key("v1_visual_get_details_background_css")? {
                         return Ok(None);
@@ -151,10 +330,18 @@ impl Driver {
 
                     Ok(lua.globals().call_function("v1_visual_get_details_background_css", edition)?)
                 }
-            }
+
+                IntegrationStandard::V2 => {
+                    if !v2::has_function(lua, "visual.get_details_background_css")? {
+                        return Ok(None);
+                    }
+
+                    v2::call(lua, "visual.get_details_background_css", edition)
+                }
+            })
         }
 
-        get_details_background_style(&self.lua, self.standard, &self.game_name, edition)
+        get_details_background_style(self, &self.game_name, edition)
     }
 
     // Game
@@ -168,133 +355,139 @@ impl Driver {
             convert = r##"{ _game.to_string() }"##,
             result
         )]
-        fn get_game_editions_list(lua: &Lua, standard: IntegrationStandard, _game: &str) -> anyhow::Result<Vec<GameEdition>> {
-            match standard {
-                IntegrationStandard::V1 => {
-                    let editions = lua.globals()
-                        .call_function::<_, LuaTable>("v1_game_get_editions_list", ())?
-                        .sequence_values::<LuaTable>()
-                        .flatten()
-                        .map(|edition| GameEdition::from_table(edition, standard))
-                        .collect::<Result<Vec<_>, _>>()?;
-
-                    Ok(editions)
-                }
-            }
+        fn get_game_editions_list(driver: &Driver, _game: &str) -> anyhow::Result<Vec<GameEdition>> {
+            driver.with_lua(&[], |lua| {
+                let editions: LuaTable = match driver.standard {
+                    IntegrationStandard::V1 => lua.globals().call_function("v1_game_get_editions_list", ())?,
+                    IntegrationStandard::V2 => v2::call(lua, "game.get_editions_list", ())?
+                };
+
+                editions.sequence_values::<LuaTable>()
+                    .flatten()
+                    .map(|edition| GameEdition::from_table(edition, driver.standard))
+                    .collect::<Result<Vec<_>, _>>()
+            })
         }
 
-        get_game_editions_list(&self.lua, self.standard, &self.game_name)
+        get_game_editions_list(self, &self.game_name)
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn is_game_installed(&self, path: &str, edition: &str) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_is_installed", (path, edition))?)
-        }
+        self.with_lua(&[path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals()
+                .call_function("v1_game_is_installed", (path, edition))?),
+
+            IntegrationStandard::V2 => v2::call(lua, "game.is_installed", (path, edition))
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_game_version(&self, path: &str, edition: &str) -> anyhow::Result<Option<String>> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_get_version", (path, edition))?)
-        }
+        self.with_lua(&[path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals()
+                .call_function("v1_game_get_version", (path, edition))?),
+
+            IntegrationStandard::V2 => v2::call(lua, "game.get_version", (path, edition))
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_game_download(&self, edition: &str) -> anyhow::Result<Download> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let download = self.lua.globals()
-                    .call_function("v1_game_get_download", edition)?;
+        self.with_lua(&[], |lua| {
+            let download: LuaTable = match self.standard {
+                IntegrationStandard::V1 => lua.globals().call_function("v1_game_get_download", edition)?,
+                IntegrationStandard::V2 => v2::call(lua, "game.get_download", edition)?
+            };
 
-                Download::from_table(download, self.standard)
-            }
-        }
+            Download::from_table(download, self.standard)
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_game_diff(&self, path: &str, edition: &str) -> anyhow::Result<Option<Diff>> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let diff = self.lua.globals()
-                    .call_function("v1_game_get_diff", (path, edition))?;
-
-                match diff {
-                    Some(diff) => Ok(Some(Diff::from_table(diff, self.standard)?)),
-                    None => Ok(None)
-                }
+        self.with_lua(&[path], |lua| {
+            let diff: Option<LuaTable> = match self.standard {
+                IntegrationStandard::V1 => lua.globals().call_function("v1_game_get_diff", (path, edition))?,
+                IntegrationStandard::V2 => v2::call(lua, "game.get_diff", (path, edition))?
+            };
+
+            match diff {
+                Some(diff) => Ok(Some(Diff::from_table(diff, self.standard)?)),
+                None => Ok(None)
             }
-        }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_game_status(&self, path: &str, edition: &str) -> anyhow::Result<Option<GameStatus>> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let status = self.lua.globals()
-                    .call_function("v1_game_get_status", (path, edition))?;
-
-                match status {
-                    Some(status) => Ok(Some(GameStatus::from_table(status, self.standard)?)),
-                    None => Ok(None)
-                }
+        self.with_lua(&[path], |lua| {
+            let status: Option<LuaTable> = match self.standard {
+                IntegrationStandard::V1 => lua.globals().call_function("v1_game_get_status", (path, edition))?,
+                IntegrationStandard::V2 => v2::call(lua, "game.get_status", (path, edition))?
+            };
+
+            match status {
+                Some(status) => Ok(Some(GameStatus::from_table(status, self.standard)?)),
+                None => Ok(None)
             }
-        }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_launch_options(&self, game_path: &str, addons_path: &str, edition: &str) -> anyhow::Result<GameLaunchOptions> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let options = self.lua.globals()
-                    .call_function("v1_game_get_launch_options", (game_path, addons_path, edition))?;
+        self.with_lua(&[game_path, addons_path], |lua| {
+            let options: LuaTable = match self.standard {
+                IntegrationStandard::V1 => lua.globals().call_function("v1_game_get_launch_options", (game_path, addons_path, edition))?,
+                IntegrationStandard::V2 => v2::call(lua, "game.get_launch_options", (game_path, addons_path, edition))?
+            };
 
-                GameLaunchOptions::from_table(options, self.standard)
-            }
-        }
+            GameLaunchOptions::from_table(options, self.standard)
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn is_process_running(&self, game_path: &str, edition: &str) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_is_running", (game_path, edition))?)
-        }
+        self.with_lua(&[game_path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals()
+                .call_function("v1_game_is_running", (game_path, edition))?),
+
+            IntegrationStandard::V2 => v2::call(lua, "game.is_running", (game_path, edition))
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn kill_process(&self, game_path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_kill", (game_path, edition))?)
-        }
+        self.with_lua(&[game_path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals()
+                .call_function("v1_game_kill", (game_path, edition))?),
+
+            IntegrationStandard::V2 => v2::call(lua, "game.kill", (game_path, edition))
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace")]
     pub fn get_game_integrity(&self, game_path: &str, edition: &str) -> anyhow::Result<Vec<IntegrityInfo>> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let info = self.lua.globals()
-                    .call_function::<_, LuaTable>("v1_game_get_integrity_info", (game_path, edition))?
-                    .sequence_values::<LuaTable>()
-                    .flatten()
-                    .map(|info| IntegrityInfo::from_table(info, self.standard))
-                    .collect::<Result<Vec<_>, _>>()?;
-
-                Ok(info)
-            }
-        }
+        self.with_lua(&[game_path], |lua| {
+            let info: LuaTable = match self.standard {
+                IntegrationStandard::V1 => lua.globals().call_function("v1_game_get_integrity_info", (game_path, edition))?,
+                IntegrationStandard::V2 => v2::call(lua, "game.get_integrity_info", (game_path, edition))?
+            };
+
+            info.sequence_values::<LuaTable>()
+                .flatten()
+                .map(|info| IntegrityInfo::from_table(info, self.standard))
+                .collect::<Result<Vec<_>, _>>()
+        })
     }
 
     // Addons
@@ -308,124 +501,105 @@ impl Driver {
             convert = r##"{ format!("{_game}{edition}") }"##,
             result
         )]
-        fn get_addons_list(lua: &Lua, standard: IntegrationStandard, _game: &str, edition: &str) -> anyhow::Result<Vec<AddonsGroup>> {
-            match standard {
-                IntegrationStandard::V1 => {
-                    let addons = lua.globals()
-                        .call_function::<_, LuaTable>("v1_addons_get_list", edition)?
-                        .sequence_values::<LuaTable>()
-                        .flatten()
-                        .map(|group| AddonsGroup::from_table(group, standard))
-                        .collect::<Result<Vec<_>, _>>()?;
-
-                    Ok(addons)
-                }
-            }
+        fn get_addons_list(driver: &Driver, _game: &str, edition: &str) -> anyhow::Result<Vec<AddonsGroup>> {
+            driver.with_lua(&[], |lua| {
+                let addons: LuaTable = match driver.standard {
+                    IntegrationStandard::V1 => lua.globals().call_function("v1_addons_get_list", edition)?,
+                    IntegrationStandard::V2 => v2::call(lua, "addons.get_list", edition)?
+                };
+
+                addons.sequence_values::<LuaTable>()
+                    .flatten()
+                    .map(|group| AddonsGroup::from_table(group, driver.standard))
+                    .collect::<Result<Vec<_>, _>>()
+            })
         }
 
-        get_addons_list(&self.lua, self.standard, &self.game_name, edition)
+        get_addons_list(self, &self.game_name, edition)
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn is_addon_installed(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_is_installed", (
-                    group_name,
-                    addon_name,
-                    addon_path,
-                    edition
-                ))?)
-        }
+        let args = (group_name, addon_name, addon_path, edition);
+
+        self.with_lua(&[addon_path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_addons_is_installed", args)?),
+            IntegrationStandard::V2 => v2::call(lua, "addons.is_installed", args)
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_addon_version(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<Option<String>> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_get_version", (
-                    group_name,
-                    addon_name,
-                    addon_path,
-                    edition
-                ))?)
-        }
+        let args = (group_name, addon_name, addon_path, edition);
+
+        self.with_lua(&[addon_path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_addons_get_version", args)?),
+            IntegrationStandard::V2 => v2::call(lua, "addons.get_version", args)
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_addon_download(&self, group_name: &str, addon_name: &str, edition: &str) -> anyhow::Result<Download> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let download = self.lua.globals()
-                    .call_function("v1_addons_get_download", (
-                        group_name,
-                        addon_name,
-                        edition
-                    ))?;
-
-                Download::from_table(download, self.standard)
-            }
-        }
+        let args = (group_name, addon_name, edition);
+
+        self.with_lua(&[], |lua| {
+            let download: LuaTable = match self.standard {
+                IntegrationStandard::V1 => lua.globals().call_function("v1_addons_get_download", args)?,
+                IntegrationStandard::V2 => v2::call(lua, "addons.get_download", args)?
+            };
+
+            Download::from_table(download, self.standard)
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_addon_diff(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<Option<Diff>> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let diff = self.lua.globals()
-                    .call_function("v1_addons_get_diff", (
-                        group_name,
-                        addon_name,
-                        addon_path,
-                        edition
-                    ))?;
-
-                match diff {
-                    Some(diff) => Ok(Some(Diff::from_table(diff, self.standard)?)),
-                    None => Ok(None)
-                }
+        let args = (group_name, addon_name, addon_path, edition);
+
+        self.with_lua(&[addon_path], |lua| {
+            let diff: Option<LuaTable> = match self.standard {
+                IntegrationStandard::V1 => lua.globals().call_function("v1_addons_get_diff", args)?,
+                IntegrationStandard::V2 => v2::call(lua, "addons.get_diff", args)?
+            };
+
+            match diff {
+                Some(diff) => Ok(Some(Diff::from_table(diff, self.standard)?)),
+                None => Ok(None)
             }
-        }
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn get_addon_paths(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<Vec<String>> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_get_paths", (
-                    group_name,
-                    addon_name,
-                    addon_path,
-                    edition
-                ))?)
-        }
+        let args = (group_name, addon_name, addon_path, edition);
+
+        self.with_lua(&[addon_path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_addons_get_paths", args)?),
+            IntegrationStandard::V2 => v2::call(lua, "addons.get_paths", args)
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace")]
     pub fn get_addon_integrity(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<Vec<IntegrityInfo>> {
-        match self.standard {
-            IntegrationStandard::V1 => {
-                let info = self.lua.globals()
-                    .call_function::<_, LuaTable>("v1_addons_get_integrity_info", (
-                        group_name,
-                        addon_name,
-                        addon_path,
-                        edition
-                    ))?
-                    .sequence_values::<LuaTable>()
-                    .flatten()
-                    .map(|info| IntegrityInfo::from_table(info, self.standard))
-                    .collect::<Result<Vec<_>, _>>()?;
-
-                Ok(info)
-            }
-        }
+        let args = (group_name, addon_name, addon_path, edition);
+
+        self.with_lua(&[addon_path], |lua| {
+            let info: LuaTable = match self.standard {
+                IntegrationStandard::V1 => lua.globals().call_function("v1_addons_get_integrity_info", args)?,
+                IntegrationStandard::V2 => v2::call(lua, "addons.get_integrity_info", args)?
+            };
+
+            info.sequence_values::<LuaTable>()
+                .flatten()
+                .map(|info| IntegrityInfo::from_table(info, self.standard))
+                .collect::<Result<Vec<_>, _>>()
+        })
     }
 
     // Game transitions
@@ -433,52 +607,61 @@ impl Driver {
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_game_diff_pre_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_game_diff_pre_transition")?)
-        }
+        self.with_lua(&[], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().contains_key("v1_game_diff_pre_transition")?),
+            IntegrationStandard::V2 => v2::has_function(lua, "hooks.game.diff_pre_transition")
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_game_diff_pre_transition(&self, path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_diff_pre_transition", (path, edition))?)
-        }
+        self.with_lua(&[path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals()
+                .call_function("v1_game_diff_pre_transition", (path, edition))?),
+
+            IntegrationStandard::V2 => v2::call(lua, "hooks.game.diff_pre_transition", (path, edition))
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_game_diff_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_game_diff_transition")?)
-        }
+        self.with_lua(&[], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().contains_key("v1_game_diff_transition")?),
+            IntegrationStandard::V2 => v2::has_function(lua, "hooks.game.diff_transition")
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_game_diff_transition(&self, transition_path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_diff_transition", (transition_path, edition))?)
-        }
+        self.with_lua(&[transition_path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals()
+                .call_function("v1_game_diff_transition", (transition_path, edition))?),
+
+            IntegrationStandard::V2 => v2::call(lua, "hooks.game.diff_transition", (transition_path, edition))
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_game_diff_post_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_game_diff_post_transition")?)
-        }
+        self.with_lua(&[], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().contains_key("v1_game_diff_post_transition")?),
+            IntegrationStandard::V2 => v2::has_function(lua, "hooks.game.diff_post_transition")
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_game_diff_post_transition(&self, path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_game_diff_post_transition", (path, edition))?)
-        }
+        self.with_lua(&[path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals()
+                .call_function("v1_game_diff_post_transition", (path, edition))?),
+
+            IntegrationStandard::V2 => v2::call(lua, "hooks.game.diff_post_transition", (path, edition))
+        })
     }
 
     // Addons transitions
@@ -486,67 +669,61 @@ impl Driver {
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_addons_diff_pre_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_addons_diff_pre_transition")?)
-        }
+        self.with_lua(&[], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().contains_key("v1_addons_diff_pre_transition")?),
+            IntegrationStandard::V2 => v2::has_function(lua, "hooks.addons.diff_pre_transition")
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_addons_diff_pre_transition(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_diff_pre_transition", (
-                    group_name,
-                    addon_name,
-                    addon_path,
-                    edition
-                ))?)
-        }
+        let args = (group_name, addon_name, addon_path, edition);
+
+        self.with_lua(&[addon_path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_addons_diff_pre_transition", args)?),
+            IntegrationStandard::V2 => v2::call(lua, "hooks.addons.diff_pre_transition", args)
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_addons_diff_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_addons_diff_transition")?)
-        }
+        self.with_lua(&[], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().contains_key("v1_addons_diff_transition")?),
+            IntegrationStandard::V2 => v2::has_function(lua, "hooks.addons.diff_transition")
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_addons_diff_transition(&self, group_name: &str, addon_name: &str, transition_path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_diff_transition", (
-                    group_name,
-                    addon_name,
-                    transition_path,
-                    edition
-                ))?)
-        }
+        let args = (group_name, addon_name, transition_path, edition);
+
+        self.with_lua(&[transition_path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_addons_diff_transition", args)?),
+            IntegrationStandard::V2 => v2::call(lua, "hooks.addons.diff_transition", args)
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_addons_diff_post_transition(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_addons_diff_post_transition")?)
-        }
+        self.with_lua(&[], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().contains_key("v1_addons_diff_post_transition")?),
+            IntegrationStandard::V2 => v2::has_function(lua, "hooks.addons.diff_post_transition")
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn run_addons_diff_post_transition(&self, group_name: &str, addon_name: &str, addon_path: &str, edition: &str) -> anyhow::Result<()> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_addons_diff_post_transition", (
-                    group_name,
-                    addon_name,
-                    addon_path,
-                    edition
-                ))?)
-        }
+        let args = (group_name, addon_name, addon_path, edition);
+
+        self.with_lua(&[addon_path], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_addons_diff_post_transition", args)?),
+            IntegrationStandard::V2 => v2::call(lua, "hooks.addons.diff_post_transition", args)
+        })
     }
 
     // Hashes
@@ -554,20 +731,22 @@ impl Driver {
     #[inline]
     #[tracing::instrument(level = "trace", ret)]
     pub fn has_integrity_hash(&self) -> anyhow::Result<bool> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals().contains_key("v1_integrity_hash")?)
-        }
+        self.with_lua(&[], |lua| match self.standard {
+            IntegrationStandard::V1 => Ok(lua.globals().contains_key("v1_integrity_hash")?),
+            IntegrationStandard::V2 => v2::has_function(lua, "integrity.hash")
+        })
     }
 
     #[inline]
     #[tracing::instrument(level = "trace", skip(data), ret)]
     pub fn integrity_hash(&self, algorithm: &str, data: impl AsRef<[u8]>) -> anyhow::Result<String> {
-        match self.standard {
-            IntegrationStandard::V1 => Ok(self.lua.globals()
-                .call_function("v1_integrity_hash", (
-                    algorithm,
-                    self.lua.create_string(data)?
-                ))?)
-        }
+        self.with_lua(&[], |lua| {
+            let args = (algorithm, lua.create_string(data)?);
+
+            match self.standard {
+                IntegrationStandard::V1 => Ok(lua.globals().call_function("v1_integrity_hash", args)?),
+                IntegrationStandard::V2 => v2::call(lua, "integrity.hash", args)
+            }
+        })
     }
 }
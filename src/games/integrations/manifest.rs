@@ -1,6 +1,10 @@
+use std::path::{Path, Component};
+
 use serde_json::Value as Json;
 
 use super::standards::IntegrationStandard;
+use super::standards::integrity::HashType;
+use super::capabilities::Capabilities;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Manifest {
@@ -10,58 +14,219 @@ pub struct Manifest {
 
     pub script_path: String,
     pub script_version: String,
-    pub script_standard: IntegrationStandard
+    pub script_standard: IntegrationStandard,
+
+    /// Expected script hash
+    /// 
+    /// Required since manifest v2
+    pub script_hash: Option<ManifestHash>,
+
+    /// Additional integration files (e.g. lua modules)
+    /// 
+    /// Available since manifest v2
+    pub files: Vec<ManifestFile>,
+
+    /// Files from the `shared` folder of the integrations source
+    /// 
+    /// They're stored in the `shared` folder of the integration.
+    /// Available since manifest v2
+    pub shared: Vec<ManifestFile>,
+
+    pub capabilities: Capabilities
 }
 
-impl Manifest {
-    pub fn from_json(manifest: &Json) -> anyhow::Result<Self> {
-        match manifest.get("manifest_version").and_then(Json::as_str) {
-            Some("1") => {
-                let Some(game_manifest) = manifest.get("game") else {
-                    anyhow::bail!("Wrong manifest v1 structure: field `game` expected but wasn't presented");
-                };
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ManifestHash {
+    pub hash: HashType,
+    pub value: String
+}
+
+impl ManifestHash {
+    pub fn from_json(value: &Json, field: &str) -> anyhow::Result<Self> {
+        let hash = value.get("type")
+            .and_then(Json::as_str)
+            .ok_or_else(|| anyhow::anyhow!("Wrong manifest v2 structure: field `{field}.type` expected but wasn't presented"))?;
+
+        // Custom hashes are calculated by the script which can't be trusted before it's verified
+        let hash = match HashType::from_str(hash, IntegrationStandard::V1)? {
+            HashType::Custom(name) => anyhow::bail!("Wrong manifest v2 structure: field `{field}.type` contains unsupported hash: {name}"),
+            hash => hash
+        };
+
+        Ok(Self {
+            hash,
+
+            value: value.get("value")
+                .and_then(Json::as_str)
+                .ok_or_else(|| anyhow::anyhow!("Wrong manifest v2 structure: field `{field}.value` expected but wasn't presented"))?
+                .to_ascii_lowercase()
+        })
+    }
+
+    /// Check if the data has expected hash
+    pub fn verify(&self, data: impl AsRef<[u8]>) -> bool {
+        self.hash.hash(data).as_deref() == Some(self.value.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ManifestFile {
+    /// Path relative to the manifest file
+    /// or to the source's `shared` folder
+    pub path: String,
+
+    pub hash: ManifestHash
+}
 
-                let Some(script_manifest) = manifest.get("script") else {
-                    anyhow::bail!("Wrong manifest v1 structure: field `script` expected but wasn't presented");
+impl ManifestFile {
+    /// Parse list of files from the manifest field
+    pub fn list_from_json(value: &Json, field: &str) -> anyhow::Result<Vec<Self>> {
+        let Some(files) = value.as_array() else {
+            anyhow::bail!("Wrong manifest v2 structure: field `{field}` must be a list of files");
+        };
+
+        files.iter()
+            .map(|file| {
+                let path = file.get("path")
+                    .and_then(Json::as_str)
+                    .ok_or_else(|| anyhow::anyhow!("Wrong manifest v2 structure: field `{field}.path` expected but wasn't presented"))?;
+
+                if !is_relative_path(path) {
+                    anyhow::bail!("Wrong manifest v2 structure: file `{path}` must be a relative path");
+                }
+
+                let Some(hash) = file.get("hash") else {
+                    anyhow::bail!("Wrong manifest v2 structure: field `{field}.hash` expected but wasn't presented");
                 };
 
                 Ok(Self {
-                    game_name: game_manifest.get("name")
-                        .and_then(Json::as_str)
-                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest v1 structure: field `game.name` expected but wasn't presented"))?
-                        .to_string(),
-
-                    game_title: game_manifest.get("title")
-                        .and_then(Json::as_str)
-                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest v1 structure: field `game.title` expected but wasn't presented"))?
-                        .to_string(),
-
-                    game_developer: game_manifest.get("developer")
-                        .and_then(Json::as_str)
-                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest v1 structure: field `game.developer` expected but wasn't presented"))?
-                        .to_string(),
-
-                    script_path: script_manifest.get("path")
-                        .and_then(Json::as_str)
-                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest v1 structure: field `script.path` expected but wasn't presented"))?
-                        .to_string(),
-
-                    script_version: script_manifest.get("version")
-                        .and_then(Json::as_str)
-                        .ok_or_else(|| anyhow::anyhow!("Wrong manifest v1 structure: field `script.version` expected but wasn't presented"))?
-                        .to_string(),
-
-                    script_standard: match script_manifest.get("standard").and_then(Json::as_str) {
-                        Some("1") => IntegrationStandard::V1,
-
-                        Some(version) => anyhow::bail!("Wrong manifest v1 structure: field `script.standard` containts unknown version: {version}"),
-                        None => anyhow::bail!("Wrong manifest v1 structure: field `script.standard` expected but wasn't presented")
-                    }
+                    path: path.to_string(),
+                    hash: ManifestHash::from_json(hash, &format!("{field}.hash"))?
                 })
-            }
+            })
+            .collect()
+    }
+}
+
+/// Check that the path stays inside of the integration folder
+fn is_relative_path(path: &str) -> bool {
+    Path::new(path).components().all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+}
+
+impl Manifest {
+    pub fn from_json(manifest: &Json) -> anyhow::Result<Self> {
+        let version = match manifest.get("manifest_version").and_then(Json::as_str) {
+            Some(version @ ("1" | "2")) => version,
 
             Some(version) => anyhow::bail!("Unknown manifest version: {version}"),
             None => anyhow::bail!("Wrong manifest file structure")
+        };
+
+        let Some(game_manifest) = manifest.get("game") else {
+            anyhow::bail!("Wrong manifest v{version} structure: field `game` expected but wasn't presented");
+        };
+
+        let Some(script_manifest) = manifest.get("script") else {
+            anyhow::bail!("Wrong manifest v{version} structure: field `script` expected but wasn't presented");
+        };
+
+        let script_path = script_manifest.get("path")
+            .and_then(Json::as_str)
+            .ok_or_else(|| anyhow::anyhow!("Wrong manifest v{version} structure: field `script.path` expected but wasn't presented"))?
+            .to_string();
+
+        let (script_hash, files, shared) = match version {
+            "1" => (None, vec![], vec![]),
+
+            _ => {
+                if !is_relative_path(&script_path) {
+                    anyhow::bail!("Wrong manifest v2 structure: field `script.path` must be relative to the manifest file");
+                }
+
+                let script_hash = match script_manifest.get("hash") {
+                    Some(hash) => ManifestHash::from_json(hash, "script.hash")?,
+                    None => anyhow::bail!("Wrong manifest v2 structure: field `script.hash` expected but wasn't presented")
+                };
+
+                let files = match manifest.get("files") {
+                    Some(files) => ManifestFile::list_from_json(files, "files")?,
+                    None => vec![]
+                };
+
+                let shared = match manifest.get("shared") {
+                    Some(files) => ManifestFile::list_from_json(files, "shared")?,
+                    None => vec![]
+                };
+
+                (Some(script_hash), files, shared)
+            }
+        };
+
+        Ok(Self {
+            game_name: game_manifest.get("name")
+                .and_then(Json::as_str)
+                .ok_or_else(|| anyhow::anyhow!("Wrong manifest v{version} structure: field `game.name` expected but wasn't presented"))?
+                .to_string(),
+
+            game_title: game_manifest.get("title")
+                .and_then(Json::as_str)
+                .ok_or_else(|| anyhow::anyhow!("Wrong manifest v{version} structure: field `game.title` expected but wasn't presented"))?
+                .to_string(),
+
+            game_developer: game_manifest.get("developer")
+                .and_then(Json::as_str)
+                .ok_or_else(|| anyhow::anyhow!("Wrong manifest v{version} structure: field `game.developer` expected but wasn't presented"))?
+                .to_string(),
+
+            script_path,
+
+            script_version: script_manifest.get("version")
+                .and_then(Json::as_str)
+                .ok_or_else(|| anyhow::anyhow!("Wrong manifest v{version} structure: field `script.version` expected but wasn't presented"))?
+                .to_string(),
+
+            script_standard: match script_manifest.get("standard").and_then(Json::as_str) {
+                Some("1") => IntegrationStandard::V1,
+                Some("2") => IntegrationStandard::V2,
+
+                Some(standard) => anyhow::bail!("Wrong manifest v{version} structure: field `script.standard` containts unknown version: {standard}"),
+                None => anyhow::bail!("Wrong manifest v{version} structure: field `script.standard` expected but wasn't presented")
+            },
+
+            script_hash,
+            files,
+            shared,
+
+            capabilities: match manifest.get("capabilities") {
+                Some(capabilities) => Capabilities::from_json(capabilities)?,
+                None => Capabilities::default()
+            }
+        })
+    }
+
+    /// Verify hashes of the script and additional files
+    /// stored in the given integration folder
+    pub fn verify_files(&self, integration_path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let integration_path = integration_path.as_ref();
+
+        if let Some(hash) = &self.script_hash {
+            if !hash.verify(std::fs::read(integration_path.join(&self.script_path))?) {
+                anyhow::bail!("Script {} has wrong hash", self.script_path);
+            }
+        }
+
+        for file in &self.files {
+            if !file.hash.verify(std::fs::read(integration_path.join(&file.path))?) {
+                anyhow::bail!("File {} has wrong hash", file.path);
+            }
         }
+
+        for file in &self.shared {
+            if !file.hash.verify(std::fs::read(integration_path.join("shared").join(&file.path))?) {
+                anyhow::bail!("Shared file {} has wrong hash", file.path);
+            }
+        }
+
+        Ok(())
     }
 }
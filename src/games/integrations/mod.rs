@@ -2,8 +2,15 @@ use std::path::{Path, PathBuf};
 
 use serde_json::Value as Json;
 
+use crate::config;
+
 pub mod manifest;
 pub mod standards;
+pub mod capabilities;
+pub mod sandbox;
+pub mod modules;
+pub mod versions;
+pub mod signature;
 pub mod driver;
 
 use manifest::Manifest;
@@ -21,21 +28,32 @@ impl Game {
         let manifest = serde_json::from_slice::<Json>(&manifest)?;
         let manifest = Manifest::from_json(&manifest)?;
 
+        let integration_path = manifest_path.as_ref()
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        // Refuse to load modified scripts
+        manifest.verify_files(&integration_path)?;
+
         let script_path = PathBuf::from(&manifest.script_path);
 
         let script_path = if script_path.is_absolute() {
             script_path
         } else {
-            manifest_path.as_ref()
-                .parent()
-                .map(|path| path.join(&script_path))
-                .unwrap_or(script_path)
+            integration_path.join(&script_path)
         };
 
+        // Sandbox the script with its requested capabilities if enabled
+        let sandbox = config::get().games.integrations.sandbox
+            .then(|| manifest.capabilities.clone());
+
         let driver = Driver::new(
             &manifest.game_name,
             manifest.script_standard,
-            std::fs::read_to_string(script_path)?
+            sandbox,
+            std::fs::read_to_string(script_path)?,
+            Some(integration_path)
         )?;
 
         Ok(Self {
@@ -0,0 +1,53 @@
+use std::path::{Path, PathBuf};
+
+use mlua::prelude::*;
+
+/// Resolve lua module name to the file in the integration folder
+/// 
+/// `utils.hashes` is searched as `utils/hashes.lua` and `utils/hashes/init.lua`
+pub fn resolve_module(root: &Path, name: &str) -> Option<PathBuf> {
+    // Don't let module names leave the integration folder
+    if name.split('.').any(|part| part.is_empty() || part.contains(['/', '\\'])) {
+        return None;
+    }
+
+    let path = root.join(name.replace('.', "/"));
+
+    [path.with_extension("lua"), path.join("init.lua")]
+        .into_iter()
+        .find(|path| path.is_file())
+}
+
+/// Add `package.loaders` searcher which loads
+/// modules from the integration folder
+/// 
+/// Searcher is inserted right after the preloaded modules one
+/// so integration modules have priority over the system ones
+pub fn install_searcher(lua: &Lua, root: impl Into<PathBuf>) -> anyhow::Result<()> {
+    let root = root.into();
+
+    let searcher = lua.create_function(move |lua, name: String| {
+        let Some(path) = resolve_module(&root, &name) else {
+            return Ok(LuaValue::String(lua.create_string(format!("\n\tno integration module '{name}'"))?));
+        };
+
+        let code = std::fs::read(&path)?;
+
+        let loader = lua.load(code)
+            .set_name(format!("@{}", path.to_string_lossy()))
+            .into_function()?;
+
+        Ok(LuaValue::Function(loader))
+    })?;
+
+    let loaders = lua.globals()
+        .get::<_, LuaTable>("package")?
+        .get::<_, LuaTable>("loaders")?;
+
+    lua.globals()
+        .get::<_, LuaTable>("table")?
+        .get::<_, LuaFunction>("insert")?
+        .call::<_, ()>((loaders, 2, searcher))?;
+
+    Ok(())
+}
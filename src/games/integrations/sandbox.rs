@@ -0,0 +1,195 @@
+use std::path::{Path, PathBuf, Component};
+
+use mlua::prelude::*;
+
+use super::capabilities::Capabilities;
+
+const SANDBOX_PRELUDE: &str = r#"
+local check_path, grants = ...
+
+local native_io, native_os = io, os
+local native_load = load
+
+local function denied(capability)
+    return function()
+        error("integration script requires `" .. capability .. "` capability which wasn't granted", 2)
+    end
+end
+
+io = {
+    type  = native_io.type,
+    write = native_io.write,
+
+    open = grants.filesystem and function(path, mode)
+        check_path(path)
+
+        return native_io.open(path, mode)
+    end or denied("filesystem"),
+
+    lines = grants.filesystem and function(path, ...)
+        if path == nil then
+            error("reading standard input is not allowed", 2)
+        end
+
+        check_path(path)
+
+        return native_io.lines(path, ...)
+    end or denied("filesystem"),
+
+    popen = grants.process and native_io.popen or denied("process")
+}
+
+os = {
+    time     = native_os.time,
+    clock    = native_os.clock,
+    date     = native_os.date,
+    difftime = native_os.difftime,
+    getenv   = native_os.getenv,
+
+    remove = grants.filesystem and function(path)
+        check_path(path)
+
+        return native_os.remove(path)
+    end or denied("filesystem"),
+
+    rename = grants.filesystem and function(from, to)
+        check_path(from)
+        check_path(to)
+
+        return native_os.rename(from, to)
+    end or denied("filesystem"),
+
+    execute = grants.process and native_os.execute or denied("process")
+}
+
+-- Only allow loading text chunks
+load = function(chunk, name, _, env)
+    return native_load(chunk, name, "t", env)
+end
+
+loadstring = function(chunk, name)
+    return native_load(chunk, name, "t")
+end
+
+dofile = nil
+loadfile = nil
+
+-- Only keep preloaded modules searcher
+package.path = ""
+package.cpath = ""
+package.loadlib = nil
+package.loaders = { package.loaders[1] }
+
+package.loaded.io = io
+package.loaded.os = os
+"#;
+
+/// Paths the sandboxed script can access during the current call
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SandboxScope(pub Vec<PathBuf>);
+
+/// Create new lua VM with restricted standard library
+/// 
+/// `ffi`, `debug` and `jit` are not loaded at all, while `io`, `os`
+/// and `package` are replaced by their restricted versions
+pub fn create_lua(capabilities: &Capabilities) -> anyhow::Result<Lua> {
+    let libs = LuaStdLib::COROUTINE
+        | LuaStdLib::TABLE
+        | LuaStdLib::IO
+        | LuaStdLib::OS
+        | LuaStdLib::STRING
+        | LuaStdLib::BIT
+        | LuaStdLib::MATH
+        | LuaStdLib::PACKAGE;
+
+    let lua = Lua::new_with(libs, LuaOptions::new())?;
+
+    let check_path = lua.create_function(|lua, path: String| {
+        let path = resolve_path(&std::env::current_dir()?.join(path));
+
+        let allowed = lua.app_data_ref::<SandboxScope>()
+            .map(|scope| scope.0.iter().any(|root| path.starts_with(root)))
+            .unwrap_or_default();
+
+        if !allowed {
+            return Err(LuaError::RuntimeError(format!("integration script tried to access `{}` which is outside of its allowed folders", path.to_string_lossy())));
+        }
+
+        Ok(())
+    })?;
+
+    let grants = lua.create_table()?;
+
+    grants.set("filesystem", capabilities.filesystem)?;
+    grants.set("process", capabilities.process)?;
+
+    lua.load(SANDBOX_PRELUDE)
+        .set_name("sandbox")
+        .call::<_, ()>((check_path, grants))?;
+
+    Ok(lua)
+}
+
+/// Allow the script to access given paths until the scope is cleared
+pub fn set_scope(lua: &Lua, paths: &[&str]) {
+    let paths = paths.iter()
+        .map(|path| resolve_path(Path::new(path)))
+        .collect();
+
+    lua.set_app_data(SandboxScope(paths));
+}
+
+/// Forbid the script to access any path
+pub fn clear_scope(lua: &Lua) {
+    lua.remove_app_data::<SandboxScope>();
+}
+
+/// Check if the script can send requests to the given URI
+pub fn check_uri(capabilities: &Capabilities, uri: &str) -> LuaResult<()> {
+    let Some(host) = super::capabilities::get_uri_host(uri) else {
+        return Err(LuaError::RuntimeError(format!("failed to parse host of `{uri}`")));
+    };
+
+    if !capabilities.is_host_allowed(host) {
+        return Err(LuaError::RuntimeError(format!("integration script tried to send request to `{host}` which is not listed in its network capabilities")));
+    }
+
+    Ok(())
+}
+
+/// Resolve path the same way the filesystem does
+/// 
+/// Symlinks are followed for the deepest existing ancestor of the path
+/// and the rest of it, which doesn't exist yet, is normalized lexically.
+/// Otherwise a symlink inside of the scope could point outside of it
+fn resolve_path(path: &Path) -> PathBuf {
+    for ancestor in path.ancestors() {
+        if let Ok(resolved) = ancestor.canonicalize() {
+            return match path.strip_prefix(ancestor) {
+                Ok(rest) => normalize_path(&resolved.join(rest)),
+                Err(_) => resolved
+            };
+        }
+    }
+
+    normalize_path(path)
+}
+
+/// Resolve `.` and `..` path components without touching the filesystem
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+
+            Component::ParentDir => {
+                result.pop();
+            }
+
+            component => result.push(component)
+        }
+    }
+
+    result
+}
@@ -0,0 +1,254 @@
+use ed25519_dalek::{Signature, VerifyingKey, Verifier};
+use sha2::{Sha256, Digest};
+
+use crate::config::games::prelude::SignaturePolicy;
+
+/// Name of the file with manifest and script signature
+/// stored next to the manifest file in the integrations source
+pub const SIGNATURE_FILE: &str = "manifest.sig";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureStatus {
+    /// Signature was made by one of the trusted keys
+    Valid,
+
+    /// Source doesn't provide signature
+    Unsigned,
+
+    /// There are no trusted keys for the source
+    Untrusted,
+
+    /// Signature is malformed or wasn't made by any of the trusted keys
+    Invalid(String)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureVerdict {
+    /// Integration can be installed
+    Accept,
+
+    /// Integration can be installed, but the reason must be logged
+    Warn(String),
+
+    /// Integration must not be installed
+    Refuse(String)
+}
+
+/// Get message signed by the integration maintainer
+/// 
+/// Message is the manifest file content followed by raw SHA-256
+/// hashes of the script and all the additional files, in the same
+/// order as they're listed in the manifest (`files`, then `shared`)
+pub fn signed_message(manifest: &[u8], script: &[u8], files: &[&[u8]]) -> Vec<u8> {
+    let mut message = manifest.to_vec();
+
+    message.extend_from_slice(&Sha256::digest(script));
+
+    for file in files {
+        message.extend_from_slice(&Sha256::digest(file));
+    }
+
+    message
+}
+
+/// Verify hex-encoded ed25519 signature of the integration
+/// using hex-encoded trusted public keys
+pub fn verify(trusted_keys: &[String], manifest: &[u8], script: &[u8], files: &[&[u8]], signature: Option<&[u8]>) -> SignatureStatus {
+    let Some(signature) = signature else {
+        return SignatureStatus::Unsigned;
+    };
+
+    if trusted_keys.is_empty() {
+        return SignatureStatus::Untrusted;
+    }
+
+    let signature = String::from_utf8_lossy(signature);
+
+    let signature = match hex::decode(signature.trim()) {
+        Ok(signature) => signature,
+        Err(err) => return SignatureStatus::Invalid(format!("failed to decode signature: {err}"))
+    };
+
+    let signature = match <[u8; 64]>::try_from(signature.as_slice()) {
+        Ok(signature) => Signature::from_bytes(&signature),
+        Err(_) => return SignatureStatus::Invalid(String::from("signature must be 64 bytes long"))
+    };
+
+    let message = signed_message(manifest, script, files);
+
+    for trusted_key in trusted_keys {
+        let key = hex::decode(trusted_key.trim()).ok()
+            .and_then(|key| <[u8; 32]>::try_from(key.as_slice()).ok())
+            .and_then(|key| VerifyingKey::from_bytes(&key).ok());
+
+        let Some(key) = key else {
+            tracing::warn!("Skipping malformed trusted key: {trusted_key}");
+
+            continue;
+        };
+
+        if key.verify(&message, &signature).is_ok() {
+            return SignatureStatus::Valid;
+        }
+    }
+
+    SignatureStatus::Invalid(String::from("signature wasn't made by any of the trusted keys"))
+}
+
+/// Decide whether the integration from the `origin` source
+/// can be installed with the given signature status
+pub fn check(policy: SignaturePolicy, status: &SignatureStatus, origin: &str) -> SignatureVerdict {
+    let reason = match status {
+        SignatureStatus::Valid => return SignatureVerdict::Accept,
+
+        SignatureStatus::Unsigned => String::from("integration is not signed"),
+        SignatureStatus::Untrusted => format!("there are no trusted keys for {origin}"),
+        SignatureStatus::Invalid(reason) => format!("invalid signature: {reason}")
+    };
+
+    match policy {
+        SignaturePolicy::Ignore => SignatureVerdict::Accept,
+        SignaturePolicy::Warn => SignatureVerdict::Warn(reason),
+        SignaturePolicy::Require => SignatureVerdict::Refuse(reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{SigningKey, Signer};
+
+    use super::*;
+
+    const MANIFEST: &[u8] = b"{\"script_version\":\"1.0.0\"}";
+    const SCRIPT: &[u8] = b"return {}";
+    const FILES: &[&[u8]] = &[b"first file", b"second file"];
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    fn trusted_keys() -> Vec<String> {
+        vec![hex::encode(signing_key().verifying_key().as_bytes())]
+    }
+
+    fn sign(manifest: &[u8], script: &[u8], files: &[&[u8]]) -> Vec<u8> {
+        let signature = signing_key().sign(&signed_message(manifest, script, files));
+
+        hex::encode(signature.to_bytes()).into_bytes()
+    }
+
+    #[test]
+    fn message() {
+        let message = signed_message(MANIFEST, SCRIPT, FILES);
+
+        let mut expected = MANIFEST.to_vec();
+
+        expected.extend_from_slice(&Sha256::digest(SCRIPT));
+        expected.extend_from_slice(&Sha256::digest(FILES[0]));
+        expected.extend_from_slice(&Sha256::digest(FILES[1]));
+
+        assert_eq!(message, expected);
+        assert_eq!(message.len(), MANIFEST.len() + 32 * 3);
+
+        // Files order matters
+        assert_ne!(message, signed_message(MANIFEST, SCRIPT, &[FILES[1], FILES[0]]));
+
+        assert_eq!(signed_message(MANIFEST, SCRIPT, &[]).len(), MANIFEST.len() + 32);
+    }
+
+    #[test]
+    fn valid_signature() {
+        let signature = sign(MANIFEST, SCRIPT, FILES);
+
+        assert_eq!(verify(&trusted_keys(), MANIFEST, SCRIPT, FILES, Some(signature.as_slice())), SignatureStatus::Valid);
+
+        // Surrounding whitespaces are ignored
+        let mut signature = signature;
+
+        signature.push(b'\n');
+
+        assert_eq!(verify(&trusted_keys(), MANIFEST, SCRIPT, FILES, Some(signature.as_slice())), SignatureStatus::Valid);
+
+        // Malformed keys are skipped
+        let keys = [vec![String::from("not a key")], trusted_keys()].concat();
+
+        assert_eq!(verify(&keys, MANIFEST, SCRIPT, FILES, Some(signature.as_slice())), SignatureStatus::Valid);
+    }
+
+    #[test]
+    fn tampered_manifest() {
+        let signature = sign(MANIFEST, SCRIPT, FILES);
+
+        let status = verify(&trusted_keys(), b"{\"script_version\":\"1.0.1\"}", SCRIPT, FILES, Some(signature.as_slice()));
+
+        assert!(matches!(status, SignatureStatus::Invalid(_)));
+    }
+
+    #[test]
+    fn tampered_script() {
+        let signature = sign(MANIFEST, SCRIPT, FILES);
+
+        let status = verify(&trusted_keys(), MANIFEST, b"return { tampered = true }", FILES, Some(signature.as_slice()));
+
+        assert!(matches!(status, SignatureStatus::Invalid(_)));
+    }
+
+    #[test]
+    fn tampered_file() {
+        let signature = sign(MANIFEST, SCRIPT, FILES);
+
+        let status = verify(&trusted_keys(), MANIFEST, SCRIPT, &[FILES[0], &b"tampered file"[..]], Some(signature.as_slice()));
+
+        assert!(matches!(status, SignatureStatus::Invalid(_)));
+
+        // Missing file
+        let status = verify(&trusted_keys(), MANIFEST, SCRIPT, &FILES[..1], Some(signature.as_slice()));
+
+        assert!(matches!(status, SignatureStatus::Invalid(_)));
+    }
+
+    #[test]
+    fn wrong_key() {
+        let signature = sign(MANIFEST, SCRIPT, FILES);
+
+        let keys = vec![hex::encode(SigningKey::from_bytes(&[8; 32]).verifying_key().as_bytes())];
+
+        assert!(matches!(verify(&keys, MANIFEST, SCRIPT, FILES, Some(signature.as_slice())), SignatureStatus::Invalid(_)));
+        assert_eq!(verify(&[], MANIFEST, SCRIPT, FILES, Some(signature.as_slice())), SignatureStatus::Untrusted);
+    }
+
+    #[test]
+    fn malformed_signature() {
+        assert!(matches!(verify(&trusted_keys(), MANIFEST, SCRIPT, FILES, Some(&b"not hex"[..])), SignatureStatus::Invalid(_)));
+        assert!(matches!(verify(&trusted_keys(), MANIFEST, SCRIPT, FILES, Some(&b"abcdef"[..])), SignatureStatus::Invalid(_)));
+    }
+
+    #[test]
+    fn missing_signature() {
+        let status = verify(&trusted_keys(), MANIFEST, SCRIPT, FILES, None);
+
+        assert_eq!(status, SignatureStatus::Unsigned);
+
+        assert_eq!(check(SignaturePolicy::Ignore, &status, "origin"), SignatureVerdict::Accept);
+        assert_eq!(check(SignaturePolicy::Warn, &status, "origin"), SignatureVerdict::Warn(String::from("integration is not signed")));
+        assert_eq!(check(SignaturePolicy::Require, &status, "origin"), SignatureVerdict::Refuse(String::from("integration is not signed")));
+    }
+
+    #[test]
+    fn policies() {
+        for policy in [SignaturePolicy::Ignore, SignaturePolicy::Warn, SignaturePolicy::Require] {
+            assert_eq!(check(policy, &SignatureStatus::Valid, "origin"), SignatureVerdict::Accept);
+        }
+
+        let status = SignatureStatus::Untrusted;
+
+        assert_eq!(check(SignaturePolicy::Ignore, &status, "origin"), SignatureVerdict::Accept);
+        assert_eq!(check(SignaturePolicy::Warn, &status, "origin"), SignatureVerdict::Warn(String::from("there are no trusted keys for origin")));
+        assert_eq!(check(SignaturePolicy::Require, &status, "origin"), SignatureVerdict::Refuse(String::from("there are no trusted keys for origin")));
+
+        let status = SignatureStatus::Invalid(String::from("reason"));
+
+        assert_eq!(check(SignaturePolicy::Warn, &status, "origin"), SignatureVerdict::Warn(String::from("invalid signature: reason")));
+        assert_eq!(check(SignaturePolicy::Require, &status, "origin"), SignatureVerdict::Refuse(String::from("invalid signature: reason")));
+    }
+}
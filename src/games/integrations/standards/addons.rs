@@ -17,7 +17,7 @@ pub struct AddonsGroup {
 impl AddonsGroup {
     pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 Ok(Self {
                     name: table.get::<_, String>("name")?,
                     title: table.get::<_, String>("title")?,
@@ -34,7 +34,7 @@ impl AddonsGroup {
 
     pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 let table = lua.create_table()?;
                 let addons = lua.create_table()?;
 
@@ -64,7 +64,7 @@ pub struct Addon {
 impl Addon {
     pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 Ok(Self {
                     r#type: AddonType::from_str(table.get::<_, String>("type")?, standard)?,
                     name: table.get::<_, String>("name")?,
@@ -78,7 +78,7 @@ impl Addon {
 
     pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 let table = lua.create_table()?;
 
                 table.set("type", self.r#type.to_str(standard))?;
@@ -126,7 +126,7 @@ pub enum AddonType {
 impl AddonType {
     pub fn from_str(value: impl AsRef<str>, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 match value.as_ref() {
                     "module"    => Ok(Self::Module),
                     "layer"     => Ok(Self::Layer),
@@ -140,7 +140,7 @@ impl AddonType {
 
     pub fn to_str(&self, standard: IntegrationStandard) -> &str {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 match self {
                     Self::Module    => "module",
                     Self::Layer     => "layer",
@@ -13,7 +13,7 @@ pub struct IntegrityInfo {
 impl IntegrityInfo {
     pub fn from_table(table: LuaTable, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 Ok(Self {
                     hash: HashType::from_str(table.get::<_, String>("hash")?, standard)?,
                     value: table.get::<_, String>("value")?,
@@ -25,7 +25,7 @@ impl IntegrityInfo {
 
     pub fn to_table<'a>(&self, lua: &'a Lua, standard: IntegrationStandard) -> anyhow::Result<LuaTable<'a>> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 let table = lua.create_table()?;
 
                 table.set("hash", self.hash.to_str(standard))?;
@@ -58,7 +58,7 @@ pub enum HashType {
 impl HashType {
     pub fn from_str(value: impl AsRef<str>, standard: IntegrationStandard) -> anyhow::Result<Self> {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 match value.as_ref() {
                     "md5"         => Ok(Self::Md5),
                     "sha1"        => Ok(Self::Sha1),
@@ -76,7 +76,7 @@ impl HashType {
 
     pub fn to_str(&self, standard: IntegrationStandard) -> &str {
         match standard {
-            IntegrationStandard::V1 => {
+            IntegrationStandard::V1 | IntegrationStandard::V2 => {
                 match self {
                     Self::Md5         => "md5",
                     Self::Sha1        => "sha1",
@@ -91,4 +91,43 @@ impl HashType {
             }
         }
     }
+
+    /// Hash given data
+    /// 
+    /// Returns `None` for custom hashes which
+    /// should be calculated by the integration script
+    pub fn hash(&self, data: impl AsRef<[u8]>) -> Option<String> {
+        let data = data.as_ref();
+
+        let hash = match self {
+            Self::Md5 => {
+                use md5::{Md5, Digest};
+
+                format!("{:x}", Md5::digest(data))
+            }
+
+            Self::Sha1 => {
+                use sha1::{Sha1, Digest};
+
+                format!("{:x}", Sha1::digest(data))
+            }
+
+            Self::Crc32 => {
+                let mut hasher = crc32fast::Hasher::new();
+
+                hasher.update(data);
+
+                hasher.finalize().to_string()
+            }
+
+            Self::Xxhash32    => format!("{:x}", xxhash_rust::xxh32::xxh32(data, 0)),
+            Self::Xxhash64    => format!("{:x}", xxhash_rust::xxh64::xxh64(data, 0)),
+            Self::Xxhash3_64  => format!("{:x}", xxhash_rust::xxh3::xxh3_64(data)),
+            Self::Xxhash3_128 => format!("{:x}", xxhash_rust::xxh3::xxh3_128(data)),
+
+            Self::Custom(_) => return None
+        };
+
+        Some(hash)
+    }
 }
@@ -4,6 +4,7 @@ pub mod diff;
 pub mod download;
 pub mod integrity;
 pub mod network;
+pub mod v2;
 
 pub mod prelude {
     pub use super::game::{
@@ -23,5 +24,9 @@ pub mod prelude {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IntegrationStandard {
-    V1
+    /// Script defines global `v1_*` functions
+    V1,
+
+    /// Script returns a single module table
+    V2
 }
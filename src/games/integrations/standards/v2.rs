@@ -0,0 +1,118 @@
+use mlua::prelude::*;
+
+/// Name of the lua registry value storing the module table
+/// returned by the V2 integration script
+pub const MODULE_REGISTRY_KEY: &str = "v2_module";
+
+/// Functions of the V2 module table and whether they're required
+pub const MODULE_SCHEMA: &[(&str, bool)] = &[
+    ("visual.get_card_picture", true),
+    ("visual.get_background_picture", true),
+    ("visual.get_details_background_css", false),
+
+    ("game.get_editions_list", true),
+    ("game.is_installed", true),
+    ("game.get_version", true),
+    ("game.get_download", true),
+    ("game.get_diff", true),
+    ("game.get_status", true),
+    ("game.get_launch_options", true),
+    ("game.is_running", true),
+    ("game.kill", true),
+    ("game.get_integrity_info", true),
+
+    ("addons.get_list", true),
+    ("addons.is_installed", true),
+    ("addons.get_version", true),
+    ("addons.get_download", true),
+    ("addons.get_diff", true),
+    ("addons.get_paths", true),
+    ("addons.get_integrity_info", true),
+
+    ("hooks.game.diff_pre_transition", false),
+    ("hooks.game.diff_transition", false),
+    ("hooks.game.diff_post_transition", false),
+
+    ("hooks.addons.diff_pre_transition", false),
+    ("hooks.addons.diff_transition", false),
+    ("hooks.addons.diff_post_transition", false),
+
+    ("integrity.hash", false)
+];
+
+/// Get value from the module table by its dot-separated path
+/// 
+/// Returns path of the first non-table parent as an error
+fn get_value<'lua>(module: &LuaTable<'lua>, path: &str) -> Result<LuaValue<'lua>, String> {
+    let mut value = LuaValue::Table(module.clone());
+    let mut current_path = Vec::new();
+
+    for key in path.split('.') {
+        value = match value {
+            LuaValue::Table(table) => table.get(key)
+                .map_err(|err| format!("`{}`: {err}", current_path.join(".")))?,
+
+            LuaValue::Nil => return Ok(LuaValue::Nil),
+
+            value => return Err(format!("`{}` expected to be a table, got {}", current_path.join("."), value.type_name()))
+        };
+
+        current_path.push(key);
+    }
+
+    Ok(value)
+}
+
+/// Validate module table returned by the script
+/// 
+/// All the missing and mistyped functions are reported at once
+pub fn validate_module(module: &LuaTable) -> anyhow::Result<()> {
+    let mut errors = Vec::new();
+
+    for (path, required) in MODULE_SCHEMA {
+        match get_value(module, path) {
+            Ok(LuaValue::Function(_)) => (),
+            Ok(LuaValue::Nil) if !required => (),
+
+            Ok(LuaValue::Nil) => errors.push(format!("missing required function `{path}`")),
+            Ok(value) => errors.push(format!("`{path}` expected to be a function, got {}", value.type_name())),
+
+            Err(err) => errors.push(err)
+        }
+    }
+
+    errors.dedup();
+
+    if !errors.is_empty() {
+        anyhow::bail!("Wrong v2 integration module structure:\n- {}", errors.join("\n- "));
+    }
+
+    Ok(())
+}
+
+/// Get module function by its dot-separated path
+pub fn get_function<'lua>(lua: &'lua Lua, path: &str) -> anyhow::Result<Option<LuaFunction<'lua>>> {
+    let module = lua.named_registry_value::<LuaTable>(MODULE_REGISTRY_KEY)?;
+
+    match get_value(&module, path) {
+        Ok(LuaValue::Function(function)) => Ok(Some(function)),
+        Ok(_) => Ok(None),
+
+        Err(err) => anyhow::bail!(err)
+    }
+}
+
+/// Check if the module implements given function
+#[inline]
+pub fn has_function(lua: &Lua, path: &str) -> anyhow::Result<bool> {
+    Ok(get_function(lua, path)?.is_some())
+}
+
+/// Call module function by its dot-separated path
+pub fn call<'lua, R: FromLuaMulti<'lua>>(lua: &'lua Lua, path: &str, args: impl IntoLuaMulti<'lua>) -> anyhow::Result<R> {
+    let Some(function) = get_function(lua, path)? else {
+        anyhow::bail!("Integration module doesn't implement `{path}` function");
+    };
+
+    Ok(function.call(args)?)
+}
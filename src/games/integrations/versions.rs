@@ -0,0 +1,199 @@
+use std::path::{Path, PathBuf, Component};
+
+use serde_json::Value as Json;
+
+use crate::config;
+
+use super::manifest::Manifest;
+
+/// Name of the folder in the integrations folder
+/// which stores previous integrations versions
+pub const VERSIONS_FOLDER: &str = ".versions";
+
+/// Get folder with previous versions of the game integration
+#[inline]
+pub fn get_versions_path(integrations_path: &Path, game: &str) -> PathBuf {
+    integrations_path.join(VERSIONS_FOLDER).join(game)
+}
+
+/// Check that the version can be used as a folder name
+/// 
+/// Versions come from remote manifests so they must not
+/// point outside of the versions folder (e.g. `../..`)
+pub fn is_valid_version(version: &str) -> bool {
+    let mut components = Path::new(version).components();
+
+    matches!((components.next(), components.next()), (Some(Component::Normal(_)), None))
+}
+
+/// Read manifest from the integration folder
+pub fn read_manifest(integration_path: &Path) -> anyhow::Result<Manifest> {
+    let manifest = std::fs::read(integration_path.join("manifest.json"))?;
+    let manifest = serde_json::from_slice::<Json>(&manifest)?;
+
+    Manifest::from_json(&manifest)
+}
+
+/// List saved previous versions of the game integration
+/// 
+/// Newest versions go first
+pub fn list(integrations_path: &Path, game: &str) -> anyhow::Result<Vec<String>> {
+    let versions_path = get_versions_path(integrations_path, game);
+
+    if !versions_path.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut versions = Vec::new();
+
+    for entry in versions_path.read_dir()?.flatten() {
+        if entry.path().is_dir() {
+            let modified = entry.metadata()?.modified()?;
+
+            versions.push((entry.file_name().to_string_lossy().to_string(), modified));
+        }
+    }
+
+    versions.sort_by(|a, b| b.1.cmp(&a.1));
+
+    Ok(versions.into_iter().map(|(version, _)| version).collect())
+}
+
+/// Copy current version of the game integration to the versions folder
+/// and remove the oldest ones so only `keep` versions are stored
+/// 
+/// Pinned version is never removed and not counted in `keep`
+pub fn archive(integrations_path: &Path, game: &str, keep: usize) -> anyhow::Result<()> {
+    let integration_path = integrations_path.join(game);
+
+    if keep == 0 || !integration_path.join("manifest.json").exists() {
+        return Ok(());
+    }
+
+    let manifest = read_manifest(&integration_path)?;
+
+    if !is_valid_version(&manifest.script_version) {
+        anyhow::bail!("Wrong {game} integration version: {}", manifest.script_version);
+    }
+
+    let version_path = get_versions_path(integrations_path, game)
+        .join(&manifest.script_version);
+
+    if version_path.exists() {
+        std::fs::remove_dir_all(&version_path)?;
+    }
+
+    copy_folder(&integration_path, &version_path)?;
+
+    // Pinned version is used instead of the latest one so it must be kept
+    let pinned = config::get().games.integrations.pinned.remove(game);
+
+    let versions = list(integrations_path, game)?.into_iter()
+        .filter(|version| Some(version) != pinned.as_ref());
+
+    for version in versions.skip(keep) {
+        if !is_valid_version(&version) {
+            tracing::warn!("Skipping removal of wrong {game} integration version: {version}");
+
+            continue;
+        }
+
+        std::fs::remove_dir_all(get_versions_path(integrations_path, game).join(version))?;
+    }
+
+    Ok(())
+}
+
+/// Get path to the manifest of the game integration
+/// respecting the pinned version from the config
+pub fn get_manifest_path(integrations_path: &Path, game: &str) -> PathBuf {
+    let integration_path = integrations_path.join(game);
+
+    if let Some(version) = config::get().games.integrations.pinned.get(game).filter(|version| is_valid_version(version)) {
+        let is_current = read_manifest(&integration_path)
+            .map(|manifest| &manifest.script_version == version)
+            .unwrap_or_default();
+
+        if !is_current {
+            let version_path = get_versions_path(integrations_path, game).join(version);
+
+            if version_path.join("manifest.json").exists() {
+                return version_path.join("manifest.json");
+            }
+
+            tracing::warn!("Pinned {game} integration version {version} not found, using the latest one");
+        }
+    }
+
+    integration_path.join("manifest.json")
+}
+
+/// Get saved version older than the currently used one
+pub fn get_older_version(integrations_path: &Path, game: &str) -> anyhow::Result<Option<String>> {
+    let current = read_manifest(get_manifest_path(integrations_path, game).parent().unwrap_or(integrations_path))?
+        .script_version;
+
+    let versions = list(integrations_path, game)?;
+
+    // Versions list is sorted from newest to oldest
+    let version = match versions.iter().position(|version| version == &current) {
+        Some(i) => versions.get(i + 1),
+        None => versions.first()
+    };
+
+    Ok(version.cloned())
+}
+
+/// Check if the game integration can be reverted to an older saved version
+#[inline]
+pub fn can_revert(game: &str) -> bool {
+    get_older_version(&config::get().games.integrations.path, game)
+        .map(|version| version.is_some())
+        .unwrap_or_default()
+}
+
+/// Pin the game integration to the saved version older than the currently used one
+/// 
+/// Returns pinned version
+pub fn revert(game: &str) -> anyhow::Result<String> {
+    let integrations_path = config::get().games.integrations.path;
+
+    let Some(version) = get_older_version(&integrations_path, game)? else {
+        anyhow::bail!("There are no saved versions older than the current one");
+    };
+
+    // Game names can contain dots so the pin can't be set by its dotted path
+    let mut config = config::get();
+
+    config.games.integrations.pinned.insert(game.to_string(), version.clone());
+
+    config::update(&config)?;
+
+    Ok(version)
+}
+
+/// Remove the game integration pin so the latest version is used
+#[inline]
+pub fn unpin(game: &str) -> anyhow::Result<()> {
+    let mut config = config::get();
+
+    config.games.integrations.pinned.remove(game);
+
+    config::update(&config)
+}
+
+fn copy_folder(from: &Path, to: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(to)?;
+
+    for entry in from.read_dir()?.flatten() {
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            copy_folder(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+
+    Ok(())
+}
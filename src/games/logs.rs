@@ -0,0 +1,69 @@
+use std::path::PathBuf;
+use std::fs::File;
+
+use crate::LAUNCHER_FOLDER;
+
+/// Get path to the game edition sessions logs folder
+#[inline]
+pub fn get_logs_folder(game: impl AsRef<str>, edition: impl AsRef<str>) -> PathBuf {
+    LAUNCHER_FOLDER
+        .join("games")
+        .join(game.as_ref())
+        .join(edition.as_ref())
+        .join("logs")
+}
+
+/// Get sessions logs of the game edition, from oldest to newest
+pub fn get_logs(game: impl AsRef<str>, edition: impl AsRef<str>) -> anyhow::Result<Vec<PathBuf>> {
+    let folder = get_logs_folder(game, edition);
+
+    if !folder.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut logs = folder.read_dir()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map(|ext| ext == "log").unwrap_or(false))
+        .collect::<Vec<_>>();
+
+    // Logs names are timestamps so they're sorted by creation time
+    logs.sort();
+
+    Ok(logs)
+}
+
+/// Get the latest session log of the game edition
+#[inline]
+pub fn get_last_log(game: impl AsRef<str>, edition: impl AsRef<str>) -> anyhow::Result<Option<PathBuf>> {
+    Ok(get_logs(game, edition)?.pop())
+}
+
+/// Create new session log file
+/// 
+/// Old logs are removed so only `keep_sessions` latest ones
+/// remain, including the created one
+pub fn create_session_log(game: impl AsRef<str>, edition: impl AsRef<str>, keep_sessions: u64) -> anyhow::Result<(PathBuf, File)> {
+    let folder = get_logs_folder(game.as_ref(), edition.as_ref());
+
+    if !folder.exists() {
+        std::fs::create_dir_all(&folder)?;
+    }
+
+    let logs = get_logs(game.as_ref(), edition.as_ref())?;
+
+    let keep = (keep_sessions as usize).saturating_sub(1);
+
+    if logs.len() > keep {
+        for log in &logs[..logs.len() - keep] {
+            if let Err(err) = std::fs::remove_file(log) {
+                tracing::warn!("Failed to remove old session log {log:?}: {err}");
+            }
+        }
+    }
+
+    let path = folder.join(format!("{}.log", chrono::Local::now().format("%Y-%m-%d_%H-%M-%S")));
+    let file = File::create(&path)?;
+
+    Ok((path, file))
+}
@@ -3,6 +3,7 @@ use serde_json::Value as Json;
 
 use chrono::Datelike;
 
+use crate::tr;
 use crate::LAUNCHER_FOLDER;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -65,66 +66,179 @@ impl LauncherMetadata {
         Ok(())
     }
 
-    pub fn get_last_played_text(&self) -> String {
-        let Some(last_launch) = self.launches.last_launch else {
-            return String::from("Never");
+    /// Start new game session and return its index
+    /// 
+    /// Sessions interrupted by the launcher crash are finished before that
+    pub fn start_session(&mut self, wine_version: Option<String>) -> usize {
+        self.finish_interrupted_sessions();
+
+        self.launches.sessions.push(GameSessionMetadata::start(wine_version));
+
+        self.launches.sessions.len() - 1
+    }
+
+    /// Update session heartbeat so its playtime is not lost if the launcher crashes
+    #[inline]
+    pub fn update_session(&mut self, index: usize) {
+        if let Some(session) = self.launches.sessions.get_mut(index) {
+            session.updated_at = chrono::Utc::now().timestamp();
+        }
+    }
+
+    /// Finish game session and update total playtime
+    pub fn finish_session(&mut self, index: usize, exit_code: Option<i32>) {
+        if let Some(session) = self.launches.sessions.get_mut(index) {
+            let now = chrono::Utc::now().timestamp();
+
+            session.updated_at = now;
+            session.stopped_at = Some(now);
+            session.exit_code = exit_code;
+
+            self.launches.total_playtime += session.get_duration();
+
+            self.launches.last_launch = Some(GameLastLaunchMetadata {
+                started_at: session.started_at,
+                stopped_at: now
+            });
+        }
+    }
+
+    /// Finish sessions which were not stopped properly
+    /// using their last heartbeat time
+    pub fn finish_interrupted_sessions(&mut self) {
+        for session in &mut self.launches.sessions {
+            if session.stopped_at.is_none() {
+                session.stopped_at = Some(session.updated_at);
+
+                self.launches.total_playtime += session.get_duration();
+
+                self.launches.last_launch = Some(GameLastLaunchMetadata {
+                    started_at: session.started_at,
+                    stopped_at: session.updated_at
+                });
+            }
+        }
+    }
+
+    /// Get total playtime including sessions in progress
+    pub fn get_total_playtime(&self) -> u64 {
+        let in_progress = self.launches.sessions.iter()
+            .filter(|session| session.stopped_at.is_none())
+            .map(GameSessionMetadata::get_duration)
+            .sum::<u64>();
+
+        self.launches.total_playtime + in_progress
+    }
+
+    /// Get average amount of game sessions per week
+    /// since the first recorded session
+    pub fn get_sessions_per_week(&self) -> f64 {
+        let Some(first) = self.launches.sessions.first() else {
+            return 0.0;
         };
 
-        let last_launch = chrono::DateTime::from_timestamp(last_launch.stopped_at, 0).unwrap();
+        const WEEK: i64 = 7 * 24 * 60 * 60;
 
-        let today = chrono::Utc::now().num_days_from_ce();
-        let last_run = last_launch.num_days_from_ce();
+        let weeks = ((chrono::Utc::now().timestamp() - first.started_at) as f64 / WEEK as f64).max(1.0);
 
-        match today - last_run {
-            1 => String::from("Yesterday"),
-            0 => String::from("Today"),
+        self.launches.sessions.len() as f64 / weeks
+    }
 
-            _ => format!("{}", last_launch.format("%d/%m/%Y"))
+    /// Get average game session length in seconds
+    pub fn get_average_session_length(&self) -> u64 {
+        if self.launches.sessions.is_empty() {
+            return 0;
         }
+
+        let total = self.launches.sessions.iter()
+            .map(GameSessionMetadata::get_duration)
+            .sum::<u64>();
+
+        total / self.launches.sessions.len() as u64
     }
 
-    // FIXME: make this more efficient
-    pub fn get_total_playtime_text(&self) -> String {
-        let mut seconds = self.launches.total_playtime;
-        let mut minutes = seconds / 60;
+    pub fn get_last_played_text(&self) -> String {
+        let Some(last_launch) = self.launches.last_launch else {
+            return tr!("details-never");
+        };
 
-        seconds -= minutes * 60;
+        let Some(last_launch) = chrono::DateTime::from_timestamp(last_launch.stopped_at, 0) else {
+            return tr!("details-never");
+        };
 
-        let hours = minutes / 60;
+        let last_launch = last_launch.with_timezone(&chrono::Local);
 
-        minutes -= hours * 60;
+        let today = chrono::Local::now().num_days_from_ce();
+        let last_run = last_launch.num_days_from_ce();
 
-        if hours > 0 {
-            if hours < 999 {
-                return format!("{hours} hours");
-            }
+        match today - last_run {
+            0 => tr!("details-today"),
+            1 => tr!("details-yesterday"),
 
-            let hours = hours.to_string().as_bytes()
-                .rchunks(3).rev()
-                .map(std::str::from_utf8)
-                .collect::<Result<Vec<&str>, _>>()
-                .unwrap()
-                .join(",");
+            days @ 2..=6 => tr!("details-days-ago", {
+                "days" = days
+            }),
 
-            return format!("{hours} hours");
+            _ => last_launch.format(&tr!("details-date-format")).to_string()
         }
+    }
+
+    #[inline]
+    pub fn get_total_playtime_text(&self) -> String {
+        match self.get_total_playtime() {
+            0 => tr!("details-never"),
+            playtime => get_duration_text(playtime)
+        }
+    }
 
-        else if minutes > 0 {
-            return format!("{minutes} minutes");
+    #[inline]
+    pub fn get_average_session_text(&self) -> String {
+        get_duration_text(self.get_average_session_length())
+    }
+}
+
+/// Get localized duration text, e.g. `12 h 30 min` or `5 minutes`
+fn get_duration_text(seconds: u64) -> String {
+    let hours = seconds / 3600;
+    let minutes = seconds % 3600 / 60;
+    let seconds = seconds % 60;
+
+    if hours > 0 {
+        if minutes > 0 {
+            tr!("details-duration-hours-minutes", {
+                "hours" = hours,
+                "minutes" = minutes
+            })
         }
 
-        else if seconds > 0 {
-            return format!("{seconds} seconds");
+        else {
+            tr!("details-duration-hours", {
+                "hours" = hours
+            })
         }
+    }
+
+    else if minutes > 0 {
+        tr!("details-duration-minutes", {
+            "minutes" = minutes
+        })
+    }
 
-        String::from("Never")
+    else {
+        tr!("details-duration-seconds", {
+            "seconds" = seconds
+        })
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct GameLaunchesMetadata {
     pub last_launch: Option<GameLastLaunchMetadata>,
-    pub total_playtime: u64
+
+    /// Playtime of finished sessions in seconds
+    pub total_playtime: u64,
+
+    pub sessions: Vec<GameSessionMetadata>
 }
 
 impl Default for GameLaunchesMetadata {
@@ -132,7 +246,8 @@ impl Default for GameLaunchesMetadata {
     fn default() -> Self {
         Self {
             last_launch: None,
-            total_playtime: 0
+            total_playtime: 0,
+            sessions: Vec::new()
         }
     }
 }
@@ -141,20 +256,36 @@ impl From<&Json> for GameLaunchesMetadata {
     fn from(value: &Json) -> Self {
         let default = Self::default();
 
+        let last_launch = value.get("last_launch")
+            .map(|value| {
+                if value.is_null() {
+                    None
+                } else {
+                    Some(GameLastLaunchMetadata::from(value))
+                }
+            })
+            .unwrap_or(default.last_launch);
+
+        let sessions = match value.get("sessions").and_then(Json::as_array) {
+            Some(sessions) => sessions.iter()
+                .map(GameSessionMetadata::from)
+                .collect(),
+
+            // Metadata files created before sessions history
+            // have only the last launch info stored
+            None => last_launch.iter()
+                .map(GameSessionMetadata::from)
+                .collect()
+        };
+
         Self {
-            last_launch: value.get("last_launch")
-                .map(|value| {
-                    if value.is_null() {
-                        None
-                    } else {
-                        Some(GameLastLaunchMetadata::from(value))
-                    }
-                })
-                .unwrap_or(default.last_launch),
+            last_launch,
 
             total_playtime: value.get("total_playtime")
                 .and_then(Json::as_u64)
-                .unwrap_or(default.total_playtime)
+                .unwrap_or(default.total_playtime),
+
+            sessions
         }
     }
 }
@@ -192,3 +323,92 @@ impl From<&Json> for GameLastLaunchMetadata {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GameSessionMetadata {
+    pub started_at: i64,
+
+    /// Last time the session was known to be in progress
+    /// 
+    /// Used to finish sessions interrupted by the launcher crash
+    pub updated_at: i64,
+
+    /// Not set while the session is in progress
+    pub stopped_at: Option<i64>,
+
+    /// Exit code of the launched process
+    pub exit_code: Option<i32>,
+
+    pub launcher_version: Option<String>,
+    pub wine_version: Option<String>
+}
+
+impl GameSessionMetadata {
+    pub fn start(wine_version: Option<String>) -> Self {
+        let now = chrono::Utc::now().timestamp();
+
+        Self {
+            started_at: now,
+            updated_at: now,
+            stopped_at: None,
+            exit_code: None,
+            launcher_version: Some(crate::APP_VERSION.to_string()),
+            wine_version
+        }
+    }
+
+    /// Get session duration in seconds
+    /// 
+    /// Last heartbeat time is used for sessions in progress
+    #[inline]
+    pub fn get_duration(&self) -> u64 {
+        u64::try_from(self.stopped_at.unwrap_or(self.updated_at) - self.started_at).unwrap_or_default()
+    }
+}
+
+impl From<&GameLastLaunchMetadata> for GameSessionMetadata {
+    #[inline]
+    fn from(value: &GameLastLaunchMetadata) -> Self {
+        Self {
+            started_at: value.started_at,
+            updated_at: value.stopped_at,
+            stopped_at: Some(value.stopped_at),
+            exit_code: None,
+            launcher_version: None,
+            wine_version: None
+        }
+    }
+}
+
+impl From<&Json> for GameSessionMetadata {
+    fn from(value: &Json) -> Self {
+        let now = chrono::Utc::now().timestamp();
+
+        let started_at = value.get("started_at")
+            .and_then(Json::as_i64)
+            .unwrap_or(now);
+
+        Self {
+            started_at,
+
+            updated_at: value.get("updated_at")
+                .and_then(Json::as_i64)
+                .unwrap_or(started_at),
+
+            stopped_at: value.get("stopped_at")
+                .and_then(Json::as_i64),
+
+            exit_code: value.get("exit_code")
+                .and_then(Json::as_i64)
+                .map(|code| code as i32),
+
+            launcher_version: value.get("launcher_version")
+                .and_then(Json::as_str)
+                .map(String::from),
+
+            wine_version: value.get("wine_version")
+                .and_then(Json::as_str)
+                .map(String::from)
+        }
+    }
+}
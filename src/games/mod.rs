@@ -4,6 +4,7 @@ use crate::config;
 
 pub mod integrations;
 pub mod metadata;
+pub mod logs;
 
 static mut GAMES_SINGLETON: Option<HashMap<String, integrations::Game>> = None;
 
@@ -13,10 +14,19 @@ pub fn init() -> anyhow::Result<()> {
     let mut games = HashMap::new();
 
     for entry in integration_scripts.read_dir()?.flatten() {
-        if entry.path().is_dir() {
-            let game = integrations::Game::new(entry.path().join("manifest.json"))?;
-
-            games.insert(entry.file_name().to_string_lossy().to_string(), game);
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        // Skip service folders like previous integrations versions
+        if entry.path().is_dir() && !name.starts_with('.') {
+            // Broken integration shouldn't prevent other games from loading.
+            // Its files fail verification so it's downloaded again on the next start
+            match integrations::Game::new(integrations::versions::get_manifest_path(&integration_scripts, &name)) {
+                Ok(game) => {
+                    games.insert(name, game);
+                }
+
+                Err(err) => tracing::error!("Failed to load {name} integration, skipping it: {err}")
+            }
         }
     }
 
@@ -6,8 +6,10 @@ pub mod i18n;
 pub mod utils;
 pub mod config;
 pub mod games;
+pub mod network;
 pub mod components;
 pub mod ui;
+pub mod cli;
 
 use ui::windows::loading::LoadingApp;
 
@@ -44,12 +46,20 @@ lazy_static::lazy_static! {
 
     /// Path to launcher's debug log file
     pub static ref DEBUG_FILE: PathBuf = LAUNCHER_FOLDER.join("debug.log");
+
+    /// Path to the saved tasks queue file
+    pub static ref TASKS_FILE: PathBuf = LAUNCHER_FOLDER.join("tasks.json");
 }
 
 fn main() -> anyhow::Result<()> {
     // Setup custom panic handler
     human_panic::setup_panic!(human_panic::metadata!());
 
+    // Run headless command if one was given
+    if let Some(args) = cli::CliArgs::parse(std::env::args().skip(1))? {
+        return cli::run(args);
+    }
+
     adw::init().expect("Libadwaita initialization failed");
 
     // Register and include resources
@@ -0,0 +1,130 @@
+use std::sync::{Arc, Mutex, PoisonError};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Instant, Duration};
+
+use crate::config;
+
+lazy_static::lazy_static! {
+    /// Limiter shared by all the downloads
+    /// 
+    /// Its rate is read from the config when the first download starts
+    pub static ref GLOBAL_LIMITER: RateLimiter = RateLimiter::new(config::get().general.bandwidth.global_limit);
+}
+
+/// Timeout between large downloads window checks
+pub const DOWNLOAD_WINDOW_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant
+}
+
+/// Token bucket rate limiter
+/// 
+/// Tokens are bytes which can be downloaded. Bucket is refilled
+/// with `rate` tokens per second and can hold up to one second
+/// of traffic, so downloads can't burst after long idling
+#[derive(Debug)]
+pub struct RateLimiter {
+    rate: AtomicU64,
+    bucket: Mutex<Bucket>
+}
+
+impl RateLimiter {
+    /// Create new limiter with the given rate
+    /// in bytes per second. `0` disables the limiter
+    #[inline]
+    pub fn new(rate: u64) -> Self {
+        Self {
+            rate: AtomicU64::new(rate),
+            bucket: Mutex::new(Bucket {
+                tokens: rate as f64,
+                updated_at: Instant::now()
+            })
+        }
+    }
+
+    #[inline]
+    pub fn rate(&self) -> u64 {
+        self.rate.load(Ordering::Relaxed)
+    }
+
+    /// Take `amount` bytes from the bucket, blocking
+    /// the current thread until they're available
+    /// 
+    /// Bucket can go into debt so parallel downloads wait
+    /// for each other and share the same budget
+    pub fn acquire(&self, amount: u64) {
+        let rate = self.rate();
+
+        if rate == 0 {
+            return;
+        }
+
+        let wait = {
+            // Bucket contains only numbers so it can't be left broken
+            // by a panicked thread, and downloads shouldn't fail because of it
+            let mut bucket = self.bucket.lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            let now = Instant::now();
+            let elapsed = (now - bucket.updated_at).as_secs_f64();
+
+            bucket.tokens = (bucket.tokens + elapsed * rate as f64).min(rate as f64);
+            bucket.tokens -= amount as f64;
+            bucket.updated_at = now;
+
+            if bucket.tokens < 0.0 {
+                Duration::from_secs_f64(-bucket.tokens / rate as f64)
+            } else {
+                Duration::ZERO
+            }
+        };
+
+        if !wait.is_zero() {
+            std::thread::sleep(wait);
+        }
+    }
+}
+
+/// Bandwidth limiter of a single task
+/// 
+/// Cloned limiters share the same task budget, so
+/// all the task's threads are throttled together
+#[derive(Debug, Clone)]
+pub struct DownloadLimiter {
+    task: Arc<RateLimiter>
+}
+
+impl DownloadLimiter {
+    /// Create new task limiter using the current config's task limit
+    pub fn new() -> Self {
+        Self {
+            task: Arc::new(RateLimiter::new(config::get().general.bandwidth.task_limit))
+        }
+    }
+
+    /// Take `amount` bytes from both task and global budgets
+    #[inline]
+    pub fn acquire(&self, amount: u64) {
+        self.task.acquire(amount);
+
+        GLOBAL_LIMITER.acquire(amount);
+    }
+}
+
+impl Default for DownloadLimiter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Check if download of the given size is allowed to run now
+pub fn is_download_allowed(size: u64) -> bool {
+    match config::get().general.bandwidth.large_downloads {
+        Some(window) => window.allows(size, chrono::Local::now().time()),
+        None => true
+    }
+}
@@ -0,0 +1,309 @@
+use std::path::{Path, PathBuf};
+use std::io::{Read, Write};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use super::bandwidth::DownloadLimiter;
+
+use crate::network;
+
+/// Size of the buffer used to read response body
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// How often paused downloads check if they were resumed
+const PAUSE_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Files downloader which can be throttled by the bandwidth limiter
+#[derive(Debug, Clone)]
+pub struct Downloader {
+    uri: String,
+    continue_downloading: bool,
+    limiter: Option<DownloadLimiter>,
+    paused: Option<Arc<AtomicBool>>,
+    timeout: Option<Duration>
+}
+
+impl Downloader {
+    #[inline]
+    pub fn new(uri: impl ToString) -> Self {
+        Self {
+            uri: uri.to_string(),
+            continue_downloading: true,
+            limiter: None,
+            paused: None,
+            timeout: None
+        }
+    }
+
+    #[inline]
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// Continue downloading of already existing file (true by default)
+    #[inline]
+    pub fn continue_downloading(mut self, continue_downloading: bool) -> Self {
+        self.continue_downloading = continue_downloading;
+
+        self
+    }
+
+    #[inline]
+    pub fn with_limiter(mut self, limiter: DownloadLimiter) -> Self {
+        self.limiter = Some(limiter);
+
+        self
+    }
+
+    /// Stop reading the response while the flag is set
+    #[inline]
+    pub fn with_pause_flag(mut self, paused: Arc<AtomicBool>) -> Self {
+        self.paused = Some(paused);
+
+        self
+    }
+
+    /// Limit time of a single request
+    /// 
+    /// Requests timeout is not reset when the data is received, so the
+    /// file is downloaded by several requests each continuing from
+    /// the last received byte. Request without any received data fails
+    #[inline]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused.as_ref()
+            .map(|paused| paused.load(Ordering::Relaxed))
+            .unwrap_or(false)
+    }
+
+    /// Get name of the downloading file from its URI
+    pub fn file_name(&self) -> String {
+        let uri = self.uri.split(['?', '#'])
+            .next()
+            .unwrap_or(&self.uri);
+
+        uri.trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .filter(|name| !name.is_empty())
+            .unwrap_or("index.html")
+            .to_string()
+    }
+
+    /// Start downloading file in background thread
+    pub fn download(&self, path: impl AsRef<Path>) -> anyhow::Result<DownloadUpdater> {
+        let downloader = self.clone();
+        let path = path.as_ref().to_path_buf();
+
+        let current = Arc::new(AtomicU64::new(0));
+        let total = Arc::new(AtomicU64::new(1)); // To prevent division by 0
+        let aborted = Arc::new(AtomicBool::new(false));
+
+        Ok(DownloadUpdater {
+            current: current.clone(),
+            total: total.clone(),
+            aborted: aborted.clone(),
+
+            worker: Some(std::thread::spawn(move || {
+                downloader.download_file(path, current, total, aborted)
+            }))
+        })
+    }
+
+    fn download_file(&self, path: PathBuf, current: Arc<AtomicU64>, total: Arc<AtomicU64>, aborted: Arc<AtomicBool>) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut downloaded = 0;
+
+        if self.continue_downloading {
+            if let Ok(metadata) = path.metadata() {
+                downloaded = metadata.len();
+            }
+        }
+
+        // File opened by the previous request
+        let mut opened = None;
+        let mut buf = vec![0; CHUNK_SIZE];
+
+        loop {
+            // Range is always requested to know if the server supports it
+            let mut request = network::get(&self.uri)
+                .with_header("Range", format!("bytes={downloaded}-"));
+
+            if let Some(timeout) = self.timeout {
+                request = request.with_timeout(timeout.as_secs().max(1));
+            }
+
+            let mut response = request.send_streamed()?;
+
+            // Requested range is empty so the file is already downloaded
+            if downloaded > 0 && response.status_code == 416 {
+                current.store(downloaded, Ordering::Relaxed);
+                total.store(downloaded, Ordering::Relaxed);
+
+                return Ok(());
+            }
+
+            if !response.is_ok() {
+                anyhow::bail!("Failed to download file from {}: {} {}", self.uri, response.status_code, response.reason_phrase);
+            }
+
+            // Server doesn't support ranges so the file is downloaded from scratch
+            let is_partial = response.status_code == 206;
+
+            if !is_partial {
+                downloaded = 0;
+                opened = None;
+            }
+
+            let length = response.headers.get("content-length")
+                .and_then(|length| length.parse::<u64>().ok());
+
+            current.store(downloaded, Ordering::Relaxed);
+
+            if let Some(length) = length {
+                total.store(downloaded + length, Ordering::Relaxed);
+            }
+
+            let mut file = match opened.take() {
+                Some(file) => file,
+
+                None if downloaded > 0 => std::fs::OpenOptions::new()
+                    .append(true)
+                    .open(&path)?,
+
+                None => std::fs::File::create(&path)?
+            };
+
+            let mut received = 0;
+            let mut was_paused = false;
+
+            let result = loop {
+                while self.is_paused() && !aborted.load(Ordering::Relaxed) {
+                    was_paused = true;
+
+                    std::thread::sleep(PAUSE_TIMEOUT);
+                }
+
+                let read = match response.body.read(&mut buf) {
+                    Ok(0) => break Ok(()),
+                    Ok(read) => read,
+
+                    Err(err) => break Err(err)
+                };
+
+                // Aborted thread can be waiting for the network for a long time,
+                // so it shouldn't write anything after a new attempt was started
+                if aborted.load(Ordering::Relaxed) {
+                    anyhow::bail!("Downloading was aborted");
+                }
+
+                file.write_all(&buf[..read])?;
+
+                current.fetch_add(read as u64, Ordering::Relaxed);
+
+                received += read as u64;
+                downloaded += read as u64;
+
+                if let Some(limiter) = &self.limiter {
+                    limiter.acquire(read as u64);
+                }
+            };
+
+            match result {
+                Ok(()) => {
+                    file.flush()?;
+
+                    if length.is_none() {
+                        total.store(current.load(Ordering::Relaxed), Ordering::Relaxed);
+                    }
+
+                    return Ok(());
+                }
+
+                // Request has reached its timeout while the data was still
+                // being received or the downloading was paused,
+                // so the next one continues downloading
+                Err(err) if is_partial && (received > 0 || was_paused) && is_timeout(&err) => {
+                    file.flush()?;
+
+                    opened = Some(file);
+
+                    tracing::debug!("Continuing downloading of {} from {downloaded} bytes", self.uri);
+                }
+
+                Err(err) => return Err(err.into())
+            }
+        }
+    }
+}
+
+#[inline]
+fn is_timeout(err: &std::io::Error) -> bool {
+    matches!(err.kind(), std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock)
+}
+
+#[derive(Debug)]
+pub struct DownloadUpdater {
+    current: Arc<AtomicU64>,
+    total: Arc<AtomicU64>,
+    aborted: Arc<AtomicBool>,
+
+    worker: Option<JoinHandle<anyhow::Result<()>>>
+}
+
+impl DownloadUpdater {
+    /// Check if the downloading thread is finished,
+    /// either successfully or with an error
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.worker.as_ref()
+            .map(JoinHandle::is_finished)
+            .unwrap_or(true)
+    }
+
+    /// Amount of downloaded bytes
+    #[inline]
+    pub fn current(&self) -> u64 {
+        self.current.load(Ordering::Relaxed)
+    }
+
+    /// Total size of the file in bytes
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.total.load(Ordering::Relaxed)
+    }
+
+    /// Stop downloading and wait until the thread is finished
+    /// 
+    /// Thread is stopped after it receives the next chunk or reaches
+    /// the request timeout. Waiting for it guarantees that nothing
+    /// will be written to the file when it's downloaded again
+    pub fn abort(mut self) {
+        self.aborted.store(true, Ordering::Relaxed);
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+
+    /// Wait until the file is downloaded and return the result
+    pub fn wait(mut self) -> anyhow::Result<()> {
+        match self.worker.take() {
+            Some(worker) => worker.join()
+                .map_err(|_| anyhow::anyhow!("Downloading thread panicked"))?,
+
+            None => Ok(())
+        }
+    }
+}
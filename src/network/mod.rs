@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::io::Read;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+
+use anime_game_core::network::minreq;
+
+pub use minreq::Method as RequestMethod;
+
+use crate::LAUNCHER_FOLDER;
+
+pub mod bandwidth;
+pub mod downloader;
+pub mod retry;
+
+lazy_static::lazy_static! {
+    /// Transport used by the launcher's HTTP requests
+    /// 
+    /// Resolved from `$LAUNCHER_NETWORK_MODE` (`live`, `record` or `replay`)
+    /// and `$LAUNCHER_NETWORK_FIXTURES` (fixtures folder,
+    /// `<launcher folder>/fixtures` by default)
+    pub static ref TRANSPORT: Transport = {
+        let fixtures = std::env::var("LAUNCHER_NETWORK_FIXTURES")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| LAUNCHER_FOLDER.join("fixtures"));
+
+        match std::env::var("LAUNCHER_NETWORK_MODE").as_deref() {
+            Ok("record") => Transport::Record(fixtures),
+            Ok("replay") => Transport::Replay(fixtures),
+
+            _ => Transport::Live
+        }
+    };
+}
+
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Mark the launcher as working in offline mode
+/// 
+/// Set when some remote resource couldn't be fetched
+/// and its cached version was used instead
+#[inline]
+pub fn set_offline() {
+    OFFLINE.store(true, Ordering::Relaxed);
+}
+
+/// Check if some remote resources couldn't be fetched during this session
+#[inline]
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// Check if the error was caused by the network
+/// 
+/// Other errors (e.g. wrong files hashes) mustn't be
+/// treated as the launcher being offline
+#[inline]
+pub fn is_transport_error(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<minreq::Error>().is_some()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Transport {
+    /// Send requests to the network
+    Live,
+
+    /// Send requests to the network and save responses to the fixtures folder
+    Record(PathBuf),
+
+    /// Read responses from the fixtures folder without accessing the network
+    Replay(PathBuf)
+}
+
+impl Transport {
+    pub fn send(&self, request: &Request) -> anyhow::Result<Response> {
+        match self {
+            Self::Live => request.send_live(),
+
+            Self::Record(fixtures) => {
+                let response = request.send_live()?;
+
+                response.save(fixtures, request)?;
+
+                Ok(response)
+            }
+
+            Self::Replay(fixtures) => Response::load(fixtures, request)
+        }
+    }
+
+    /// Send request without reading its body
+    /// 
+    /// Only live responses are streamed. Recorded and replayed ones
+    /// are read into memory because fixtures store whole responses
+    pub fn send_streamed(&self, request: &Request) -> anyhow::Result<StreamedResponse> {
+        match self {
+            Self::Live => request.send_lazy(),
+
+            _ => self.send(request).map(StreamedResponse::from)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: RequestMethod,
+    pub uri: String,
+    pub headers: HashMap<String, String>,
+    pub body: Option<Vec<u8>>,
+
+    /// Request timeout, in seconds
+    pub timeout: Option<u64>
+}
+
+impl Request {
+    #[inline]
+    pub fn new(method: RequestMethod, uri: impl ToString) -> Self {
+        Self {
+            method,
+            uri: uri.to_string(),
+            headers: HashMap::new(),
+            body: None,
+            timeout: None
+        }
+    }
+
+    #[inline]
+    pub fn with_header(mut self, key: impl ToString, value: impl ToString) -> Self {
+        self.headers.insert(key.to_string(), value.to_string());
+
+        self
+    }
+
+    #[inline]
+    pub fn with_body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = Some(body.into());
+
+        self
+    }
+
+    #[inline]
+    pub fn with_timeout(mut self, timeout: u64) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Send request using the selected transport
+    #[inline]
+    pub fn send(&self) -> anyhow::Result<Response> {
+        TRANSPORT.send(self)
+    }
+
+    /// Send request using the selected transport without reading its body
+    #[inline]
+    pub fn send_streamed(&self) -> anyhow::Result<StreamedResponse> {
+        TRANSPORT.send_streamed(self)
+    }
+
+    fn build(&self) -> minreq::Request {
+        let mut request = minreq::Request::new(self.method.clone(), &self.uri);
+
+        for (key, value) in &self.headers {
+            request = request.with_header(key, value);
+        }
+
+        if let Some(body) = &self.body {
+            request = request.with_body(body.clone());
+        }
+
+        if let Some(timeout) = self.timeout {
+            request = request.with_timeout(timeout);
+        }
+
+        request
+    }
+
+    fn send_live(&self) -> anyhow::Result<Response> {
+        let response = self.build().send()?;
+
+        Ok(Response {
+            url: response.url.clone(),
+            status_code: response.status_code,
+            reason_phrase: response.reason_phrase.clone(),
+            headers: response.headers.clone(),
+            body: response.into_bytes()
+        })
+    }
+
+    fn send_lazy(&self) -> anyhow::Result<StreamedResponse> {
+        let response = self.build().send_lazy()?;
+
+        Ok(StreamedResponse {
+            status_code: response.status_code,
+            reason_phrase: response.reason_phrase.clone(),
+            headers: response.headers.clone(),
+            body: Box::new(response)
+        })
+    }
+
+    /// Name of the fixture files storing response to this request
+    /// 
+    /// Headers are not used because they can contain private tokens
+    fn fixture_name(&self) -> String {
+        let mut key = format!("{} {}", self.method, self.uri).into_bytes();
+
+        if let Some(body) = &self.body {
+            key.push(b'\n');
+            key.extend_from_slice(body);
+        }
+
+        format!("{:016x}", xxhash_rust::xxh3::xxh3_64(&key))
+    }
+}
+
+/// Create GET request
+#[inline]
+pub fn get(uri: impl ToString) -> Request {
+    Request::new(RequestMethod::Get, uri)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Response {
+    pub url: String,
+    pub status_code: i32,
+    pub reason_phrase: String,
+    pub headers: HashMap<String, String>,
+
+    #[serde(skip)]
+    pub body: Vec<u8>
+}
+
+impl Response {
+    /// HTTP OK
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        (200..300).contains(&self.status_code)
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.body
+    }
+
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.body
+    }
+
+    #[inline]
+    pub fn json<T: DeserializeOwned>(&self) -> anyhow::Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+
+    /// Save response to the fixtures folder
+    /// 
+    /// Response info is stored in `<name>.json` file
+    /// and its body in `<name>.body`
+    fn save(&self, fixtures: &Path, request: &Request) -> anyhow::Result<()> {
+        let name = request.fixture_name();
+
+        std::fs::create_dir_all(fixtures)?;
+
+        std::fs::write(fixtures.join(format!("{name}.json")), serde_json::to_string_pretty(&serde_json::json!({
+            "request": {
+                "method": request.method.to_string(),
+                "uri": request.uri
+            },
+            "response": self
+        }))?)?;
+
+        std::fs::write(fixtures.join(format!("{name}.body")), &self.body)?;
+
+        Ok(())
+    }
+
+    /// Load recorded response from the fixtures folder
+    fn load(fixtures: &Path, request: &Request) -> anyhow::Result<Self> {
+        let name = request.fixture_name();
+
+        let info_path = fixtures.join(format!("{name}.json"));
+
+        if !info_path.exists() {
+            anyhow::bail!("No recorded response for {} {}", request.method, request.uri);
+        }
+
+        let info = serde_json::from_slice::<serde_json::Value>(&std::fs::read(info_path)?)?;
+
+        let Some(response) = info.get("response") else {
+            anyhow::bail!("Wrong fixture structure: field `response` expected but wasn't presented");
+        };
+
+        let mut response = serde_json::from_value::<Self>(response.clone())?;
+
+        response.body = std::fs::read(fixtures.join(format!("{name}.body")))?;
+
+        Ok(response)
+    }
+}
+
+/// Response which body is read on demand
+pub struct StreamedResponse {
+    pub status_code: i32,
+    pub reason_phrase: String,
+    pub headers: HashMap<String, String>,
+    pub body: Box<dyn Read + Send>
+}
+
+impl StreamedResponse {
+    /// HTTP OK
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        (200..300).contains(&self.status_code)
+    }
+}
+
+impl std::fmt::Debug for StreamedResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamedResponse")
+            .field("status_code", &self.status_code)
+            .field("reason_phrase", &self.reason_phrase)
+            .field("headers", &self.headers)
+            .finish()
+    }
+}
+
+impl From<Response> for StreamedResponse {
+    fn from(response: Response) -> Self {
+        Self {
+            status_code: response.status_code,
+            reason_phrase: response.reason_phrase,
+            headers: response.headers,
+            body: Box::new(std::io::Cursor::new(response.body))
+        }
+    }
+}
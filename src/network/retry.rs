@@ -0,0 +1,264 @@
+use std::path::Path;
+use std::time::{Instant, Duration};
+
+use crate::config;
+
+use super::downloader::Downloader;
+
+/// Timeout between downloading progress checks
+pub const WATCH_TIMEOUT: Duration = Duration::from_millis(20);
+
+/// Max amount of failed URIs listed in the error message
+const MAX_LISTED_FAILURES: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_attempts: u64,
+    pub backoff_delay: Duration,
+    pub max_backoff_delay: Duration,
+    pub request_timeout: Duration,
+    pub stall_timeout: Duration
+}
+
+impl RetryPolicy {
+    /// Get retry policy from the current config
+    pub fn from_config() -> Self {
+        let retries = config::get().general.retries;
+
+        Self {
+            max_attempts: retries.max_attempts.max(1),
+            backoff_delay: Duration::from_millis(retries.backoff_delay),
+            max_backoff_delay: Duration::from_millis(retries.max_backoff_delay),
+            request_timeout: Duration::from_secs(retries.request_timeout),
+            stall_timeout: Duration::from_secs(retries.stall_timeout)
+        }
+    }
+
+    /// Get delay before the next attempt after
+    /// the given failed one (starting from 1)
+    pub fn get_delay(&self, attempt: u64) -> Duration {
+        let multiplier = 2u32.saturating_pow(attempt.saturating_sub(1).min(31) as u32);
+
+        self.backoff_delay.saturating_mul(multiplier)
+            .min(self.max_backoff_delay)
+    }
+}
+
+impl Default for RetryPolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::from_config()
+    }
+}
+
+/// File couldn't be downloaded after all the attempts
+#[derive(Debug)]
+pub struct DownloadFailed {
+    pub uri: String,
+    pub attempts: u64,
+    pub error: anyhow::Error
+}
+
+impl std::fmt::Display for DownloadFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to download {} after {} attempts: {}", self.uri, self.attempts, self.error)
+    }
+}
+
+impl std::error::Error for DownloadFailed {}
+
+/// Some files couldn't be downloaded after all the attempts
+/// 
+/// Other files are downloaded anyway, so only
+/// the failed ones should be downloaded again
+#[derive(Debug)]
+pub struct FilesDownloadFailed(pub Vec<DownloadFailed>);
+
+impl std::fmt::Display for FilesDownloadFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to download {} files:", self.0.len())?;
+
+        for failed in self.0.iter().take(MAX_LISTED_FAILURES) {
+            write!(f, "\n{}: {}", failed.uri, failed.error)?;
+        }
+
+        if self.0.len() > MAX_LISTED_FAILURES {
+            write!(f, "\nand {} more", self.0.len() - MAX_LISTED_FAILURES)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for FilesDownloadFailed {}
+
+/// Download file retrying failed attempts according to the policy
+/// 
+/// `callback` is called with current and total downloading progress.
+/// Its error stops downloading immediately without retries, so it can
+/// be used to cancel the task. Attempt fails if the server doesn't start
+/// sending the file in `request_timeout`, or there's no progress for
+/// `stall_timeout`. After the last failed attempt `DownloadFailed` is returned
+/// 
+/// Timeouts don't count time while the downloader is paused or the
+/// callback is running, so the callback can block until the task is resumed
+pub fn download(
+    downloader: &Downloader,
+    path: impl AsRef<Path>,
+    policy: &RetryPolicy,
+    mut callback: impl FnMut(u64, u64) -> anyhow::Result<()>
+) -> anyhow::Result<()> {
+    let path = path.as_ref();
+
+    // Abandoned attempts are waited for before starting the next ones,
+    // so their requests mustn't block for longer than the stall timeout
+    let downloader = downloader.clone()
+        .with_timeout(policy.stall_timeout);
+
+    let mut attempt = 1;
+
+    loop {
+        let updater = downloader.download(path)?;
+
+        let mut last_current = updater.current();
+        let mut last_total;
+        let mut last_progress = Instant::now();
+        let mut started = false;
+
+        let error = loop {
+            let current = updater.current();
+
+            last_total = updater.total();
+
+            let callback_started = Instant::now();
+
+            if let Err(err) = callback(current, last_total) {
+                updater.abort();
+
+                return Err(err);
+            }
+
+            last_progress += callback_started.elapsed();
+
+            if updater.is_finished() {
+                // Report the final progress, which could change
+                // after the callback above was called
+                let current = updater.current();
+                let total = updater.total();
+
+                match updater.wait() {
+                    Ok(()) => return callback(current, total),
+                    Err(err) => break err
+                }
+            }
+
+            if current != last_current {
+                last_current = current;
+                last_progress = Instant::now();
+                started = true;
+            }
+
+            if downloader.is_paused() {
+                last_progress = Instant::now();
+            }
+
+            let idle = last_progress.elapsed();
+
+            if !started && idle > policy.request_timeout {
+                updater.abort();
+
+                break anyhow::anyhow!("Server didn't respond in {} seconds", policy.request_timeout.as_secs());
+            }
+
+            if started && idle > policy.stall_timeout {
+                updater.abort();
+
+                break anyhow::anyhow!("Downloading stalled for {} seconds", policy.stall_timeout.as_secs());
+            }
+
+            std::thread::sleep(WATCH_TIMEOUT);
+        };
+
+        if attempt >= policy.max_attempts {
+            return Err(DownloadFailed {
+                uri: downloader.uri().to_string(),
+                attempts: attempt,
+                error
+            }.into());
+        }
+
+        let delay = policy.get_delay(attempt);
+
+        tracing::warn!(
+            "Failed to download {} (attempt {attempt}/{}), retrying in {} ms: {error}",
+            downloader.uri(),
+            policy.max_attempts,
+            delay.as_millis()
+        );
+
+        // Keep calling the callback so downloading can be cancelled during the delay
+        let retry_at = Instant::now() + delay;
+
+        while Instant::now() < retry_at {
+            callback(last_current, last_total)?;
+
+            std::thread::sleep(WATCH_TIMEOUT);
+        }
+
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(backoff_delay: u64, max_backoff_delay: u64) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            backoff_delay: Duration::from_millis(backoff_delay),
+            max_backoff_delay: Duration::from_millis(max_backoff_delay),
+            request_timeout: Duration::from_secs(30),
+            stall_timeout: Duration::from_secs(30)
+        }
+    }
+
+    #[test]
+    fn delay_growth() {
+        let policy = policy(500, 60_000);
+
+        assert_eq!(policy.get_delay(1), Duration::from_millis(500));
+        assert_eq!(policy.get_delay(2), Duration::from_millis(1000));
+        assert_eq!(policy.get_delay(3), Duration::from_millis(2000));
+        assert_eq!(policy.get_delay(4), Duration::from_millis(4000));
+        assert_eq!(policy.get_delay(5), Duration::from_millis(8000));
+
+        // Attempts are counted from 1
+        assert_eq!(policy.get_delay(0), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn delay_cap() {
+        let policy = policy(500, 3000);
+
+        assert_eq!(policy.get_delay(3), Duration::from_millis(2000));
+        assert_eq!(policy.get_delay(4), Duration::from_millis(3000));
+        assert_eq!(policy.get_delay(10), Duration::from_millis(3000));
+
+        // Huge attempts numbers don't overflow
+        assert_eq!(policy.get_delay(64), Duration::from_millis(3000));
+        assert_eq!(policy.get_delay(u64::MAX), Duration::from_millis(3000));
+    }
+
+    #[test]
+    fn zero_delay() {
+        let policy = policy(0, 60_000);
+
+        assert_eq!(policy.get_delay(1), Duration::ZERO);
+        assert_eq!(policy.get_delay(10), Duration::ZERO);
+
+        let policy = self::policy(500, 0);
+
+        assert_eq!(policy.get_delay(1), Duration::ZERO);
+    }
+}
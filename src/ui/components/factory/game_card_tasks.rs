@@ -1,10 +1,13 @@
 use relm4::prelude::*;
 
+use gtk::prelude::*;
+
+use crate::tr;
+
 use crate::ui::components::game_card::{
     CardInfo,
     CardComponent,
-    CardComponentInput,
-    CardComponentOutput
+    CardComponentInput
 };
 
 #[derive(Debug)]
@@ -12,25 +15,75 @@ pub struct CardFactory {
     pub component: AsyncController<CardComponent>
 }
 
+#[derive(Debug)]
+pub enum CardFactoryOutput {
+    MoveBackward(DynamicIndex),
+    MoveForward(DynamicIndex),
+    Remove(DynamicIndex)
+}
+
 #[relm4::factory(pub)]
 impl FactoryComponent for CardFactory {
     type Init = CardInfo;
     type Input = CardComponentInput;
-    type Output = CardComponentOutput;
+    type Output = CardFactoryOutput;
     type CommandOutput = ();
     type ParentWidget = gtk::FlowBox;
 
     view! {
         root = gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_spacing: 4,
+
             self.component.widget(),
+
+            gtk::Box {
+                set_halign: gtk::Align::Center,
+
+                add_css_class: "linked",
+
+                gtk::Button {
+                    set_icon_name: "go-previous-symbolic",
+                    set_tooltip_text: Some(&tr!("tasks-move-backward")),
+
+                    add_css_class: "flat",
+
+                    connect_clicked[sender, index = index.clone()] => move |_| {
+                        sender.output(CardFactoryOutput::MoveBackward(index.clone())).unwrap();
+                    }
+                },
+
+                gtk::Button {
+                    set_icon_name: "user-trash-symbolic",
+                    set_tooltip_text: Some(&tr!("tasks-remove")),
+
+                    add_css_class: "flat",
+
+                    connect_clicked[sender, index = index.clone()] => move |_| {
+                        sender.output(CardFactoryOutput::Remove(index.clone())).unwrap();
+                    }
+                },
+
+                gtk::Button {
+                    set_icon_name: "go-next-symbolic",
+                    set_tooltip_text: Some(&tr!("tasks-move-forward")),
+
+                    add_css_class: "flat",
+
+                    connect_clicked[sender, index = index.clone()] => move |_| {
+                        sender.output(CardFactoryOutput::MoveForward(index.clone())).unwrap();
+                    }
+                }
+            }
         }
     }
 
     #[inline]
-    fn init_model(init: Self::Init, _index: &DynamicIndex, sender: FactorySender<Self>) -> Self {
+    fn init_model(init: Self::Init, _index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+        // Queued cards are not clickable so their output is ignored
         let component = CardComponent::builder()
             .launch(init)
-            .forward(sender.output_sender(), std::convert::identity);
+            .detach();
 
         component.emit(CardComponentInput::SetWidth(60));
         component.emit(CardComponentInput::SetHeight(84));
@@ -0,0 +1,245 @@
+use relm4::prelude::*;
+
+use gtk::prelude::*;
+
+use crate::tr;
+
+use crate::ui::components::game_card::{
+    CardInfo,
+    CardComponent,
+    CardComponentInput
+};
+
+use crate::ui::components::tasks_queue::UPDATER_TIMEOUT;
+
+#[derive(Debug)]
+pub struct TaskProgressFactory {
+    pub info: CardInfo,
+    pub card: AsyncController<CardComponent>,
+    pub progress_bar: gtk::ProgressBar,
+
+    pub status: String,
+    pub progress: String,
+    pub pulse: bool,
+
+    pub speed: String,
+    pub eta: String,
+
+    /// Task can be paused and cancelled
+    pub controllable: bool,
+    pub paused: bool
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskProgressFactoryInput {
+    Update {
+        status: String,
+        progress: f64,
+        pulse: bool,
+        paused: bool,
+        speed: String,
+        eta: String
+    }
+}
+
+#[derive(Debug)]
+pub enum TaskProgressFactoryOutput {
+    Pause(DynamicIndex),
+    Resume(DynamicIndex),
+
+    Cancel {
+        index: DynamicIndex,
+        discard: bool
+    }
+}
+
+#[relm4::factory(pub)]
+impl FactoryComponent for TaskProgressFactory {
+    /// Task info and whether it can be paused and cancelled
+    type Init = (CardInfo, bool);
+    type Input = TaskProgressFactoryInput;
+    type Output = TaskProgressFactoryOutput;
+    type CommandOutput = ();
+    type ParentWidget = gtk::Box;
+
+    view! {
+        root = gtk::Box {
+            set_orientation: gtk::Orientation::Horizontal,
+            set_spacing: 16,
+
+            set_margin_bottom: 16,
+
+            self.card.widget(),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_valign: gtk::Align::Center,
+                set_hexpand: true,
+                set_spacing: 8,
+
+                gtk::Label {
+                    set_halign: gtk::Align::Start,
+
+                    add_css_class: "heading",
+
+                    set_label: self.info.get_title()
+                },
+
+                gtk::CenterBox {
+                    #[wrap(Some)]
+                    set_start_widget = &gtk::Label {
+                        #[watch]
+                        set_label: &self.status
+                    },
+
+                    #[wrap(Some)]
+                    set_end_widget = &gtk::Label {
+                        set_margin_start: 16,
+
+                        #[watch]
+                        set_visible: !self.pulse,
+
+                        #[watch]
+                        set_label: &self.progress
+                    }
+                },
+
+                self.progress_bar.clone(),
+
+                gtk::Label {
+                    set_halign: gtk::Align::Start,
+
+                    add_css_class: "dim-label",
+
+                    #[watch]
+                    set_visible: !self.speed.is_empty() || !self.eta.is_empty(),
+
+                    #[watch]
+                    set_label: &[self.speed.as_str(), self.eta.as_str()]
+                        .into_iter()
+                        .filter(|text| !text.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(" · ")
+                },
+
+                gtk::Box {
+                    set_halign: gtk::Align::End,
+                    set_spacing: 8,
+
+                    #[watch]
+                    set_visible: self.controllable,
+
+                    gtk::ToggleButton {
+                        add_css_class: "circular",
+
+                        #[watch]
+                        set_active: self.paused,
+
+                        #[watch]
+                        set_icon_name: if self.paused {
+                            "media-playback-start-symbolic"
+                        } else {
+                            "media-playback-pause-symbolic"
+                        },
+
+                        #[watch]
+                        set_tooltip_text: Some(&if self.paused {
+                            tr!("tasks-resume")
+                        } else {
+                            tr!("tasks-pause")
+                        }),
+
+                        connect_toggled[sender, index = index.clone()] => move |button| {
+                            if button.is_active() {
+                                sender.output(TaskProgressFactoryOutput::Pause(index.clone())).unwrap();
+                            } else {
+                                sender.output(TaskProgressFactoryOutput::Resume(index.clone())).unwrap();
+                            }
+                        }
+                    },
+
+                    gtk::Button {
+                        set_icon_name: "process-stop-symbolic",
+                        set_tooltip_text: Some(&tr!("tasks-cancel")),
+
+                        add_css_class: "circular",
+
+                        connect_clicked[sender, index = index.clone()] => move |_| {
+                            sender.output(TaskProgressFactoryOutput::Cancel {
+                                index: index.clone(),
+                                discard: false
+                            }).unwrap();
+                        }
+                    },
+
+                    gtk::Button {
+                        set_icon_name: "user-trash-symbolic",
+                        set_tooltip_text: Some(&tr!("tasks-discard")),
+
+                        add_css_class: "circular",
+                        add_css_class: "destructive-action",
+
+                        connect_clicked[sender, index = index.clone()] => move |_| {
+                            sender.output(TaskProgressFactoryOutput::Cancel {
+                                index: index.clone(),
+                                discard: true
+                            }).unwrap();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn init_model((info, controllable): Self::Init, _index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+        let card = CardComponent::builder()
+            .launch(info.clone())
+            .detach();
+
+        card.emit(CardComponentInput::SetWidth(80));
+        card.emit(CardComponentInput::SetHeight(112));
+        card.emit(CardComponentInput::SetClickable(false));
+        card.emit(CardComponentInput::SetDisplayTitle(false));
+
+        let progress_bar = gtk::ProgressBar::new();
+
+        progress_bar.set_pulse_step(0.25 / UPDATER_TIMEOUT.as_millis() as f64); // 0.0125
+
+        Self {
+            info,
+            card,
+            progress_bar,
+
+            status: tr!("tasks-pending"),
+            progress: String::new(),
+            pulse: true,
+
+            speed: String::new(),
+            eta: String::new(),
+
+            controllable,
+            paused: false
+        }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: FactorySender<Self>) {
+        match msg {
+            TaskProgressFactoryInput::Update { status, progress, pulse, paused, speed, eta } => {
+                if pulse {
+                    self.progress_bar.pulse();
+                }
+
+                else {
+                    self.progress = format!("{:.2}%", progress * 100.0);
+                    self.progress_bar.set_fraction(progress);
+                }
+
+                self.status = status;
+                self.pulse = pulse;
+                self.paused = paused;
+                self.speed = speed;
+                self.eta = eta;
+            }
+        }
+    }
+}
@@ -2,8 +2,10 @@ use relm4::prelude::*;
 use gtk::prelude::*;
 
 use crate::tr;
+use crate::config;
 
 use crate::games::metadata::LauncherMetadata;
+use crate::games::integrations::versions;
 use crate::games::integrations::standards::game::{
     Status,
     StatusSeverity
@@ -24,7 +26,13 @@ pub struct GameDetailsComponent {
 
     pub installed: bool,
     pub running: bool,
-    pub status: Option<Status>
+    pub status: Option<Status>,
+
+    /// Game integration is pinned to a previous version
+    pub integration_pinned: bool,
+
+    /// There's a saved integration version older than the used one
+    pub integration_revertable: bool
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -41,7 +49,13 @@ pub enum GameDetailsComponentInput {
     EmitVerifyGame,
     EmitLaunchGame,
     EmitKillGame,
-    EmitOpenAddonsManager
+    EmitOpenAddonsManager,
+    EmitOpenGamePreferences,
+    EmitShowLaunchCommand,
+    EmitOpenLastLog,
+
+    RevertIntegration,
+    UseLatestIntegration
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -54,6 +68,9 @@ pub enum GameDetailsComponentOutput {
     LaunchGame(CardInfo),
     KillGame(CardInfo),
     OpenAddonsManager(CardInfo),
+    OpenGamePreferences(CardInfo),
+    ShowLaunchCommand(CardInfo),
+    OpenLastLog(CardInfo),
 
     ShowToast {
         title: String,
@@ -113,8 +130,6 @@ impl SimpleAsyncComponent for GameDetailsComponent {
 
                     gtk::Label {
                         set_halign: gtk::Align::Start,
-                        
-                        // TODO: translate "hours" / "minutes" / "seconds" / "Never"
 
                         #[watch]
                         set_label: &tr!("details-played", {
@@ -125,14 +140,39 @@ impl SimpleAsyncComponent for GameDetailsComponent {
                     gtk::Label {
                         set_halign: gtk::Align::Start,
 
-                        // TODO: translate "Today" / "Yesterday" / "Never"
-
                         #[watch]
                         set_label: &tr!("details-last-played", {
                             "last-played" = model.metadata.get_last_played_text()
                         })
                     },
 
+                    gtk::Label {
+                        set_halign: gtk::Align::Start,
+
+                        set_margin_top: 8,
+
+                        #[watch]
+                        set_visible: !model.metadata.launches.sessions.is_empty(),
+
+                        #[watch]
+                        set_label: &tr!("details-sessions", {
+                            "sessions" = model.metadata.launches.sessions.len(),
+                            "per-week" = format!("{:.1}", model.metadata.get_sessions_per_week())
+                        })
+                    },
+
+                    gtk::Label {
+                        set_halign: gtk::Align::Start,
+
+                        #[watch]
+                        set_visible: !model.metadata.launches.sessions.is_empty(),
+
+                        #[watch]
+                        set_label: &tr!("details-average-session", {
+                            "average" = model.metadata.get_average_session_text()
+                        })
+                    },
+
                     gtk::Box {
                         set_valign: gtk::Align::Center,
 
@@ -221,6 +261,69 @@ impl SimpleAsyncComponent for GameDetailsComponent {
 
                             connect_clicked => GameDetailsComponentInput::EmitOpenAddonsManager
                         },
+
+                        gtk::Button {
+                            adw::ButtonContent {
+                                set_icon_name: "emblem-system-symbolic",
+                                set_label: &tr!("details-game-settings")
+                            },
+
+                            add_css_class: "pill",
+
+                            #[watch]
+                            set_visible: !model.running,
+
+                            connect_clicked => GameDetailsComponentInput::EmitOpenGamePreferences
+                        },
+
+                        gtk::Button {
+                            set_icon_name: "utilities-terminal-symbolic",
+                            set_tooltip: &tr!("details-show-launch-command"),
+
+                            add_css_class: "circular",
+
+                            #[watch]
+                            set_visible: !model.running,
+
+                            connect_clicked => GameDetailsComponentInput::EmitShowLaunchCommand
+                        },
+
+                        gtk::Button {
+                            set_icon_name: "text-x-generic-symbolic",
+                            set_tooltip: &tr!("details-open-last-log"),
+
+                            add_css_class: "circular",
+
+                            connect_clicked => GameDetailsComponentInput::EmitOpenLastLog
+                        },
+
+                        gtk::Button {
+                            adw::ButtonContent {
+                                set_icon_name: "edit-undo-symbolic",
+                                set_label: &tr!("details-revert-integration")
+                            },
+
+                            add_css_class: "pill",
+
+                            #[watch]
+                            set_visible: !model.running && model.integration_revertable,
+
+                            connect_clicked => GameDetailsComponentInput::RevertIntegration
+                        },
+
+                        gtk::Button {
+                            adw::ButtonContent {
+                                set_icon_name: "edit-redo-symbolic",
+                                set_label: &tr!("details-use-latest-integration")
+                            },
+
+                            add_css_class: "pill",
+
+                            #[watch]
+                            set_visible: !model.running && model.integration_pinned,
+
+                            connect_clicked => GameDetailsComponentInput::UseLatestIntegration
+                        }
                     }
                 },
 
@@ -256,6 +359,11 @@ impl SimpleAsyncComponent for GameDetailsComponent {
     }
 
     async fn init(init: Self::Init, root: Self::Root, sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+        let integration_pinned = config::get().games.integrations.pinned
+            .contains_key(init.get_name());
+
+        let integration_revertable = versions::can_revert(init.get_name());
+
         let model = Self {
             game_card: CardComponent::builder()
                 .launch(init.clone())
@@ -266,7 +374,10 @@ impl SimpleAsyncComponent for GameDetailsComponent {
 
             installed: false,
             running: false,
-            status: None
+            status: None,
+
+            integration_pinned,
+            integration_revertable
         };
 
         model.game_card.emit(CardComponentInput::SetClickable(false));
@@ -282,6 +393,11 @@ impl SimpleAsyncComponent for GameDetailsComponent {
             GameDetailsComponentInput::SetInfo(info) => {
                 self.info = info.clone();
 
+                self.integration_pinned = config::get().games.integrations.pinned
+                    .contains_key(info.get_name());
+
+                self.integration_revertable = versions::can_revert(info.get_name());
+
                 self.game_card.emit(CardComponentInput::SetInfo(info));
             }
 
@@ -323,6 +439,66 @@ impl SimpleAsyncComponent for GameDetailsComponent {
             GameDetailsComponentInput::EmitOpenAddonsManager => {
                 sender.output(GameDetailsComponentOutput::OpenAddonsManager(self.info.clone())).unwrap();
             }
+
+            GameDetailsComponentInput::EmitOpenGamePreferences => {
+                sender.output(GameDetailsComponentOutput::OpenGamePreferences(self.info.clone())).unwrap();
+            }
+
+            GameDetailsComponentInput::EmitShowLaunchCommand => {
+                sender.output(GameDetailsComponentOutput::ShowLaunchCommand(self.info.clone())).unwrap();
+            }
+
+            GameDetailsComponentInput::EmitOpenLastLog => {
+                sender.output(GameDetailsComponentOutput::OpenLastLog(self.info.clone())).unwrap();
+            }
+
+            GameDetailsComponentInput::RevertIntegration => {
+                match versions::revert(self.info.get_name()) {
+                    Ok(version) => {
+                        self.integration_pinned = true;
+                        self.integration_revertable = versions::can_revert(self.info.get_name());
+
+                        sender.output(GameDetailsComponentOutput::ShowToast {
+                            title: tr!("details-integration-reverted", {
+                                "version" = version
+                            }),
+                            message: Some(tr!("details-restart-required"))
+                        }).unwrap();
+                    }
+
+                    Err(err) => {
+                        tracing::error!("Failed to revert integration: {err}");
+
+                        sender.output(GameDetailsComponentOutput::ShowToast {
+                            title: tr!("details-integration-revert-failed"),
+                            message: Some(err.to_string())
+                        }).unwrap();
+                    }
+                }
+            }
+
+            GameDetailsComponentInput::UseLatestIntegration => {
+                match versions::unpin(self.info.get_name()) {
+                    Ok(()) => {
+                        self.integration_pinned = false;
+                        self.integration_revertable = versions::can_revert(self.info.get_name());
+
+                        sender.output(GameDetailsComponentOutput::ShowToast {
+                            title: tr!("details-integration-unpinned"),
+                            message: Some(tr!("details-restart-required"))
+                        }).unwrap();
+                    }
+
+                    Err(err) => {
+                        tracing::error!("Failed to unpin integration: {err}");
+
+                        sender.output(GameDetailsComponentOutput::ShowToast {
+                            title: tr!("details-integration-unpin-failed"),
+                            message: Some(err.to_string())
+                        }).unwrap();
+                    }
+                }
+            }
         }
     }
 }
@@ -23,6 +23,10 @@ use super::{
 pub struct ApplyDxvkQueuedTask {
     pub card_info: CardInfo,
     pub dxvk_version: Dxvk,
+
+    /// Wine version used by the prefix
+    pub wine_version: Wine,
+
     pub prefix_path: PathBuf
 }
 
@@ -32,8 +36,17 @@ impl QueuedTask for ApplyDxvkQueuedTask {
         self.card_info.clone()
     }
 
+    #[inline]
+    fn get_locks(&self) -> Vec<PathBuf> {
+        vec![
+            self.prefix_path.clone(),
+            self.wine_version.get_folder(),
+            self.dxvk_version.get_folder()
+        ]
+    }
+
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
-        let Some(wine) = Wine::from_config()?.to_wincompatlib() else {
+        let Some(wine) = self.wine_version.to_wincompatlib() else {
             anyhow::bail!("Failed to resolve wincompatlib wine descriptor");
         };
 
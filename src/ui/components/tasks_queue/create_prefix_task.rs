@@ -105,7 +105,13 @@ impl UpdaterExt for Updater {
 #[derive(Debug)]
 pub struct CreatePrefixQueuedTask {
     pub path: PathBuf,
-    pub install_corefonts: bool
+    pub install_corefonts: bool,
+
+    /// Wine version used to create the prefix
+    pub wine_version: Wine,
+
+    /// DXVK version applied to the prefix
+    pub dxvk_version: Dxvk
 }
 
 impl QueuedTask for CreatePrefixQueuedTask {
@@ -117,10 +123,19 @@ impl QueuedTask for CreatePrefixQueuedTask {
         }
     }
 
+    #[inline]
+    fn get_locks(&self) -> Vec<PathBuf> {
+        vec![
+            self.path.clone(),
+            self.wine_version.get_folder(),
+            self.dxvk_version.get_folder()
+        ]
+    }
+
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
         let (sender, receiver) = flume::unbounded();
 
-        let Some(wine) = Wine::from_config()?.to_wincompatlib() else {
+        let Some(wine) = self.wine_version.to_wincompatlib() else {
             anyhow::bail!("Failed to resolve wincompatlib wine descriptor");
         };
 
@@ -157,9 +172,7 @@ impl QueuedTask for CreatePrefixQueuedTask {
 
                     sender.send((Status::InstallingDxvk, 0, 1))?;
 
-                    let dxvk = Dxvk::from_config()?;
-
-                    wine.install_dxvk(dxvk.get_folder(), InstallParams {
+                    wine.install_dxvk(self.dxvk_version.get_folder(), InstallParams {
                         repair_dlls: false,
                         ..InstallParams::default()
                     })?;
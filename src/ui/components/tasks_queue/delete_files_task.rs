@@ -31,6 +31,11 @@ impl QueuedTask for DeleteFilesQueuedTask {
         }
     }
 
+    #[inline]
+    fn get_locks(&self) -> Vec<PathBuf> {
+        self.paths.clone()
+    }
+
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>> {
         let paths = self.paths.clone();
 
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Instant, Duration};
 use std::sync::atomic::{
     AtomicU64,
     Ordering
@@ -14,12 +15,22 @@ use anime_game_core::updater::{
 use anime_game_core::archive;
 use anime_game_core::filesystem::transition::Transition;
 
-use anime_game_core::network::downloader::{
-    DownloaderExt,
-    basic::Downloader
+use crate::ui::components::game_card::CardInfo;
+
+use crate::network::downloader::Downloader;
+
+use crate::network::retry::{
+    self,
+    RetryPolicy,
+    DownloadFailed,
+    FilesDownloadFailed
 };
 
-use crate::ui::components::game_card::CardInfo;
+use crate::network::bandwidth::{
+    self,
+    DownloadLimiter,
+    DOWNLOAD_WINDOW_TIMEOUT
+};
 
 use crate::games;
 use crate::games::integrations::standards::diff::DiffInfo;
@@ -29,9 +40,75 @@ use crate::config;
 use super::{
     QueuedTask,
     ResolvedTask,
-    TaskStatus
+    TaskStatus,
+    TaskControl,
+    TaskKind,
+    SavedTask,
+    saved_tasks
 };
 
+/// Timeout between unpacking progress checks
+const UNPACKING_WATCH_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// Watch archive extraction until it's finished
+/// 
+/// Extractor can't be paused or stopped, so it's always waited for,
+/// even if the task was cancelled or unpacking has stalled. Otherwise
+/// it would keep writing to the transition folder while it's discarded
+/// or used by another task. Pausing takes effect after unpacking.
+/// 
+/// `callback` is called with current and total unpacking progress
+fn unpack<T>(updater: &mut T, control: &TaskControl, retry_policy: &RetryPolicy, mut callback: impl FnMut(u64, u64) -> anyhow::Result<()>) -> anyhow::Result<()>
+where
+    T: UpdaterExt<Status = bool>,
+    T::Error: std::fmt::Display
+{
+    let mut last_current = updater.current();
+    let mut last_progress = Instant::now();
+    let mut stalled = false;
+    let mut error = None;
+
+    loop {
+        match updater.status() {
+            Ok(true) => break,
+            Ok(false) => (),
+
+            Err(err) => anyhow::bail!("Failed to extract files from the archive: {err}")
+        }
+
+        let current = updater.current();
+
+        if current != last_current {
+            last_current = current;
+            last_progress = Instant::now();
+        }
+
+        else if !stalled && last_progress.elapsed() > retry_policy.stall_timeout {
+            tracing::warn!("Unpacking stalled for {} seconds, waiting for the extractor to finish", retry_policy.stall_timeout.as_secs());
+
+            stalled = true;
+        }
+
+        // Progress is not reported after cancelling or
+        // callback's error, but the extractor is still waited for
+        if error.is_none() && !control.is_cancelled() {
+            error = callback(current, updater.total()).err();
+        }
+
+        std::thread::sleep(UNPACKING_WATCH_TIMEOUT);
+    }
+
+    if stalled {
+        tracing::info!("Stalled unpacking was finished");
+    }
+
+    if let Some(err) = error {
+        return Err(err);
+    }
+
+    control.check_cancelled()
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum DiffOrigin {
     Game,
@@ -45,6 +122,7 @@ pub enum DiffOrigin {
 pub enum Status {
     PreparingTransition,
     RunPreTransitionCode,
+    WaitingDownloadWindow,
     Downloading,
     Unpacking,
     RunTransitionCode,
@@ -77,8 +155,13 @@ impl QueuedTask for DownloadDiffQueuedTask {
 
         let download_path = self.download_path.clone();
 
+        let saved_task = self.get_saved();
+
+        let control = TaskControl::new();
+
         Ok(Box::new(DownloadDiffResolvedTask {
             card_info: self.card_info.clone(),
+            control: control.clone(),
 
             updater: BasicUpdater::spawn(move |sender| {
                 Box::new(move || -> Result<(), anyhow::Error> {
@@ -96,210 +179,274 @@ impl QueuedTask for DownloadDiffQueuedTask {
                         config.general.transitions.path
                     )?;
 
-                    sender.send((Status::PreparingTransition, 1, 1))?;
-
-                    // Run pre-transition code
-
-                    match &diff_origin {
-                        DiffOrigin::Game if game.driver.has_game_diff_pre_transition()? => {
-                            sender.send((Status::RunPreTransitionCode, 0, 1))?;
-
-                            game.driver.run_game_diff_pre_transition(
-                                &transition.original_path().to_string_lossy(),
-                                &game_edition
-                            )?;
-
-                            sender.send((Status::RunPreTransitionCode, 1, 1))?;
-                        }
-
-                        DiffOrigin::Addon { group_name, addon_name } if game.driver.has_addons_diff_pre_transition()? => {
-                            sender.send((Status::RunPreTransitionCode, 0, 1))?;
-
-                            game.driver.run_addons_diff_pre_transition(
-                                group_name,
-                                addon_name,
-                                &transition.original_path().to_string_lossy(),
-                                &game_edition
-                            )?;
+                    if let Some(saved_task) = &saved_task {
+                        saved_task.own_transition(transition.transition_path())?;
+                    }
 
-                            sender.send((Status::RunPreTransitionCode, 1, 1))?;
-                        }
+                    sender.send((Status::PreparingTransition, 1, 1))?;
 
-                        _ => ()
-                    }
+                    // Everything until the transition is finished can be cancelled.
+                    // Cancelled transition keeps downloaded files so the same task
+                    // can continue from where it stopped, or they're removed if discarded
+                    control.run_discardable(transition.transition_path(), || -> anyhow::Result<()> {
+                        control.checkpoint()?;
 
-                    // Download and extract diff files
+                        // Run pre-transition code
 
-                    match diff_info {
-                        DiffInfo::Archive { size: _, uri } => {
-                            // Download archive
+                        match &diff_origin {
+                            DiffOrigin::Game if game.driver.has_game_diff_pre_transition()? => {
+                                sender.send((Status::RunPreTransitionCode, 0, 1))?;
 
-                            let downloader = Downloader::new(uri);
+                                game.driver.run_game_diff_pre_transition(
+                                    &transition.original_path().to_string_lossy(),
+                                    &game_edition
+                                )?;
 
-                            let archive = transition.transition_path()
-                                .join(downloader.file_name());
+                                sender.send((Status::RunPreTransitionCode, 1, 1))?;
+                            }
 
-                            let mut updater = downloader.download(&archive)?;
+                            DiffOrigin::Addon { group_name, addon_name } if game.driver.has_addons_diff_pre_transition()? => {
+                                sender.send((Status::RunPreTransitionCode, 0, 1))?;
 
-                            while !updater.is_finished() {
-                                // TODO: add timeouts
+                                game.driver.run_addons_diff_pre_transition(
+                                    group_name,
+                                    addon_name,
+                                    &transition.original_path().to_string_lossy(),
+                                    &game_edition
+                                )?;
 
-                                sender.send((
-                                    Status::Downloading,
-                                    updater.current(),
-                                    updater.total()
-                                ))?;
+                                sender.send((Status::RunPreTransitionCode, 1, 1))?;
                             }
 
-                            // Extract archive
+                            _ => ()
+                        }
 
-                            let Some(mut updater) = archive::extract(&archive, transition.transition_path()) else {
-                                anyhow::bail!("Failed to extract files from the archive: {:?}", archive);
-                            };
+                        control.checkpoint()?;
 
-                            while let Ok(false) = updater.status() {
-                                // TODO: add timeouts
+                        // Wait until large downloads are allowed
 
-                                sender.send((
-                                    Status::Unpacking,
-                                    updater.current(),
-                                    updater.total()
-                                ))?;
-                            }
+                        while !bandwidth::is_download_allowed(diff_info.size()) {
+                            sender.send((Status::WaitingDownloadWindow, 0, 1))?;
 
-                            // Delete archive
+                            control.checkpoint()?;
 
-                            std::fs::remove_file(archive)?;
+                            std::thread::sleep(DOWNLOAD_WINDOW_TIMEOUT);
                         }
 
-                        DiffInfo::Segments { size, segments } => {
-                            // Download segments
+                        // All the task's downloads share the same bandwidth budget
+                        let limiter = DownloadLimiter::new();
 
-                            let mut archives = vec![];
-                            let mut downloaded = 0;
+                        let retry_policy = RetryPolicy::from_config();
 
-                            for uri in segments {
-                                let downloader = Downloader::new(uri);
+                        // Download and extract diff files
 
-                                let archive = transition.transition_path()
-                                    .join(downloader.file_name());
+                        match diff_info {
+                            DiffInfo::Archive { size: _, uri } => {
+                                // Download archive
 
-                                let mut updater = downloader.download(&archive)?;
+                                let downloader = Downloader::new(uri)
+                                    .with_limiter(limiter)
+                                    .with_pause_flag(control.pause_flag());
 
-                                archives.push(archive);
+                                let archive = transition.transition_path()
+                                    .join(downloader.file_name());
 
-                                while !updater.is_finished() {
-                                    // TODO: add timeouts
+                                retry::download(&downloader, &archive, &retry_policy, |current, total| {
+                                    control.checkpoint()?;
 
-                                    sender.send((
-                                        Status::Downloading,
-                                        downloaded + updater.current(),
-                                        size
-                                        // updater.total()
-                                    ))?;
-                                }
+                                    sender.send((Status::Downloading, current, total))?;
 
-                                downloaded += updater.total();
-                            }
+                                    Ok(())
+                                })?;
 
-                            // Extract segments
+                                // Extract archive
 
-                            let Some(mut updater) = archive::extract(&archives[0], transition.transition_path()) else {
-                                anyhow::bail!("Failed to extract files from segmented archive: {:?}", archives[0]);
-                            };
+                                let Some(mut updater) = archive::extract(&archive, transition.transition_path()) else {
+                                    anyhow::bail!("Failed to extract files from the archive: {:?}", archive);
+                                };
 
-                            while let Ok(false) = updater.status() {
-                                // TODO: add timeouts
+                                unpack(&mut updater, &control, &retry_policy, |current, total| {
+                                    sender.send((Status::Unpacking, current, total))?;
 
-                                sender.send((
-                                    Status::Unpacking,
-                                    updater.current(),
-                                    updater.total()
-                                ))?;
-                            }
+                                    Ok(())
+                                })?;
 
-                            // Delete segments
+                                // Delete archive
 
-                            for archive in archives {
                                 std::fs::remove_file(archive)?;
                             }
-                        }
 
-                        DiffInfo::Files { size, files } => {
-                            let pool = rusty_pool::Builder::new()
-                                .name(String::from("download_files"))
-                                .core_size(config.general.threads.number as usize)
-                                .build();
+                            DiffInfo::Segments { size, segments } => {
+                                // Download segments
 
-                            let queue_size = config.general.threads.max_queue_size as usize;
+                                let mut archives = vec![];
+                                let mut downloaded = 0;
 
-                            let mut tasks = Vec::with_capacity(queue_size);
+                                for uri in segments {
+                                    control.checkpoint()?;
 
-                            let downloaded = Arc::new(AtomicU64::new(0));
+                                    let downloader = Downloader::new(uri)
+                                        .with_limiter(limiter.clone())
+                                        .with_pause_flag(control.pause_flag());
 
-                            for chunk in files.chunks(queue_size) {
-                                for file in chunk {
-                                    let download_path = transition.transition_path().join(&file.path);
-                                    let download_uri = file.uri.clone();
-                                    let file_size = file.size;
+                                    let archive = transition.transition_path()
+                                        .join(downloader.file_name());
 
-                                    let downloaded = downloaded.clone();
-                                    let sender = sender.clone();
+                                    let mut segment_size = 0;
 
-                                    tasks.push(pool.evaluate(move || -> anyhow::Result<()> {
-                                        Downloader::new(download_uri)
-                                            .continue_downloading(false)
-                                            .download(download_path)?
-                                            .wait()?;
+                                    retry::download(&downloader, &archive, &retry_policy, |current, total| {
+                                        control.checkpoint()?;
 
-                                        let prev = downloaded.fetch_add(file_size, Ordering::Relaxed);
+                                        segment_size = total;
 
                                         sender.send((
                                             Status::Downloading,
-                                            prev + file_size,
+                                            downloaded + current,
                                             size
                                         ))?;
 
                                         Ok(())
-                                    }));
+                                    })?;
+
+                                    archives.push(archive);
+
+                                    downloaded += segment_size;
+                                }
+
+                                // Extract segments
+
+                                let Some(mut updater) = archive::extract(&archives[0], transition.transition_path()) else {
+                                    anyhow::bail!("Failed to extract files from segmented archive: {:?}", archives[0]);
+                                };
+
+                                unpack(&mut updater, &control, &retry_policy, |current, total| {
+                                    sender.send((Status::Unpacking, current, total))?;
+
+                                    Ok(())
+                                })?;
+
+                                // Delete segments
+
+                                for archive in archives {
+                                    std::fs::remove_file(archive)?;
                                 }
+                            }
 
-                                for task in tasks.drain(..) {
-                                    task.await_complete()?;
+                            DiffInfo::Files { size, files } => {
+                                let pool = rusty_pool::Builder::new()
+                                    .name(String::from("download_files"))
+                                    .core_size(config.general.threads.number as usize)
+                                    .build();
+
+                                let queue_size = config.general.threads.max_queue_size as usize;
+
+                                let mut tasks = Vec::with_capacity(queue_size);
+                                let mut failed_files = Vec::new();
+
+                                let downloaded = Arc::new(AtomicU64::new(0));
+
+                                for chunk in files.chunks(queue_size) {
+                                    control.checkpoint()?;
+
+                                    for file in chunk {
+                                        let download_path = transition.transition_path().join(&file.path);
+                                        let download_uri = file.uri.clone();
+                                        let file_size = file.size;
+
+                                        let downloaded = downloaded.clone();
+                                        let sender = sender.clone();
+                                        let control = control.clone();
+                                        let limiter = limiter.clone();
+
+                                        tasks.push(pool.evaluate(move || -> anyhow::Result<Option<DownloadFailed>> {
+                                            control.checkpoint()?;
+
+                                            // Files downloaded by the failed or cancelled task are kept
+                                            // in the transition folder, so only the rest is downloaded again
+                                            let is_downloaded = download_path.metadata()
+                                                .map(|metadata| metadata.len() == file_size)
+                                                .unwrap_or(false);
+
+                                            if !is_downloaded {
+                                                let downloader = Downloader::new(download_uri)
+                                                    .continue_downloading(false)
+                                                    .with_limiter(limiter)
+                                                    .with_pause_flag(control.pause_flag());
+
+                                                let result = retry::download(&downloader, download_path, &retry_policy, |_, _| {
+                                                    control.checkpoint()
+                                                });
+
+                                                // Other files are still downloaded if this one has failed
+                                                if let Err(err) = result {
+                                                    return match err.downcast::<DownloadFailed>() {
+                                                        Ok(failed) => Ok(Some(failed)),
+                                                        Err(err) => Err(err)
+                                                    };
+                                                }
+                                            }
+
+                                            let prev = downloaded.fetch_add(file_size, Ordering::Relaxed);
+
+                                            sender.send((
+                                                Status::Downloading,
+                                                prev + file_size,
+                                                size
+                                            ))?;
+
+                                            Ok(None)
+                                        }));
+                                    }
+
+                                    for task in tasks.drain(..) {
+                                        if let Some(failed) = task.await_complete()? {
+                                            failed_files.push(failed);
+                                        }
+                                    }
+                                }
+
+                                if !failed_files.is_empty() {
+                                    return Err(FilesDownloadFailed(failed_files).into());
                                 }
                             }
                         }
-                    }
 
-                    // Run transition code
+                        control.checkpoint()?;
 
-                    match &diff_origin {
-                        DiffOrigin::Game if game.driver.has_game_diff_transition()? => {
-                            sender.send((Status::RunTransitionCode, 0, 1))?;
+                        // Run transition code
 
-                            game.driver.run_game_diff_transition(
-                                &transition.transition_path().to_string_lossy(),
-                                &game_edition
-                            )?;
+                        match &diff_origin {
+                            DiffOrigin::Game if game.driver.has_game_diff_transition()? => {
+                                sender.send((Status::RunTransitionCode, 0, 1))?;
 
-                            sender.send((Status::RunTransitionCode, 1, 1))?;
-                        }
+                                game.driver.run_game_diff_transition(
+                                    &transition.transition_path().to_string_lossy(),
+                                    &game_edition
+                                )?;
 
-                        DiffOrigin::Addon { group_name, addon_name } if game.driver.has_addons_diff_transition()? => {
-                            sender.send((Status::RunTransitionCode, 0, 1))?;
+                                sender.send((Status::RunTransitionCode, 1, 1))?;
+                            }
 
-                            game.driver.run_addons_diff_transition(
-                                group_name,
-                                addon_name,
-                                &transition.transition_path().to_string_lossy(),
-                                &game_edition
-                            )?;
+                            DiffOrigin::Addon { group_name, addon_name } if game.driver.has_addons_diff_transition()? => {
+                                sender.send((Status::RunTransitionCode, 0, 1))?;
+
+                                game.driver.run_addons_diff_transition(
+                                    group_name,
+                                    addon_name,
+                                    &transition.transition_path().to_string_lossy(),
+                                    &game_edition
+                                )?;
 
-                            sender.send((Status::RunTransitionCode, 1, 1))?;
+                                sender.send((Status::RunTransitionCode, 1, 1))?;
+                            }
+
+                            _ => ()
                         }
 
-                        _ => ()
-                    }
+                        control.checkpoint()?;
+
+                        Ok(())
+                    })?;
 
                     // Finish transition
 
@@ -307,6 +454,8 @@ impl QueuedTask for DownloadDiffQueuedTask {
 
                     transition.finish()?;
 
+                    saved_tasks::release_transition(transition.transition_path())?;
+
                     sender.send((Status::FinishingTransition, 1, 1))?;
 
                     // Run post-transition code
@@ -344,12 +493,34 @@ impl QueuedTask for DownloadDiffQueuedTask {
             })
         }))
     }
+
+    #[inline]
+    fn get_kind(&self) -> TaskKind {
+        TaskKind::Download
+    }
+
+    #[inline]
+    fn get_locks(&self) -> Vec<PathBuf> {
+        vec![self.download_path.clone()]
+    }
+
+    fn get_saved(&self) -> Option<SavedTask> {
+        match &self.diff_origin {
+            DiffOrigin::Game => Some(SavedTask::DownloadGame {
+                game: self.card_info.get_name().to_string(),
+                edition: self.card_info.get_edition().to_string()
+            }),
+
+            DiffOrigin::Addon { .. } => None
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct DownloadDiffResolvedTask {
     pub updater: BasicUpdater<Status, (), anyhow::Error>,
-    pub card_info: CardInfo
+    pub card_info: CardInfo,
+    pub control: TaskControl
 }
 
 impl ResolvedTask for DownloadDiffResolvedTask {
@@ -385,6 +556,7 @@ impl ResolvedTask for DownloadDiffResolvedTask {
 
                 BasicStatus::Working(Status::PreparingTransition)   => TaskStatus::PreparingTransition,
                 BasicStatus::Working(Status::RunPreTransitionCode)  => TaskStatus::RunPreTransitionCode,
+                BasicStatus::Working(Status::WaitingDownloadWindow) => TaskStatus::WaitingDownloadWindow,
                 BasicStatus::Working(Status::Downloading)           => TaskStatus::Downloading,
                 BasicStatus::Working(Status::Unpacking)             => TaskStatus::Unpacking,
                 BasicStatus::Working(Status::RunTransitionCode)     => TaskStatus::RunTransitionCode,
@@ -397,4 +569,9 @@ impl ResolvedTask for DownloadDiffResolvedTask {
             Err(err) => anyhow::bail!(err.to_string())
         }
     }
+
+    #[inline]
+    fn get_control(&self) -> Option<&TaskControl> {
+        Some(&self.control)
+    }
 }
@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread::JoinHandle;
@@ -10,14 +11,20 @@ use relm4::prelude::*;
 use gtk::prelude::*;
 
 use crate::tr;
+use crate::config;
 
-use crate::ui::components::game_card::{
-    CardInfo,
-    CardComponent,
-    CardComponentInput
+use crate::ui::components::game_card::CardInfo;
+
+use crate::ui::components::factory::game_card_tasks::{
+    CardFactory,
+    CardFactoryOutput
 };
 
-use crate::ui::components::factory::game_card_tasks::CardFactory;
+use crate::ui::components::factory::task_progress::{
+    TaskProgressFactory,
+    TaskProgressFactoryInput,
+    TaskProgressFactoryOutput
+};
 
 use crate::utils::{
     pretty_bytes,
@@ -25,6 +32,7 @@ use crate::utils::{
 };
 
 pub mod task;
+pub mod saved_tasks;
 pub mod create_prefix_task;
 pub mod apply_dxvk_task;
 pub mod download_diff_task;
@@ -33,6 +41,11 @@ pub mod delete_files_task;
 
 pub use task::*;
 
+pub use saved_tasks::{
+    SavedTask,
+    SavedTasks
+};
+
 pub const UPDATER_TIMEOUT: Duration = Duration::from_millis(20);
 
 #[derive(Debug)]
@@ -47,27 +60,92 @@ impl Drop for TasksQueueProgressUpdater {
     }
 }
 
+#[derive(Debug)]
+pub struct RunningTask {
+    pub task: Box<dyn ResolvedTask>,
+    pub saved_task: Option<SavedTask>,
+
+    pub kind: TaskKind,
+    pub locks: Vec<PathBuf>,
+
+    pub started_at: Instant
+}
+
+impl RunningTask {
+    /// Get localized average speed and ETA of the task
+    pub fn describe_progress(&self) -> (String, String) {
+        let current = self.task.get_current();
+
+        if current == 0 {
+            return (String::new(), String::new());
+        }
+
+        // TODO: update avg-s with some timeout
+
+        let elapsed_time = (Instant::now() - self.started_at).as_secs_f64();
+        let average_speed = (current as f64 / elapsed_time).ceil() as u64;
+
+        let speed = if self.task.get_total() > 1024 * 512 {
+            tr!("tasks-avg-speed", {
+                "format" = "data",
+                "speed" = pretty_bytes(average_speed)
+            })
+        }
+
+        else {
+            tr!("tasks-avg-speed", {
+                "format" = "items",
+                "speed" = average_speed
+            })
+        };
+
+        let expected_total_time = (elapsed_time / self.task.get_progress()).ceil() as u64;
+        let remaining_time = expected_total_time.saturating_sub(elapsed_time as u64);
+
+        let eta = if remaining_time < 24 * 60 * 60 {
+            tr!("tasks-avg-eta", {
+                "eta" = pretty_seconds(remaining_time)
+            })
+        }
+
+        else {
+            String::new()
+        };
+
+        (speed, eta)
+    }
+}
+
 #[derive(Debug)]
 pub struct TasksQueueComponent {
-    pub current_task: Option<Box<dyn ResolvedTask>>,
-    pub current_task_card: AsyncController<CardComponent>,
-    pub current_task_status: String,
-    pub current_task_progress_start: Instant,
-    pub current_task_progress_pulse: bool,
+    pub running_tasks_factory: FactoryVecDeque<TaskProgressFactory>,
+    pub running_tasks: Vec<RunningTask>,
 
     pub queued_tasks_factory: FactoryVecDeque<CardFactory>,
     pub queued_tasks: VecDeque<Box<dyn QueuedTask>>,
 
-    pub progress_label: gtk::Label,
-    pub progress_bar: gtk::ProgressBar,
-
     pub updater: Option<TasksQueueProgressUpdater>
 }
 
 #[derive(Debug)]
 pub enum TasksQueueComponentInput {
     AddTask(Box<dyn QueuedTask>),
-    UpdateCurrentTask,
+
+    PauseTask(DynamicIndex),
+    ResumeTask(DynamicIndex),
+
+    /// Cancel running task. Its transition is removed
+    /// if `discard` is true, otherwise it's kept to be resumed later
+    CancelTask {
+        index: DynamicIndex,
+        discard: bool
+    },
+
+    MoveQueuedTaskBackward(DynamicIndex),
+    MoveQueuedTaskForward(DynamicIndex),
+    RemoveQueuedTask(DynamicIndex),
+
+    UpdateRunningTasks,
     StartUpdater,
     StopUpdater
 }
@@ -84,161 +162,211 @@ pub enum TasksQueueComponentOutput {
     }
 }
 
-#[relm4::component(pub, async)]
-impl SimpleAsyncComponent for TasksQueueComponent {
-    type Init = CardInfo;
-    type Input = TasksQueueComponentInput;
-    type Output = TasksQueueComponentOutput;
+impl TasksQueueComponent {
+    /// Save running and queued tasks to the tasks file
+    pub fn save_tasks(&self) {
+        let tasks = SavedTasks {
+            running: self.running_tasks.iter()
+                .filter_map(|task| task.saved_task.clone())
+                .collect(),
+
+            queued: self.queued_tasks.iter()
+                .filter_map(|task| task.get_saved())
+                .collect()
+        };
 
-    view! {
-        #[root]
-        gtk::Box {
-            set_orientation: gtk::Orientation::Vertical,
+        if let Err(err) = tasks.save() {
+            tracing::error!("Failed to save tasks queue: {err}");
+        }
+    }
 
-            set_margin_all: 24,
+    /// Check if the task is already running or queued
+    pub fn has_task(&self, task: &SavedTask) -> bool {
+        self.running_tasks.iter().any(|running_task| running_task.saved_task.as_ref() == Some(task)) ||
+            self.queued_tasks.iter().any(|queued_task| queued_task.get_saved().as_ref() == Some(task))
+    }
 
-            gtk::Box {
-                set_halign: gtk::Align::Center,
+    /// Get control token of the running task
+    pub fn get_task_control(&self, index: &DynamicIndex) -> Option<&TaskControl> {
+        self.running_tasks.get(index.current_index())
+            .and_then(|task| task.task.get_control())
+    }
 
-                #[watch]
-                set_visible: model.current_task.is_some(),
+    /// Resolve queued task and add it to the running tasks list
+    fn start_task(&mut self, task: Box<dyn QueuedTask>, sender: &AsyncComponentSender<Self>) {
+        let saved_task = task.get_saved();
+        let kind = task.get_kind();
+        let locks = task.get_locks();
 
-                model.current_task_card.widget(),
-            },
+        match task.resolve() {
+            Ok(task) => {
+                let controllable = task.get_control().is_some();
 
-            adw::Clamp {
-                set_maximum_size: 200,
+                self.running_tasks_factory.guard().push_back((task.get_info(), controllable));
 
-                #[watch]
-                set_visible: model.current_task.is_none(),
+                self.running_tasks.push(RunningTask {
+                    task,
+                    saved_task,
+                    kind,
+                    locks,
+                    started_at: Instant::now()
+                });
+            }
 
-                gtk::Picture {
-                    set_resource: Some(&format!("{}/icons/hicolor/scalable/apps/{}.png", crate::APP_RESOURCE_PREFIX, crate::APP_ID))
-                }
-            },
+            Err(err) => {
+                sender.output(TasksQueueComponentOutput::ShowToast {
+                    title: tr!("tasks-resolve-queued-failed"),
+                    message: Some(err.to_string())
+                }).unwrap();
+            }
+        }
+    }
 
-            gtk::Label {
-                set_halign: gtk::Align::Center,
+    /// Start queued tasks which don't exceed running tasks
+    /// limits and don't conflict with other tasks
+    /// 
+    /// Task is not started if it conflicts with a queued task
+    /// placed before it so dependent tasks keep their order
+    fn schedule(&mut self, sender: &AsyncComponentSender<Self>) {
+        let config = config::get();
 
-                set_margin_top: 24,
+        let mut waiting_locks = Vec::new();
+        let mut index = 0;
 
-                add_css_class: "title-4",
+        while index < self.queued_tasks.len() {
+            let task = &self.queued_tasks[index];
 
-                #[watch]
-                set_label: &match &model.current_task {
-                    Some(task) => task.get_info().get_title().to_string(),
-                    None => tr!("tasks-nothing")
-                }
-            },
+            let kind = task.get_kind();
+            let locks = task.get_locks();
 
-            gtk::CenterBox {
-                set_margin_top: 16,
+            let limit = match kind {
+                TaskKind::Download => config.general.tasks.max_downloads,
+                TaskKind::Disk     => config.general.tasks.max_disk_tasks
+            };
 
-                #[watch]
-                set_visible: model.current_task.is_some(),
+            let running = self.running_tasks.iter()
+                .filter(|task| task.kind == kind)
+                .count() as u64;
 
-                #[wrap(Some)]
-                set_start_widget = &gtk::Label {
-                    #[watch]
-                    set_label: &model.current_task_status
-                },
+            let conflicts = is_locks_conflict(&waiting_locks, &locks) ||
+                self.running_tasks.iter().any(|task| is_locks_conflict(&task.locks, &locks));
 
-                #[wrap(Some)]
-                #[local_ref]
-                set_end_widget = progress_label -> gtk::Label {
-                    set_margin_start: 16,
+            if running >= limit.max(1) || conflicts {
+                waiting_locks.extend(locks);
 
-                    #[watch]
-                    set_visible: !&model.current_task_progress_pulse
-                }
-            },
+                index += 1;
 
-            #[local_ref]
-            progress_bar -> gtk::ProgressBar {
-                set_margin_top: 16,
+                continue;
+            }
 
-                #[watch]
-                set_visible: model.current_task.is_some(),
+            if let Some(task) = self.queued_tasks.remove(index) {
+                self.queued_tasks_factory.guard().remove(index);
 
-                set_pulse_step: 0.25 / UPDATER_TIMEOUT.as_millis() as f64 // 0.0125
-            },
+                self.start_task(task, sender);
+            }
+        }
+    }
 
-            gtk::Label {
-                set_halign: gtk::Align::Start,
+    /// Remove finished task from the running tasks list
+    fn finish_task(&mut self, index: usize, sender: &AsyncComponentSender<Self>) {
+        let mut task = self.running_tasks.remove(index).task;
+
+        self.running_tasks_factory.guard().remove(index);
+
+        let is_cancelled = task.get_control()
+            .map(TaskControl::is_cancelled)
+            .unwrap_or(false);
+
+        if let Err(err) = task.get_status() {
+            // Cancelled tasks finish with an error as well
+            if is_cancelled {
+                sender.output(TasksQueueComponentOutput::ShowToast {
+                    title: tr!("tasks-cancelled", {
+                        "game-title" = task.get_info().get_title().to_string()
+                    }),
+                    message: None
+                }).unwrap();
+            }
 
-                set_margin_top: 16,
+            else {
+                sender.output(TasksQueueComponentOutput::ShowToast {
+                    title: tr!("tasks-get-status-failed", {
+                        "game-title" = task.get_info().get_title().to_string()
+                    }),
+                    message: Some(err.to_string())
+                }).unwrap();
+            }
+        }
 
-                #[watch]
-                set_visible: model.current_task.is_some(),
+        let info = task.get_info();
 
-                // TODO: update avg-s with some timeout
+        let has_other_tasks = self.running_tasks.iter().any(|task| task.task.get_info() == info) ||
+            self.queued_tasks.iter().any(|task| task.get_info() == info);
 
-                #[watch]
-                set_label: &match &model.current_task {
-                    Some(task) if task.get_current() > 0 => {
-                        let elapsed_time  = (Instant::now() - model.current_task_progress_start).as_secs_f64();
-                        let average_speed = (task.get_current() as f64 / elapsed_time).ceil() as u64;
-
-                        if task.get_total() > 1024 * 512 {
-                            tr!("tasks-avg-speed", {
-                                "format" = "data",
-                                "speed" = pretty_bytes(average_speed)
-                            })
-                        }
+        if !has_other_tasks {
+            sender.output(TasksQueueComponentOutput::TaskFinished(info)).unwrap();
+        }
+    }
+}
 
-                        else {
-                            tr!("tasks-avg-speed", {
-                                "format" = "items",
-                                "speed" = average_speed
-                            })
-                        }
-                    }
+#[relm4::component(pub, async)]
+impl SimpleAsyncComponent for TasksQueueComponent {
+    type Init = ();
+    type Input = TasksQueueComponentInput;
+    type Output = TasksQueueComponentOutput;
+
+    view! {
+        #[root]
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
 
-                    _ => String::new()
+            set_margin_all: 24,
+
+            adw::Clamp {
+                set_maximum_size: 200,
+
+                #[watch]
+                set_visible: model.running_tasks.is_empty(),
+
+                gtk::Picture {
+                    set_resource: Some(&format!("{}/icons/hicolor/scalable/apps/{}.png", crate::APP_RESOURCE_PREFIX, crate::APP_ID))
                 }
             },
 
             gtk::Label {
-                set_halign: gtk::Align::Start,
+                set_halign: gtk::Align::Center,
 
-                set_margin_top: 8,
+                set_margin_top: 24,
 
-                #[watch]
-                set_visible: model.current_task.is_some(),
+                add_css_class: "title-4",
 
                 #[watch]
-                set_label: &match &model.current_task {
-                    Some(task) if task.get_current() > 0 => {
-                        let elapsed_time = (Instant::now() - model.current_task_progress_start).as_secs_f64();
-                        let progress = task.get_progress();
-
-                        let expected_total_time = (elapsed_time / progress).ceil() as u64;
-                        let remaining_time = expected_total_time - elapsed_time as u64;
-
-                        if remaining_time < 24 * 60 * 60 {
-                            tr!("tasks-avg-eta", {
-                                "eta" = pretty_seconds(remaining_time)
-                            })
-                        }
+                set_visible: model.running_tasks.is_empty(),
 
-                        else {
-                            String::new()
-                        }
-                    }
+                set_label: &tr!("tasks-nothing")
+            },
 
-                    _ => String::new()
-                }
+            gtk::ScrolledWindow {
+                set_hscrollbar_policy: gtk::PolicyType::Never,
+                set_propagate_natural_height: true,
+
+                #[watch]
+                set_visible: !model.running_tasks.is_empty(),
+
+                model.running_tasks_factory.widget(),
             },
 
             gtk::ScrolledWindow {
-                set_margin_top: 64,
+                set_margin_top: 32,
 
                 model.queued_tasks_factory.widget(),
             }
         }
     }
 
-    async fn init(init: Self::Init, root: Self::Root, _sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+    async fn init(_init: Self::Init, root: Self::Root, sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+        let running_box = gtk::Box::new(gtk::Orientation::Vertical, 0);
+
         let flow_box = gtk::FlowBox::new();
 
         flow_box.set_valign(gtk::Align::End);
@@ -248,37 +376,31 @@ impl SimpleAsyncComponent for TasksQueueComponent {
         flow_box.set_homogeneous(true);
 
         let model = Self {
-            current_task: None,
+            running_tasks_factory: FactoryVecDeque::builder()
+                .launch(running_box)
+                .forward(sender.input_sender(), |output| match output {
+                    TaskProgressFactoryOutput::Pause(index)  => TasksQueueComponentInput::PauseTask(index),
+                    TaskProgressFactoryOutput::Resume(index) => TasksQueueComponentInput::ResumeTask(index),
 
-            current_task_card: CardComponent::builder()
-                .launch(init)
-                .detach(),
+                    TaskProgressFactoryOutput::Cancel { index, discard }
+                        => TasksQueueComponentInput::CancelTask { index, discard }
+                }),
 
-            current_task_status: String::new(),
-            current_task_progress_start: Instant::now(),
-            current_task_progress_pulse: false,
+            running_tasks: Vec::new(),
 
             queued_tasks_factory: FactoryVecDeque::builder()
                 .launch(flow_box)
-                .detach(),
+                .forward(sender.input_sender(), |output| match output {
+                    CardFactoryOutput::MoveBackward(index) => TasksQueueComponentInput::MoveQueuedTaskBackward(index),
+                    CardFactoryOutput::MoveForward(index)  => TasksQueueComponentInput::MoveQueuedTaskForward(index),
+                    CardFactoryOutput::Remove(index)       => TasksQueueComponentInput::RemoveQueuedTask(index)
+                }),
 
             queued_tasks: VecDeque::new(),
 
-            progress_label: gtk::Label::new(None),
-            progress_bar: gtk::ProgressBar::new(),
-
             updater: None
         };
 
-        model.current_task_card.emit(CardComponentInput::SetWidth(160));
-        model.current_task_card.emit(CardComponentInput::SetHeight(224));
-
-        model.current_task_card.emit(CardComponentInput::SetClickable(false));
-        model.current_task_card.emit(CardComponentInput::SetDisplayTitle(false));
-
-        let progress_label = &model.progress_label;
-        let progress_bar = &model.progress_bar;
-
         let widgets = view_output!();
 
         AsyncComponentParts { model, widgets }
@@ -287,131 +409,137 @@ impl SimpleAsyncComponent for TasksQueueComponent {
     async fn update(&mut self, msg: Self::Input, sender: AsyncComponentSender<Self>) {
         match msg {
             TasksQueueComponentInput::AddTask(task) => {
-                if self.current_task.is_none() {
-                    match task.resolve() {
-                        Ok(task) => {
-                            self.current_task_card.emit(CardComponentInput::SetInfo(task.get_info()));
+                // Restored tasks can be queued again by the loading checks
+                if let Some(saved_task) = task.get_saved() {
+                    if self.has_task(&saved_task) {
+                        tracing::debug!("Skipping already queued task: {saved_task:?}");
 
-                            self.current_task = Some(task);
-                            self.current_task_progress_start = Instant::now();
-                        }
-
-                        Err(err) => {
-                            sender.output(TasksQueueComponentOutput::ShowToast {
-                                title: tr!("tasks-resolve-queued-failed"),
-                                message: Some(err.to_string())
-                            }).unwrap();
-                        }
+                        return;
                     }
                 }
 
-                else {
-                    self.queued_tasks_factory.guard().push_back(task.get_info().to_owned());
+                self.queued_tasks_factory.guard().push_back(task.get_info());
+                self.queued_tasks.push_back(task);
 
-                    self.queued_tasks.push_back(task);
-                }
+                self.schedule(&sender);
+                self.save_tasks();
 
                 // This will try to start an updater even if one is already running
                 // Adding a check here (e.g. is_none()) may lead to a race condition
                 sender.input(TasksQueueComponentInput::StartUpdater);
             }
 
-            TasksQueueComponentInput::UpdateCurrentTask => {
-                if let Some(task) = &mut self.current_task {
-                    if task.is_finished() {
-                        if let Err(err) = task.get_status() {
-                            sender.output(TasksQueueComponentOutput::ShowToast {
-                                title: tr!("tasks-get-status-failed", {
-                                    "game-title" = task.get_info().get_title().to_string()
-                                }),
-                                message: Some(err.to_string())
-                            }).unwrap();
-                        }
+            TasksQueueComponentInput::PauseTask(index) => {
+                if let Some(control) = self.get_task_control(&index) {
+                    control.pause();
+                }
+            }
 
-                        let mut is_task_queued = false;
+            TasksQueueComponentInput::ResumeTask(index) => {
+                if let Some(control) = self.get_task_control(&index) {
+                    control.resume();
+                }
+            }
 
-                        for queued_task in &self.queued_tasks {
-                            if queued_task.get_info() == task.get_info() {
-                                is_task_queued = true;
+            TasksQueueComponentInput::CancelTask { index, discard } => {
+                if let Some(control) = self.get_task_control(&index) {
+                    if discard {
+                        control.discard();
+                    } else {
+                        control.cancel();
+                    }
+                }
+            }
 
-                                break;
-                            }
-                        }
+            TasksQueueComponentInput::MoveQueuedTaskBackward(index) => {
+                let index = index.current_index();
 
-                        if !is_task_queued {
-                            sender.output(TasksQueueComponentOutput::TaskFinished(task.get_info().to_owned())).unwrap();
-                        }
+                if index > 0 && index < self.queued_tasks.len() {
+                    self.queued_tasks.swap(index, index - 1);
+                    self.queued_tasks_factory.guard().swap(index, index - 1);
 
-                        if let Some(queued_task) = self.queued_tasks.pop_front() {
-                            self.queued_tasks_factory.guard().pop_front();
+                    self.schedule(&sender);
+                    self.save_tasks();
+                }
+            }
 
-                            match queued_task.resolve() {
-                                Ok(task) => {
-                                    self.current_task_card.emit(CardComponentInput::SetInfo(task.get_info().to_owned()));
+            TasksQueueComponentInput::MoveQueuedTaskForward(index) => {
+                let index = index.current_index();
 
-                                    self.current_task = Some(task);
-                                }
+                if index + 1 < self.queued_tasks.len() {
+                    self.queued_tasks.swap(index, index + 1);
+                    self.queued_tasks_factory.guard().swap(index, index + 1);
 
-                                Err(err) => {
-                                    sender.output(TasksQueueComponentOutput::ShowToast {
-                                        title: tr!("tasks-resolve-queued-failed"),
-                                        message: Some(err.to_string())
-                                    }).unwrap();
-                                }
-                            }
-                        }
+                    self.schedule(&sender);
+                    self.save_tasks();
+                }
+            }
 
-                        else {
-                            self.current_task = None;
+            TasksQueueComponentInput::RemoveQueuedTask(index) => {
+                let index = index.current_index();
 
-                            sender.input(TasksQueueComponentInput::StopUpdater);
-                            sender.output(TasksQueueComponentOutput::HideTasksFlap).unwrap();
-                        }
+                if index < self.queued_tasks.len() {
+                    self.queued_tasks.remove(index);
+                    self.queued_tasks_factory.guard().remove(index);
 
-                        self.current_task_status.clear();
-                    }
+                    self.schedule(&sender);
+                    self.save_tasks();
+                }
+            }
 
-                    else {
-                        if self.current_task_progress_pulse {
-                            self.progress_bar.pulse();
-                        }
+            TasksQueueComponentInput::UpdateRunningTasks => {
+                let mut finished = Vec::new();
 
-                        else {
-                            let progress = task.get_progress();
+                for (index, running_task) in self.running_tasks.iter_mut().enumerate() {
+                    if running_task.task.is_finished() {
+                        finished.push(index);
 
-                            self.progress_label.set_text(&format!("{:.2}%", progress * 100.0));
-                            self.progress_bar.set_fraction(progress);
-                        }
+                        continue;
+                    }
 
-                        if let Ok(status) = task.get_status() {
-                            let (pulse, title) = match status {
-                                TaskStatus::Pending => (true, tr!("tasks-pending")),
+                    let (mut pulse, mut status) = match running_task.task.get_status() {
+                        Ok(status) => status.describe(),
+                        Err(_) => continue
+                    };
 
-                                TaskStatus::PreparingTransition => (true, tr!("tasks-preparing-transition")),
-                                TaskStatus::FinishingTransition => (true, tr!("tasks-finishing-transition")),
+                    let mut paused = false;
 
-                                TaskStatus::Downloading => (false, tr!("tasks-downloading")),
-                                TaskStatus::Unpacking   => (false, tr!("tasks-unpacking")),
+                    if let Some(control) = running_task.task.get_control() {
+                        if control.is_cancelled() {
+                            status = tr!("tasks-cancelling");
+                        }
 
-                                TaskStatus::DeletingFiles => (true, tr!("tasks-deleting-files")),
+                        else if control.is_paused() {
+                            pulse = false;
+                            paused = true;
+                            status = tr!("tasks-paused");
+                        }
+                    }
 
-                                TaskStatus::RunPreTransitionCode  => (true, tr!("tasks-pre-transition-code")),
-                                TaskStatus::RunTransitionCode     => (true, tr!("tasks-transition-code")),
-                                TaskStatus::RunPostTransitionCode => (true, tr!("tasks-post-transition-code")),
+                    let (speed, eta) = running_task.describe_progress();
 
-                                TaskStatus::CreatingPrefix  => (true, tr!("tasks-creating-prefix")),
-                                TaskStatus::InstallingDxvk  => (true, tr!("tasks-installing-dxvk")),
-                                TaskStatus::InstallingFonts => (false, tr!("tasks-installing-fonts")),
+                    self.running_tasks_factory.send(index, TaskProgressFactoryInput::Update {
+                        status,
+                        progress: running_task.task.get_progress(),
+                        pulse,
+                        paused,
+                        speed,
+                        eta
+                    });
+                }
 
-                                TaskStatus::VerifyingFiles => (false, tr!("tasks-verifying-files")),
-                                TaskStatus::RepairingFiles => (false, tr!("tasks-repairing-files")),
+                if !finished.is_empty() {
+                    // Remove tasks from the end so indexes stay valid
+                    for index in finished.into_iter().rev() {
+                        self.finish_task(index, &sender);
+                    }
 
-                                TaskStatus::Finished => (true, tr!("tasks-finished"))
-                            };
+                    self.schedule(&sender);
+                    self.save_tasks();
 
-                            self.current_task_progress_pulse = pulse;
-                            self.current_task_status = title;
-                        }
+                    if self.running_tasks.is_empty() {
+                        sender.input(TasksQueueComponentInput::StopUpdater);
+                        sender.output(TasksQueueComponentOutput::HideTasksFlap).unwrap();
                     }
                 }
             }
@@ -424,7 +552,7 @@ impl SimpleAsyncComponent for TasksQueueComponent {
 
                     thread: std::thread::spawn(move || {
                         while running.load(Ordering::Relaxed) {
-                            sender.input(TasksQueueComponentInput::UpdateCurrentTask);
+                            sender.input(TasksQueueComponentInput::UpdateRunningTasks);
 
                             std::thread::sleep(UPDATER_TIMEOUT);
                         }
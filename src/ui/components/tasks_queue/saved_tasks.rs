@@ -0,0 +1,134 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Serialize, Deserialize};
+use serde_json::Value as Json;
+
+use crate::TASKS_FILE;
+
+/// Task description which is stored in the tasks file
+/// 
+/// Only game name and edition are saved, so tasks are resolved
+/// again against the current integration state when restored.
+/// Components and addons tasks are not saved because loading
+/// checks queue them again anyway
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SavedTask {
+    DownloadGame {
+        game: String,
+        edition: String
+    },
+
+    VerifyGame {
+        game: String,
+        edition: String
+    }
+}
+
+impl SavedTask {
+    /// Remember that the task owns the transition folder
+    /// so it's not discarded when the task is restored
+    pub fn own_transition(&self, transition_path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let owner_path = get_transition_owner_path(transition_path);
+
+        std::fs::write(owner_path, serde_json::to_string(&self)?)?;
+
+        Ok(())
+    }
+
+    /// Get task which owns the transition folder
+    pub fn get_transition_owner(transition_path: impl AsRef<Path>) -> Option<Self> {
+        let owner = std::fs::read(get_transition_owner_path(transition_path)).ok()?;
+
+        serde_json::from_slice(&owner).ok()
+    }
+}
+
+/// Get path to the file which stores the task owning the
+/// transition folder. It's stored next to the folder itself
+/// because transition contents are moved to the original path
+pub fn get_transition_owner_path(transition_path: impl AsRef<Path>) -> PathBuf {
+    let mut path = transition_path.as_ref()
+        .as_os_str()
+        .to_os_string();
+
+    path.push(".task");
+
+    PathBuf::from(path)
+}
+
+/// Forget the task which owned the transition folder
+pub fn release_transition(transition_path: impl AsRef<Path>) -> std::io::Result<()> {
+    let owner_path = get_transition_owner_path(transition_path);
+
+    if owner_path.exists() {
+        std::fs::remove_file(owner_path)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SavedTasks {
+    /// Tasks which were running when the queue was saved
+    pub running: Vec<SavedTask>,
+
+    pub queued: Vec<SavedTask>
+}
+
+impl Default for SavedTasks {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            running: Vec::new(),
+            queued: Vec::new()
+        }
+    }
+}
+
+impl From<&Json> for SavedTasks {
+    fn from(value: &Json) -> Self {
+        let default = Self::default();
+
+        // Skip tasks which can't be parsed instead of forgetting the whole queue
+        Self {
+            running: value.get("running")
+                .and_then(Json::as_array)
+                .map(|tasks| tasks.iter()
+                    .filter_map(|task| serde_json::from_value(task.clone()).ok())
+                    .collect())
+                .unwrap_or(default.running),
+
+            queued: value.get("queued")
+                .and_then(Json::as_array)
+                .map(|tasks| tasks.iter()
+                    .filter_map(|task| serde_json::from_value(task.clone()).ok())
+                    .collect())
+                .unwrap_or(default.queued)
+        }
+    }
+}
+
+impl SavedTasks {
+    pub fn load() -> anyhow::Result<Self> {
+        if !TASKS_FILE.exists() {
+            return Ok(Self::default());
+        }
+
+        let value = serde_json::from_slice::<Json>(&std::fs::read(TASKS_FILE.as_path())?)?;
+
+        Ok(Self::from(&value))
+    }
+
+    #[inline]
+    pub fn save(&self) -> anyhow::Result<()> {
+        std::fs::write(TASKS_FILE.as_path(), serde_json::to_string_pretty(&self)?)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.running.is_empty() && self.queued.is_empty()
+    }
+}
@@ -1,11 +1,21 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use crate::tr;
+
 use crate::ui::components::game_card::CardInfo;
 
+use super::saved_tasks::{self, SavedTask};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// All the possible tasks statuses in one enum
 pub enum TaskStatus {
     Pending,
     PreparingTransition,
     RunPreTransitionCode,
+    WaitingDownloadWindow,
     Downloading,
     Unpacking,
     RunTransitionCode,
@@ -20,12 +30,194 @@ pub enum TaskStatus {
     Finished
 }
 
+impl TaskStatus {
+    /// Get localized status title and whether its progress should be pulsed
+    pub fn describe(&self) -> (bool, String) {
+        match self {
+            Self::Pending => (true, tr!("tasks-pending")),
+
+            Self::PreparingTransition => (true, tr!("tasks-preparing-transition")),
+            Self::FinishingTransition => (true, tr!("tasks-finishing-transition")),
+
+            Self::WaitingDownloadWindow => (true, tr!("tasks-waiting-download-window")),
+
+            Self::Downloading => (false, tr!("tasks-downloading")),
+            Self::Unpacking   => (false, tr!("tasks-unpacking")),
+
+            Self::DeletingFiles => (true, tr!("tasks-deleting-files")),
+
+            Self::RunPreTransitionCode  => (true, tr!("tasks-pre-transition-code")),
+            Self::RunTransitionCode     => (true, tr!("tasks-transition-code")),
+            Self::RunPostTransitionCode => (true, tr!("tasks-post-transition-code")),
+
+            Self::CreatingPrefix  => (true, tr!("tasks-creating-prefix")),
+            Self::InstallingDxvk  => (true, tr!("tasks-installing-dxvk")),
+            Self::InstallingFonts => (false, tr!("tasks-installing-fonts")),
+
+            Self::VerifyingFiles => (false, tr!("tasks-verifying-files")),
+            Self::RepairingFiles => (false, tr!("tasks-repairing-files")),
+
+            Self::Finished => (true, tr!("tasks-finished"))
+        }
+    }
+}
+
+/// Resource mostly used by the task. Scheduler limits
+/// amount of tasks of each kind running at the same time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskKind {
+    Download,
+    Disk
+}
+
+/// Check if any of the locked paths is the same
+/// as or is located inside of any other one
+pub fn is_locks_conflict(a: &[PathBuf], b: &[PathBuf]) -> bool {
+    a.iter().any(|a| b.iter().any(|b| a.starts_with(b) || b.starts_with(a)))
+}
+
+/// How often paused task workers check if they were resumed
+pub const TASK_PAUSE_TIMEOUT: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Error returned by the task worker when it was cancelled
+pub struct TaskCancelled;
+
+impl std::fmt::Display for TaskCancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Task was cancelled")
+    }
+}
+
+impl std::error::Error for TaskCancelled {}
+
+#[derive(Debug, Clone, Default)]
+/// Shared token used to pause, resume and cancel task workers
+/// 
+/// Workers are expected to call `checkpoint` between
+/// their steps and files chunks
+pub struct TaskControl {
+    paused: Arc<AtomicBool>,
+    cancelled: Arc<AtomicBool>,
+    discard: Arc<AtomicBool>
+}
+
+impl TaskControl {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+
+    /// Cancel the task keeping its transition so it can be resumed later
+    #[inline]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Cancel the task and remove its transition files
+    #[inline]
+    pub fn discard(&self) {
+        self.discard.store(true, Ordering::Relaxed);
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// Get shared flag which is set while the task is paused
+    #[inline]
+    pub fn pause_flag(&self) -> Arc<AtomicBool> {
+        self.paused.clone()
+    }
+
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub fn should_discard(&self) -> bool {
+        self.discard.load(Ordering::Relaxed)
+    }
+
+    /// Return `TaskCancelled` error if the task was cancelled
+    #[inline]
+    pub fn check_cancelled(&self) -> anyhow::Result<()> {
+        if self.is_cancelled() {
+            return Err(TaskCancelled.into());
+        }
+
+        Ok(())
+    }
+
+    /// Block current thread while the task is paused
+    /// and return `TaskCancelled` error if it was cancelled
+    pub fn checkpoint(&self) -> anyhow::Result<()> {
+        while self.is_paused() && !self.is_cancelled() {
+            std::thread::sleep(TASK_PAUSE_TIMEOUT);
+        }
+
+        self.check_cancelled()
+    }
+
+    /// Run cancellable part of the task
+    /// 
+    /// If the task was cancelled with discarding then
+    /// `discard_path` is removed after the callback returned
+    pub fn run_discardable<T>(&self, discard_path: impl AsRef<Path>, callback: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+        let result = callback();
+
+        if let Err(err) = &result {
+            let discard_path = discard_path.as_ref();
+
+            if err.is::<TaskCancelled>() && self.should_discard() && discard_path.exists() {
+                std::fs::remove_dir_all(discard_path)?;
+
+                saved_tasks::release_transition(discard_path)?;
+            }
+        }
+
+        result
+    }
+}
+
 pub trait QueuedTask: Send + std::fmt::Debug {
     /// Get component info
     fn get_info(&self) -> CardInfo;
 
     /// Resolve queued task and start downloading stuff
     fn resolve(self: Box<Self>) -> anyhow::Result<Box<dyn ResolvedTask>>;
+
+    /// Get resource mostly used by the task
+    #[inline]
+    fn get_kind(&self) -> TaskKind {
+        TaskKind::Disk
+    }
+
+    /// Get paths read or written by the task
+    /// 
+    /// Tasks with conflicting locks are never run at the same time
+    /// and are started in the same order they were queued
+    fn get_locks(&self) -> Vec<PathBuf>;
+
+    /// Get task description to store in the tasks file.
+    /// Tasks which don't provide it are not restored on startup
+    #[inline]
+    fn get_saved(&self) -> Option<SavedTask> {
+        None
+    }
 }
 
 pub trait ResolvedTask: Send + std::fmt::Debug {
@@ -46,4 +238,60 @@ pub trait ResolvedTask: Send + std::fmt::Debug {
 
     /// Get task status
     fn get_status(&mut self) -> anyhow::Result<TaskStatus>;
+
+    /// Get task control token. Tasks which don't
+    /// provide it can't be paused or cancelled
+    #[inline]
+    fn get_control(&self) -> Option<&TaskControl> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locks(paths: &[&str]) -> Vec<PathBuf> {
+        paths.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn identical_locks() {
+        assert!(is_locks_conflict(&locks(&["/games/genshin"]), &locks(&["/games/genshin"])));
+        assert!(is_locks_conflict(&locks(&["/games/genshin/"]), &locks(&["/games/genshin"])));
+    }
+
+    #[test]
+    fn parent_and_child_locks() {
+        let parent = locks(&["/games/genshin"]);
+        let child = locks(&["/games/genshin/voiceovers/english"]);
+
+        assert!(is_locks_conflict(&parent, &child));
+        assert!(is_locks_conflict(&child, &parent));
+    }
+
+    #[test]
+    fn sibling_locks() {
+        assert!(!is_locks_conflict(&locks(&["/games/genshin"]), &locks(&["/games/honkai"])));
+
+        // Paths are compared by components, not as strings
+        assert!(!is_locks_conflict(&locks(&["/games/genshin"]), &locks(&["/games/genshin-backup"])));
+
+        assert!(!is_locks_conflict(
+            &locks(&["/games/genshin/voiceovers/english"]),
+            &locks(&["/games/genshin/voiceovers/japanese"])
+        ));
+    }
+
+    #[test]
+    fn multiple_locks() {
+        let a = locks(&["/games/genshin", "/components/wine"]);
+
+        assert!(is_locks_conflict(&a, &locks(&["/games/honkai", "/components/wine/lutris"])));
+        assert!(!is_locks_conflict(&a, &locks(&["/games/honkai", "/components/dxvk"])));
+
+        // Tasks without locks never conflict
+        assert!(!is_locks_conflict(&a, &[]));
+        assert!(!is_locks_conflict(&[], &a));
+    }
 }
@@ -13,13 +13,18 @@ use anime_game_core::updater::{
     Status as BasicStatus
 };
 
-use anime_game_core::network::downloader::{
-    DownloaderExt,
-    basic::Downloader
-};
-
 use crate::ui::components::game_card::CardInfo;
 
+use crate::network::downloader::Downloader;
+use crate::network::bandwidth::DownloadLimiter;
+
+use crate::network::retry::{
+    self,
+    RetryPolicy,
+    DownloadFailed,
+    FilesDownloadFailed
+};
+
 use crate::games;
 use crate::games::integrations::standards::prelude::*;
 
@@ -28,7 +33,10 @@ use crate::config;
 use super::{
     QueuedTask,
     ResolvedTask,
-    TaskStatus
+    TaskStatus,
+    TaskControl,
+    SavedTask,
+    saved_tasks
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -62,8 +70,13 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
 
         let path = self.path.clone();
 
+        let saved_task = self.get_saved();
+
+        let control = TaskControl::new();
+
         Ok(Box::new(VerifyIntegrityResolvedTask {
             card_info: self.card_info.clone(),
+            control: control.clone(),
 
             updater: BasicUpdater::spawn(move |sender| {
                 Box::new(move || -> Result<(), anyhow::Error> {
@@ -84,176 +97,201 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
                         config.general.transitions.path
                     )?;
 
+                    if let Some(saved_task) = &saved_task {
+                        saved_task.own_transition(transition.transition_path())?;
+                    }
+
                     sender.send((Status::PreparingTransition, 1, 1))?;
 
-                    // Verify files
+                    // Repaired files are downloaded directly to the game folder
+                    // so cancelled task doesn't have anything to keep or discard
+                    // except of the transition itself
+                    control.run_discardable(transition.transition_path(), || -> anyhow::Result<()> {
+                        // Verify files
 
-                    let pool = rusty_pool::Builder::new()
-                        .name(String::from("verify_files"))
-                        .core_size(config.general.threads.number as usize)
-                        .build();
+                        let pool = rusty_pool::Builder::new()
+                            .name(String::from("verify_files"))
+                            .core_size(config.general.threads.number as usize)
+                            .build();
 
-                    let queue_size = config.general.threads.max_queue_size as usize;
+                        let queue_size = config.general.threads.max_queue_size as usize;
 
-                    let total = integrity_info.len() as u64;
-                    let current = Arc::new(AtomicU64::new(0));
+                        let total = integrity_info.len() as u64;
+                        let current = Arc::new(AtomicU64::new(0));
 
-                    let mut tasks = Vec::with_capacity(queue_size);
-                    let mut broken_files = Vec::new();
+                        let mut tasks = Vec::with_capacity(queue_size);
+                        let mut broken_files = Vec::new();
 
-                    sender.send((
-                        Status::VerifyingFiles,
-                        0,
-                        total
-                    ))?;
+                        sender.send((
+                            Status::VerifyingFiles,
+                            0,
+                            total
+                        ))?;
 
-                    // Iterate through integrity files
-                    for chunk in integrity_info.chunks(queue_size) {
-                        for info in chunk.iter().cloned() {
-                            let integrity_file = path.join(&info.file.path);
-    
-                            // Stop immediately if the file doesn't exist
-                            // or its size is different from the remote file
-                            if !integrity_file.exists() || integrity_file.metadata()?.len() != info.file.size {
-                                broken_files.push(info.file);
-
-                                sender.send((
-                                    Status::VerifyingFiles,
-                                    current.fetch_add(1, Ordering::Relaxed) + 1,
-                                    total
-                                ))?;
-
-                                continue;
-                            }
+                        // Iterate through integrity files
+                        for chunk in integrity_info.chunks(queue_size) {
+                            control.checkpoint()?;
 
-                            let current = current.clone();
-                            let sender = sender.clone();
+                            for info in chunk.iter().cloned() {
+                                let integrity_file = path.join(&info.file.path);
+    
+                                // Stop immediately if the file doesn't exist
+                                // or its size is different from the remote file
+                                if !integrity_file.exists() || integrity_file.metadata()?.len() != info.file.size {
+                                    broken_files.push(info.file);
+
+                                    sender.send((
+                                        Status::VerifyingFiles,
+                                        current.fetch_add(1, Ordering::Relaxed) + 1,
+                                        total
+                                    ))?;
+
+                                    continue;
+                                }
 
-                            // Otherwise verifying the file is a heavy task so we put it to the threads pool
-                            tasks.push(pool.evaluate(move || -> anyhow::Result<Option<DiffFileDownload>> {
-                                // Read existing file
-                                let data = std::fs::read(&integrity_file)?;
+                                let current = current.clone();
+                                let sender = sender.clone();
+                                let control = control.clone();
 
-                                // Get existing file hash
-                                let hash = match info.hash {
-                                    HashType::Md5 => {
-                                        use md5::{Md5, Digest};
+                                // Otherwise verifying the file is a heavy task so we put it to the threads pool
+                                tasks.push(pool.evaluate(move || -> anyhow::Result<Option<DiffFileDownload>> {
+                                    control.checkpoint()?;
 
-                                        format!("{:x}", Md5::digest(&data))
-                                    },
+                                    // Read existing file
+                                    let data = std::fs::read(&integrity_file)?;
 
-                                    HashType::Sha1 => {
-                                        use sha1::{Sha1, Digest};
+                                    // Get existing file hash
+                                    let hash = match info.hash.hash(&data) {
+                                        Some(hash) => hash,
 
-                                        format!("{:x}", Sha1::digest(&data))
-                                    },
+                                        None if has_integrity_hash => {
+                                            game.driver.integrity_hash(info.hash.to_str(game.driver.standard), data)?
+                                        }
 
-                                    HashType::Crc32 => {
-                                        let mut hasher = crc32fast::Hasher::new();
+                                        None => unimplemented!()
+                                    };
 
-                                        hasher.update(&data);
+                                    sender.send((
+                                        Status::VerifyingFiles,
+                                        current.fetch_add(1, Ordering::Relaxed) + 1,
+                                        total
+                                    ))?;
 
-                                        hasher.finalize().to_string()
+                                    // Compare existing file hash with integrity info
+                                    if info.value != hash {
+                                        return Ok(Some(info.file));
                                     }
 
-                                    HashType::Xxhash32    => format!("{:x}", xxhash_rust::xxh32::xxh32(&data, 0)),
-                                    HashType::Xxhash64    => format!("{:x}", xxhash_rust::xxh64::xxh64(&data, 0)),
-                                    HashType::Xxhash3_64  => format!("{:x}", xxhash_rust::xxh3::xxh3_64(&data)),
-                                    HashType::Xxhash3_128 => format!("{:x}", xxhash_rust::xxh3::xxh3_128(&data)),
+                                    Ok(None)
+                                }));
+                            }
 
-                                    HashType::Custom(name) if has_integrity_hash => {
-                                        game.driver.integrity_hash(&name, data)?
-                                    }
+                            // Wait for current chunk of files to finish verifying
+                            for task in tasks.drain(..) {
+                                if let Some(file) = task.await_complete()? {
+                                    broken_files.push(file);
+                                }
+                            }
+                        }
 
-                                    _ => unimplemented!()
-                                };
+                        sender.send((
+                            Status::VerifyingFiles,
+                            total,
+                            total
+                        ))?;
 
-                                sender.send((
-                                    Status::VerifyingFiles,
-                                    current.fetch_add(1, Ordering::Relaxed) + 1,
-                                    total
-                                ))?;
+                        // Repair files
 
-                                // Compare existing file hash with integrity info
-                                if info.value != hash {
-                                    return Ok(Some(info.file));
-                                }
+                        let limiter = DownloadLimiter::new();
+                        let retry_policy = RetryPolicy::from_config();
 
-                                Ok(None)
-                            }));
-                        }
+                        let mut tasks = Vec::with_capacity(queue_size);
+                        let mut failed_files = Vec::new();
 
-                        // Wait for current chunk of files to finish verifying
-                        for task in tasks.drain(..) {
-                            if let Some(file) = task.await_complete()? {
-                                broken_files.push(file);
-                            }
-                        }
-                    }
-
-                    sender.send((
-                        Status::VerifyingFiles,
-                        total,
-                        total
-                    ))?;
+                        let total = broken_files.len() as u64;
+                        let current = Arc::new(AtomicU64::new(0));
 
-                    // Repair files
+                        sender.send((
+                            Status::RepairingFiles,
+                            0,
+                            total
+                        ))?;
 
-                    let mut tasks = Vec::with_capacity(queue_size);
+                        // Go through the broken files list
+                        for chunk in broken_files.chunks(queue_size) {
+                            control.checkpoint()?;
 
-                    let total = broken_files.len() as u64;
-                    let current = Arc::new(AtomicU64::new(0));
+                            for file in chunk.iter().cloned() {
+                                let file_path = path.join(&file.path);
 
-                    sender.send((
-                        Status::RepairingFiles,
-                        0,
-                        total
-                    ))?;
+                                let current = current.clone();
+                                let sender = sender.clone();
+                                let control = control.clone();
+                                let limiter = limiter.clone();
 
-                    // Go through the broken files list
-                    for chunk in broken_files.chunks(queue_size) {
-                        for file in chunk.iter().cloned() {
-                            let file_path = path.join(&file.path);
+                                // Create file repairing task
+                                tasks.push(pool.evaluate(move || -> anyhow::Result<Option<DownloadFailed>> {
+                                    control.checkpoint()?;
 
-                            let current = current.clone();
-                            let sender = sender.clone();
+                                    // Create parent folder if it doesn't exist
+                                    if let Some(parent) = file_path.parent() {
+                                        if !parent.exists() {
+                                            std::fs::create_dir_all(parent)?;
+                                        }
+                                    }
 
-                            // Create file repairing task
-                            tasks.push(pool.evaluate(move || -> anyhow::Result<()> {
-                                // Create parent folder if it doesn't exist
-                                if let Some(parent) = file_path.parent() {
-                                    if !parent.exists() {
-                                        std::fs::create_dir_all(parent)?;
+                                    // Download the file
+                                    let downloader = Downloader::new(file.uri)
+                                        .continue_downloading(false)
+                                        .with_limiter(limiter)
+                                        .with_pause_flag(control.pause_flag());
+
+                                    let result = retry::download(&downloader, file_path, &retry_policy, |_, _| {
+                                        control.checkpoint()
+                                    });
+
+                                    // Other files are still repaired if this one has failed.
+                                    // Repaired files pass verification so only the failed
+                                    // ones will be downloaded when the task is started again
+                                    if let Err(err) = result {
+                                        return match err.downcast::<DownloadFailed>() {
+                                            Ok(failed) => Ok(Some(failed)),
+                                            Err(err) => Err(err)
+                                        };
                                     }
-                                }
 
-                                // Download the file
-                                Downloader::new(file.uri)
-                                    .continue_downloading(false)
-                                    .download(file_path)?
-                                    .wait()?;
+                                    sender.send((
+                                        Status::RepairingFiles,
+                                        current.fetch_add(1, Ordering::Relaxed) + 1,
+                                        total
+                                    ))?;
 
-                                sender.send((
-                                    Status::RepairingFiles,
-                                    current.fetch_add(1, Ordering::Relaxed) + 1,
-                                    total
-                                ))?;
+                                    Ok(None)
+                                }));
+                            }
 
-                                Ok(())
-                            }));
+                            // Wait for current chunk of files to finish repairing
+                            for task in tasks.drain(..) {
+                                if let Some(failed) = task.await_complete()? {
+                                    failed_files.push(failed);
+                                }
+                            }
                         }
 
-                        // Wait for current chunk of files to finish repairing
-                        for task in tasks.drain(..) {
-                            task.await_complete()?;
+                        if !failed_files.is_empty() {
+                            return Err(FilesDownloadFailed(failed_files).into());
                         }
-                    }
 
-                    sender.send((
-                        Status::RepairingFiles,
-                        total,
-                        total
-                    ))?;
+                        sender.send((
+                            Status::RepairingFiles,
+                            total,
+                            total
+                        ))?;
+
+                        control.checkpoint()?;
+
+                        Ok(())
+                    })?;
 
                     // Finish transition
 
@@ -261,6 +299,8 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
 
                     transition.finish()?;
 
+                    saved_tasks::release_transition(transition.transition_path())?;
+
                     sender.send((Status::FinishingTransition, 1, 1))?;
 
                     Ok(())
@@ -268,12 +308,26 @@ impl QueuedTask for VerifyIntegrityQueuedTask {
             })
         }))
     }
+
+    #[inline]
+    fn get_locks(&self) -> Vec<PathBuf> {
+        vec![self.path.clone()]
+    }
+
+    #[inline]
+    fn get_saved(&self) -> Option<SavedTask> {
+        Some(SavedTask::VerifyGame {
+            game: self.card_info.get_name().to_string(),
+            edition: self.card_info.get_edition().to_string()
+        })
+    }
 }
 
 #[derive(Debug)]
 pub struct VerifyIntegrityResolvedTask {
     pub updater: BasicUpdater<Status, (), anyhow::Error>,
-    pub card_info: CardInfo
+    pub card_info: CardInfo,
+    pub control: TaskControl
 }
 
 impl ResolvedTask for VerifyIntegrityResolvedTask {
@@ -318,4 +372,9 @@ impl ResolvedTask for VerifyIntegrityResolvedTask {
             Err(err) => anyhow::bail!(err.to_string())
         }
     }
+
+    #[inline]
+    fn get_control(&self) -> Option<&TaskControl> {
+        Some(&self.control)
+    }
 }
@@ -0,0 +1,664 @@
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use relm4::prelude::*;
+
+use gtk::prelude::*;
+use adw::prelude::*;
+
+use serde_json::Value as Json;
+
+use crate::tr;
+
+use crate::config;
+use crate::games;
+
+use crate::config::games::wine::prelude::*;
+use crate::config::games::enhancements::prelude::*;
+
+use crate::ui::components::game_card::CardInfo;
+
+pub static mut WINDOW: Option<adw::PreferencesWindow> = None;
+
+#[derive(Debug)]
+pub struct GamePreferencesApp {
+    pub preferences_page: adw::PreferencesPage,
+    pub preferences_groups: Vec<adw::PreferencesGroup>,
+
+    pub game_info: CardInfo
+}
+
+#[derive(Debug, Clone)]
+pub enum GamePreferencesAppMsg {
+    SetGameInfo(CardInfo),
+
+    ShowToast {
+        title: String,
+        message: Option<String>
+    }
+}
+
+/// Expander row which overrides global setting for the game edition
+/// 
+/// Expansion switch enables the override. Nested rows
+/// change the overridden value which is kept even when
+/// the override is disabled
+#[derive(Clone)]
+struct OverrideRow {
+    row: adw::ExpanderRow,
+
+    /// Config property of the override
+    property: String,
+
+    /// Overridden value. Initialized by the global one
+    value: Rc<RefCell<Json>>,
+
+    /// Description of the global value
+    inherited: String,
+
+    sender: AsyncComponentSender<GamePreferencesApp>
+}
+
+impl OverrideRow {
+    fn new(
+        title: &str,
+        property: String,
+        inherited: String,
+        inherited_value: Json,
+        overridden_value: Option<Json>,
+        sender: &AsyncComponentSender<GamePreferencesApp>
+    ) -> Self {
+        let row = adw::ExpanderRow::new();
+
+        row.set_title(title);
+        row.set_show_enable_switch(true);
+        row.set_enable_expansion(overridden_value.is_some());
+
+        let override_row = Self {
+            row,
+            property,
+            value: Rc::new(RefCell::new(overridden_value.unwrap_or(inherited_value))),
+            inherited,
+            sender: sender.clone()
+        };
+
+        override_row.update_subtitle();
+
+        let this = override_row.clone();
+
+        override_row.row.connect_enable_expansion_notify(move |row| {
+            if row.enables_expansion() {
+                this.write(this.value.borrow().clone());
+            } else {
+                this.write(Json::Null);
+            }
+
+            this.update_subtitle();
+        });
+
+        override_row
+    }
+
+    fn update_subtitle(&self) {
+        if self.row.enables_expansion() {
+            self.row.set_subtitle(&tr!("game-preferences-overridden"));
+        }
+
+        else {
+            self.row.set_subtitle(&tr!("game-preferences-inherited", {
+                "value" = self.inherited.clone()
+            }));
+        }
+    }
+
+    /// Update overridden value or one of its fields
+    fn set(&self, field: Option<&str>, value: impl Into<Json>) {
+        {
+            let mut current = self.value.borrow_mut();
+
+            match field {
+                Some(field) => current[field] = value.into(),
+                None => *current = value.into()
+            }
+        }
+
+        if self.row.enables_expansion() {
+            self.write(self.value.borrow().clone());
+        }
+    }
+
+    fn write(&self, value: Json) {
+        if let Err(err) = config::set(&self.property, value) {
+            self.sender.input(GamePreferencesAppMsg::ShowToast {
+                title: tr!("config-property-update-failed"),
+                message: Some(err.to_string())
+            });
+        }
+    }
+}
+
+#[inline]
+fn describe_bool(value: bool) -> String {
+    if value {
+        tr!("game-preferences-enabled")
+    } else {
+        tr!("game-preferences-disabled")
+    }
+}
+
+fn combo_row(title: &str, items: &[&str], selected: usize, callback: impl Fn(usize) + 'static) -> adw::ComboRow {
+    let row = adw::ComboRow::new();
+
+    row.set_title(title);
+    row.set_model(Some(&gtk::StringList::new(items)));
+    row.set_selected(selected as u32);
+
+    row.connect_selected_notify(move |row| callback(row.selected() as usize));
+
+    row
+}
+
+fn switch_row(title: &str, active: bool, callback: impl Fn(bool) + 'static) -> adw::SwitchRow {
+    let row = adw::SwitchRow::new();
+
+    row.set_title(title);
+    row.set_active(active);
+
+    row.connect_active_notify(move |row| callback(row.is_active()));
+
+    row
+}
+
+fn spin_row(title: &str, value: u64, max: f64, step: f64, callback: impl Fn(u64) + 'static) -> adw::SpinRow {
+    let row = adw::SpinRow::new(Some(&gtk::Adjustment::new(value as f64, 0.0, max, step, 1.0, 0.0)), 1.0, 0);
+
+    row.set_title(title);
+
+    row.connect_value_notify(move |row| callback(row.value() as u64));
+
+    row
+}
+
+#[relm4::component(pub, async)]
+impl SimpleAsyncComponent for GamePreferencesApp {
+    type Init = adw::Window;
+    type Input = GamePreferencesAppMsg;
+    type Output = ();
+
+    view! {
+        window = adw::PreferencesWindow {
+            set_default_size: (700, 560),
+            set_title: Some(&tr!("game-preferences")),
+
+            set_modal: true,
+            set_hide_on_close: true,
+
+            add_css_class?: crate::APP_DEBUG.then_some("devel"),
+
+            add: preferences_page
+        }
+    }
+
+    async fn init(parent: Self::Init, root: Self::Root, _sender: AsyncComponentSender<Self>) -> AsyncComponentParts<Self> {
+        let model = Self {
+            preferences_page: adw::PreferencesPage::new(),
+            preferences_groups: Vec::new(),
+
+            game_info: CardInfo::default()
+        };
+
+        let preferences_page = &model.preferences_page;
+
+        let widgets = view_output!();
+
+        widgets.window.set_transient_for(Some(&parent));
+
+        unsafe {
+            WINDOW = Some(widgets.window.clone());
+        }
+
+        AsyncComponentParts { model, widgets }
+    }
+
+    async fn update(&mut self, msg: Self::Input, sender: AsyncComponentSender<Self>) {
+        match msg {
+            GamePreferencesAppMsg::SetGameInfo(game_info) => {
+                let game = unsafe {
+                    games::get_unsafe(game_info.get_name())
+                };
+
+                let config = config::get();
+
+                let settings = match config.games.get_game_settings(game) {
+                    Ok(settings) => settings,
+
+                    Err(err) => {
+                        sender.input(GamePreferencesAppMsg::ShowToast {
+                            title: tr!("game-get-settings-failed", {
+                                "game-title" = game_info.get_title()
+                            }),
+                            message: Some(err.to_string())
+                        });
+
+                        return;
+                    }
+                };
+
+                let overrides = settings.overrides
+                    .get(game_info.get_edition())
+                    .cloned()
+                    .unwrap_or_default();
+
+                let property = |name: &str| format!("games.settings.{}.overrides.{}.{name}", game_info.get_name(), game_info.get_edition());
+
+                for group in self.preferences_groups.drain(..) {
+                    self.preferences_page.remove(&group);
+                }
+
+                self.preferences_page.set_title(game_info.get_title());
+
+                let wine = config.games.wine;
+                let enhancements = config.games.enhancements;
+
+                // Wine settings
+
+                let wine_group = adw::PreferencesGroup::new();
+
+                wine_group.set_title(&tr!("preferences--wine"));
+
+                let languages = WineLang::list();
+
+                let row = OverrideRow::new(
+                    &tr!("wine-language"),
+                    property("language"),
+                    wine.language.name().to_string(),
+                    serde_json::to_value(wine.language).unwrap(),
+                    overrides.language.map(|language| serde_json::to_value(language).unwrap()),
+                    &sender
+                );
+
+                let this = row.clone();
+
+                row.row.add_row(&combo_row(
+                    &tr!("wine-language"),
+                    &languages.iter().map(WineLang::name).collect::<Vec<_>>(),
+                    languages.iter()
+                        .position(|language| language == &overrides.language.unwrap_or(wine.language))
+                        .unwrap_or(0),
+                    move |i| this.set(None, serde_json::to_value(languages[i]).unwrap())
+                ));
+
+                wine_group.add(&row.row);
+
+                let syncs = [WineSync::None, WineSync::ESync, WineSync::FSync];
+
+                let row = OverrideRow::new(
+                    &tr!("wine-sync"),
+                    property("sync"),
+                    format!("{:?}", wine.sync),
+                    serde_json::to_value(wine.sync).unwrap(),
+                    overrides.sync.map(|sync| serde_json::to_value(sync).unwrap()),
+                    &sender
+                );
+
+                let this = row.clone();
+
+                row.row.add_row(&combo_row(
+                    &tr!("wine-sync"),
+                    &["None", "ESync", "FSync"],
+                    syncs.iter()
+                        .position(|sync| sync == &overrides.sync.unwrap_or(wine.sync))
+                        .unwrap_or(0),
+                    move |i| this.set(None, serde_json::to_value(syncs[i]).unwrap())
+                ));
+
+                wine_group.add(&row.row);
+
+                let row = OverrideRow::new(
+                    &tr!("wine-borderless"),
+                    property("borderless"),
+                    describe_bool(wine.borderless),
+                    Json::Bool(wine.borderless),
+                    overrides.borderless.map(Json::Bool),
+                    &sender
+                );
+
+                let this = row.clone();
+
+                row.row.add_row(&switch_row(
+                    &tr!("game-preferences-enabled"),
+                    overrides.borderless.unwrap_or(wine.borderless),
+                    move |active| this.set(None, active)
+                ));
+
+                wine_group.add(&row.row);
+
+                let virtual_desktop = overrides.virtual_desktop.unwrap_or(wine.virtual_desktop);
+
+                let row = OverrideRow::new(
+                    &tr!("wine-virtual-desktop"),
+                    property("virtual_desktop"),
+                    if wine.virtual_desktop.enabled {
+                        format!("{}x{}", wine.virtual_desktop.width, wine.virtual_desktop.height)
+                    } else {
+                        describe_bool(false)
+                    },
+                    serde_json::to_value(wine.virtual_desktop).unwrap(),
+                    overrides.virtual_desktop.map(|virtual_desktop| serde_json::to_value(virtual_desktop).unwrap()),
+                    &sender
+                );
+
+                let this = row.clone();
+
+                row.row.add_row(&switch_row(
+                    &tr!("wine-virtual-desktop-enabled"),
+                    virtual_desktop.enabled,
+                    move |active| this.set(Some("enabled"), active)
+                ));
+
+                let this = row.clone();
+
+                row.row.add_row(&spin_row(
+                    &tr!("wine-virtual-desktop-width"),
+                    virtual_desktop.width,
+                    4096.0, 10.0,
+                    move |value| this.set(Some("width"), value)
+                ));
+
+                let this = row.clone();
+
+                row.row.add_row(&spin_row(
+                    &tr!("wine-virtual-desktop-height"),
+                    virtual_desktop.height,
+                    4096.0, 10.0,
+                    move |value| this.set(Some("height"), value)
+                ));
+
+                wine_group.add(&row.row);
+
+                // Gaming settings
+
+                let gaming_group = adw::PreferencesGroup::new();
+
+                gaming_group.set_title(&tr!("preferences--gaming"));
+
+                let huds = [HUD::None, HUD::DXVK, HUD::MangoHUD];
+
+                let row = OverrideRow::new(
+                    &tr!("game-hud"),
+                    property("hud"),
+                    format!("{:?}", enhancements.hud),
+                    serde_json::to_value(enhancements.hud).unwrap(),
+                    overrides.hud.map(|hud| serde_json::to_value(hud).unwrap()),
+                    &sender
+                );
+
+                let this = row.clone();
+
+                row.row.add_row(&combo_row(
+                    &tr!("game-hud"),
+                    &["None", "DXVK", "MangoHUD"],
+                    huds.iter()
+                        .position(|hud| hud == &overrides.hud.unwrap_or(enhancements.hud))
+                        .unwrap_or(0),
+                    move |i| this.set(None, serde_json::to_value(huds[i]).unwrap())
+                ));
+
+                gaming_group.add(&row.row);
+
+                let fsr = overrides.fsr.unwrap_or(enhancements.fsr);
+
+                let qualities = [
+                    FsrQuality::Ultra,
+                    FsrQuality::Quality,
+                    FsrQuality::Balanced,
+                    FsrQuality::Performance
+                ];
+
+                let row = OverrideRow::new(
+                    &tr!("game-fsr"),
+                    property("fsr"),
+                    describe_bool(enhancements.fsr.enabled),
+                    serde_json::to_value(enhancements.fsr).unwrap(),
+                    overrides.fsr.map(|fsr| serde_json::to_value(fsr).unwrap()),
+                    &sender
+                );
+
+                let this = row.clone();
+
+                row.row.add_row(&switch_row(
+                    &tr!("game-fsr-enabled"),
+                    fsr.enabled,
+                    move |active| this.set(Some("enabled"), active)
+                ));
+
+                let this = row.clone();
+
+                row.row.add_row(&combo_row(
+                    &tr!("game-fsr-quality"),
+                    &[
+                        &tr!("game-fsr-quality-ultra"),
+                        &tr!("game-fsr-quality-quality"),
+                        &tr!("game-fsr-quality-balanced"),
+                        &tr!("game-fsr-quality-performance")
+                    ],
+                    qualities.iter()
+                        .position(|quality| quality == &fsr.quality)
+                        .unwrap_or(0),
+                    move |i| this.set(Some("quality"), serde_json::to_value(qualities[i]).unwrap())
+                ));
+
+                let this = row.clone();
+
+                row.row.add_row(&spin_row(
+                    &tr!("game-fsr-strength"),
+                    fsr.strength,
+                    5.0, 1.0,
+                    move |value| this.set(Some("strength"), value)
+                ));
+
+                gaming_group.add(&row.row);
+
+                let row = OverrideRow::new(
+                    &tr!("game-gamemode"),
+                    property("gamemode"),
+                    describe_bool(enhancements.gamemode),
+                    Json::Bool(enhancements.gamemode),
+                    overrides.gamemode.map(Json::Bool),
+                    &sender
+                );
+
+                let this = row.clone();
+
+                row.row.add_row(&switch_row(
+                    &tr!("game-preferences-enabled"),
+                    overrides.gamemode.unwrap_or(enhancements.gamemode),
+                    move |active| this.set(None, active)
+                ));
+
+                gaming_group.add(&row.row);
+
+                // Environment variables
+
+                let environment_group = adw::PreferencesGroup::new();
+
+                environment_group.set_title(&tr!("game-preferences-environment"));
+                environment_group.set_description(Some(&tr!("game-preferences-environment-description")));
+
+                let environment = Rc::new(RefCell::new(overrides.environment.clone()));
+                let environment_property = property("environment");
+
+                // Global variables are shown only if they're not overridden
+                for (key, value) in &config.games.environment {
+                    if !overrides.environment.contains_key(key) {
+                        let row = adw::ActionRow::new();
+
+                        row.set_title(key);
+                        row.set_subtitle(&tr!("game-preferences-inherited", {
+                            "value" = value.clone()
+                        }));
+
+                        environment_group.add(&row);
+                    }
+                }
+
+                let add_variable_row = {
+                    let environment = environment.clone();
+                    let environment_property = environment_property.clone();
+                    let environment_group = environment_group.clone();
+                    let sender = sender.clone();
+
+                    move |key: String, value: String| {
+                        let row = adw::ActionRow::new();
+
+                        row.set_title(&key);
+                        row.set_subtitle(&value);
+
+                        let button = gtk::Button::new();
+
+                        button.set_icon_name("user-trash-symbolic");
+                        button.set_valign(gtk::Align::Center);
+                        button.add_css_class("flat");
+
+                        let environment = environment.clone();
+                        let environment_property = environment_property.clone();
+                        let environment_group = environment_group.clone();
+                        let sender = sender.clone();
+
+                        button.connect_clicked(gtk::glib::clone!(@weak row => move |_| {
+                            environment.borrow_mut().remove(&key);
+
+                            if let Err(err) = config::set(&environment_property, serde_json::to_value(&*environment.borrow()).unwrap()) {
+                                sender.input(GamePreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                });
+                            }
+
+                            environment_group.remove(&row);
+                        }));
+
+                        row.add_suffix(&button);
+
+                        environment_group.add(&row);
+                    }
+                };
+
+                for (key, value) in overrides.environment {
+                    add_variable_row(key, value);
+                }
+
+                let entry_row = adw::EntryRow::new();
+
+                entry_row.set_title(&tr!("game-preferences-environment-add"));
+                entry_row.set_show_apply_button(true);
+
+                entry_row.connect_apply(gtk::glib::clone!(@strong sender => move |row| {
+                    let text = row.text();
+
+                    let Some((key, value)) = text.split_once('=') else {
+                        return;
+                    };
+
+                    let (key, value) = (key.trim().to_string(), value.trim().to_string());
+
+                    if key.is_empty() {
+                        return;
+                    }
+
+                    environment.borrow_mut().insert(key.clone(), value.clone());
+
+                    if let Err(err) = config::set(&environment_property, serde_json::to_value(&*environment.borrow()).unwrap()) {
+                        sender.input(GamePreferencesAppMsg::ShowToast {
+                            title: tr!("config-property-update-failed"),
+                            message: Some(err.to_string())
+                        });
+                    }
+
+                    add_variable_row(key, value);
+
+                    row.set_text("");
+                }));
+
+                environment_group.add(&entry_row);
+
+                // Launch hooks
+
+                let hooks_group = adw::PreferencesGroup::new();
+
+                hooks_group.set_title(&tr!("game-preferences-hooks"));
+                hooks_group.set_description(Some(&tr!("game-preferences-hooks-description")));
+
+                let hooks = settings.hooks
+                    .get(game_info.get_edition())
+                    .cloned()
+                    .unwrap_or_default();
+
+                for (title, name, hook) in [
+                    (tr!("game-preferences-hooks-pre-launch"), "pre_launch", hooks.pre_launch),
+                    (tr!("game-preferences-hooks-post-exit"), "post_exit", hooks.post_exit)
+                ] {
+                    let row = adw::EntryRow::new();
+
+                    row.set_title(&title);
+                    row.set_text(&hook.unwrap_or_default());
+                    row.set_show_apply_button(true);
+
+                    let property = format!("games.settings.{}.hooks.{}.{name}", game_info.get_name(), game_info.get_edition());
+
+                    row.connect_apply(gtk::glib::clone!(@strong sender => move |row| {
+                        let hook = row.text().trim().to_string();
+
+                        let value = if hook.is_empty() {
+                            Json::Null
+                        } else {
+                            Json::String(hook)
+                        };
+
+                        if let Err(err) = config::set(&property, value) {
+                            sender.input(GamePreferencesAppMsg::ShowToast {
+                                title: tr!("config-property-update-failed"),
+                                message: Some(err.to_string())
+                            });
+                        }
+                    }));
+
+                    hooks_group.add(&row);
+                }
+
+                for group in [wine_group, gaming_group, environment_group, hooks_group] {
+                    self.preferences_page.add(&group);
+                    self.preferences_groups.push(group);
+                }
+
+                self.game_info = game_info;
+            }
+
+            GamePreferencesAppMsg::ShowToast { title, message } => {
+                let window = unsafe {
+                    WINDOW.as_ref().unwrap_unchecked()
+                };
+
+                let toast = adw::Toast::new(&title);
+
+                if let Some(message) = message {
+                    toast.set_button_label(Some(&tr!("dialog-toast-details")));
+
+                    let dialog = adw::MessageDialog::new(
+                        Some(window),
+                        Some(&title),
+                        Some(&message)
+                    );
+
+                    dialog.add_response("close", &tr!("dialog-close"));
+
+                    toast.connect_button_clicked(move |_| {
+                        dialog.present();
+                    });
+                }
+
+                window.add_toast(toast);
+            }
+        }
+    }
+}
@@ -105,9 +105,8 @@ pub fn get_download(pool: &rusty_pool::ThreadPool) -> anyhow::Result<Vec<AddonsL
     for game in games::list()?.values() {
         let settings = config.games.get_game_settings(game)?;
 
-        // Unfortunately it's impossible to run get_game_addons and other tasks
-        // in the threads as well because lua executer cannot be run in different threads
-        // See multithread-lua branch for details
+        // Every driver spawns its own lua VM for each concurrent call
+        // so games can be checked in parallel
         tasks.push(pool.evaluate(move || -> anyhow::Result<Vec<AddonsListEntry>> {
             let mut addons = Vec::new();
 
@@ -1,37 +1,58 @@
+use std::collections::HashSet;
+
 use wincompatlib::dxvk::Dxvk as WincompatlibDxvk;
 
-use crate::config;
+use crate::config::components::Components;
 use crate::components::dxvk::Dxvk;
 
+use super::check_wine_prefix::PrefixEntry;
+
 #[inline]
-pub fn get_download() -> anyhow::Result<Option<Dxvk>> {
-    let dxvk = Dxvk::from_config()?;
+pub fn get_download(used_components: &[Components]) -> anyhow::Result<Vec<Dxvk>> {
+    let mut download = Vec::new();
+
+    for components in used_components {
+        let dxvk = Dxvk::from_components(components)?;
 
-    if dxvk.is_downloaded() {
-        return Ok(None);
+        if !dxvk.is_downloaded() && !download.contains(&dxvk) {
+            download.push(dxvk);
+        }
     }
 
-    Ok(Some(dxvk))
+    Ok(download)
 }
 
 #[inline]
-pub fn get_apply() -> anyhow::Result<Option<Dxvk>> {
-    let prefix = config::get().components.wine.prefix.path;
+pub fn get_apply(used_components: &[Components]) -> anyhow::Result<Vec<PrefixEntry>> {
+    let mut apply = Vec::new();
+    let mut checked = HashSet::new();
+
+    for components in used_components {
+        let prefix = &components.wine.prefix.path;
+
+        // The same prefix can be used by different games
+        // so it's checked using the first components settings
+        if !checked.insert(prefix) {
+            continue;
+        }
 
-    if !prefix.exists() {
         // We don't need to apply DXVK because it's done during prefix creation
-        return Ok(None);
-    }
+        if !prefix.exists() {
+            continue;
+        }
 
-    let selected_dxvk = Dxvk::from_config()?;
+        let entry = PrefixEntry::from_components(components)?;
 
-    let installed_dxvk = WincompatlibDxvk::get_version(prefix)?;
+        let installed_dxvk = WincompatlibDxvk::get_version(prefix)?;
 
-    if let Some(version) = installed_dxvk {
-        if selected_dxvk.name.contains(&version) || selected_dxvk.version.contains(&version) {
-            return Ok(None);
+        if let Some(version) = installed_dxvk {
+            if entry.dxvk.name.contains(&version) || entry.dxvk.version.contains(&version) {
+                continue;
+            }
         }
+
+        apply.push(entry);
     }
 
-    Ok(Some(selected_dxvk))
+    Ok(apply)
 }
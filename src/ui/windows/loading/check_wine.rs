@@ -1,12 +1,17 @@
+use crate::config::components::Components;
 use crate::components::wine::Wine;
 
 #[inline]
-pub fn get_download() -> anyhow::Result<Option<Wine>> {
-    let wine = Wine::from_config()?;
+pub fn get_download(used_components: &[Components]) -> anyhow::Result<Vec<Wine>> {
+    let mut download = Vec::new();
 
-    if wine.is_downloaded() {
-        return Ok(None);
+    for components in used_components {
+        let wine = Wine::from_components(components)?;
+
+        if !wine.is_downloaded() && !download.contains(&wine) {
+            download.push(wine);
+        }
     }
 
-    Ok(Some(wine))
+    Ok(download)
 }
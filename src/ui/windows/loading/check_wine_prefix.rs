@@ -1,13 +1,48 @@
-use crate::config;
+use std::path::Path;
+
+use crate::config::components::Components;
 use crate::config::components::wine::prefix::Prefix;
 
+use crate::components::wine::Wine;
+use crate::components::dxvk::Dxvk;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixEntry {
+    pub prefix: Prefix,
+    pub wine: Wine,
+    pub dxvk: Dxvk
+}
+
+impl PrefixEntry {
+    /// Resolve wine and dxvk versions used by the prefix
+    #[inline]
+    pub fn from_components(components: &Components) -> anyhow::Result<Self> {
+        Ok(Self {
+            prefix: components.wine.prefix.clone(),
+            wine: Wine::from_components(components)?,
+            dxvk: Dxvk::from_components(components)?
+        })
+    }
+
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.prefix.path
+    }
+}
+
 #[inline]
-pub fn check_wine_prefix() -> Option<Prefix> {
-    let prefix = config::get().components.wine.prefix;
+pub fn check_wine_prefix(used_components: &[Components]) -> anyhow::Result<Vec<PrefixEntry>> {
+    let mut create = Vec::<PrefixEntry>::new();
+
+    for components in used_components {
+        let path = &components.wine.prefix.path;
 
-    if !prefix.path.exists() {
-        return Some(prefix);
+        // The same prefix can be used by different games
+        // so it's created using the first components settings
+        if !path.exists() && !create.iter().any(|entry| entry.path() == path) {
+            create.push(PrefixEntry::from_components(components)?);
+        }
     }
 
-    None
+    Ok(create)
 }
@@ -19,7 +19,11 @@ pub struct GameListEntry {
 pub struct GamesList {
     pub installed: Vec<GameListEntry>,
     pub available: Vec<GameListEntry>,
-    pub outdated: Vec<GameListEntry>
+    pub outdated: Vec<GameListEntry>,
+
+    /// Installed games which diff couldn't be calculated
+    /// (e.g. when the launcher is offline)
+    pub unknown: Vec<GameListEntry>
 }
 
 #[inline]
@@ -28,7 +32,7 @@ pub fn init_games() -> anyhow::Result<()> {
 }
 
 #[inline]
-fn get_game_entries(game: &Game, settings: GameSettings) -> anyhow::Result<Vec<(GameListEntry, Option<Diff>)>> {
+fn get_game_entries(game: &Game, settings: &GameSettings) -> anyhow::Result<Vec<(GameListEntry, anyhow::Result<Option<Diff>>)>> {
     game.driver.get_game_editions_list()?
         .into_iter()
         .map(|edition| game.driver.get_card_picture(&edition.name)
@@ -39,11 +43,16 @@ fn get_game_entries(game: &Game, settings: GameSettings) -> anyhow::Result<Vec<(
             card_picture,
             edition
         })
-        .and_then(|entry| {
+        .map(|entry| {
             let path = settings.paths[&entry.edition.name].game.to_string_lossy();
 
-            game.driver.get_game_diff(&path, &entry.edition.name)
-                .map(|diff| (entry, diff))
+            let diff = game.driver.get_game_diff(&path, &entry.edition.name);
+
+            if let Err(err) = &diff {
+                tracing::warn!("Failed to get {} ({}) diff: {err}", entry.game_title, entry.edition.name);
+            }
+
+            (entry, diff)
         }))
         .collect::<anyhow::Result<Vec<_>>>()
 }
@@ -84,45 +93,41 @@ pub fn get_games_list() -> anyhow::Result<GamesList> {
     let mut installed = Vec::new();
     let mut available = Vec::with_capacity(games.len());
     let mut outdated = Vec::new();
+    let mut unknown = Vec::new();
 
     for game in games.values() {
         let settings = settings.get_game_settings(game)?;
 
-        let entries = get_game_entries(game, settings)?;
+        for (entry, diff) in get_game_entries(game, &settings)? {
+            match diff {
+                Ok(Some(Diff { status: DiffStatus::Latest, .. })) => installed.push(entry),
+                Ok(Some(Diff { status: DiffStatus::Outdated, .. })) => outdated.push(entry),
 
-        let installed_entries = entries.iter()
-            .filter_map(|(entry, diff)| {
-                match diff {
-                    Some(Diff { status: DiffStatus::Latest, .. }) => Some(entry),
-                    _ => None
-                }
-            })
-            .cloned();
-
-        let available_entries = entries.iter()
-            .filter_map(|(entry, diff)| {
-                diff.is_none().then_some(entry)
-            })
-            .cloned();
-
-        // TODO: handle "unavailable" status
-        let outdated_entries = entries.iter()
-            .filter_map(|(entry, diff)| {
-                match diff {
-                    Some(Diff { status: DiffStatus::Outdated, .. }) => Some(entry),
-                    _ => None
-                }
-            })
-            .cloned();
+                Ok(None) => available.push(entry),
 
-        installed.extend(installed_entries);
-        available.extend(available_entries);
-        outdated.extend(outdated_entries);
+                // TODO: handle "unavailable" status
+                Ok(Some(_)) => (),
+
+                // Diff can't be calculated without network access,
+                // but installed games still can be launched
+                Err(_) => {
+                    let path = settings.paths[&entry.edition.name].game.to_string_lossy();
+
+                    match game.driver.is_game_installed(&path, &entry.edition.name) {
+                        Ok(true) => unknown.push(entry),
+                        Ok(false) => available.push(entry),
+
+                        Err(err) => tracing::error!("Failed to check if {} ({}) is installed: {err}", entry.game_title, entry.edition.name)
+                    }
+                }
+            }
+        }
     }
 
     Ok(GamesList {
         installed,
         available,
-        outdated
+        outdated,
+        unknown
     })
 }
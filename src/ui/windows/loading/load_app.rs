@@ -1,25 +1,34 @@
 use relm4::prelude::*;
 
 use crate::tr;
+use crate::network;
 
 use crate::components::dxvk::Dxvk;
 use crate::components::wine::Wine;
 
-use crate::config::components::wine::prefix::Prefix;
-
 use super::*;
 
-const TOTAL_STEPS: f64 = 13.0;
+const TOTAL_STEPS: f64 = 14.0;
 
 #[derive(Debug)]
 pub struct LoadingResult {
-    pub download_wine: Option<Wine>,
-    pub download_dxvk: Option<Dxvk>,
-    pub apply_dxvk: Option<Dxvk>,
-    pub create_prefix: Option<Prefix>,
+    pub download_wine: Vec<Wine>,
+    pub download_dxvk: Vec<Dxvk>,
+    pub apply_dxvk: Vec<check_wine_prefix::PrefixEntry>,
+    pub create_prefix: Vec<check_wine_prefix::PrefixEntry>,
     pub download_addons: Vec<check_addons::AddonsListEntry>,
 
-    pub games_list: init_games::GamesList
+    /// Tasks saved when the launcher was closed
+    pub restored_tasks: load_tasks::RestoredTasks,
+
+    pub games_list: init_games::GamesList,
+
+    /// Integrations which didn't pass hashes or signature verification
+    pub rejected_integrations: Vec<update_integrations::VerificationFailed>,
+
+    /// Some remote resources were unreachable
+    /// so their cached versions were used
+    pub offline: bool
 }
 
 pub fn load_app(sender: &AsyncComponentSender<LoadingApp>) -> Result<LoadingResult, LoadingAppMsg> {
@@ -65,10 +74,20 @@ pub fn load_app(sender: &AsyncComponentSender<LoadingApp>) -> Result<LoadingResu
         .core_size(config.general.threads.number as usize)
         .build();
 
-    update_integrations::update_integrations(&pool).map_err(|err| LoadingAppMsg::DisplayError {
-        title: tr!("loading-updating-integrations-failed"),
-        message: err.to_string()
-    })?;
+    // Installed integrations are used if they couldn't be updated
+    let rejected_integrations = match update_integrations::update_integrations(&pool) {
+        Ok(rejected) => rejected,
+
+        Err(err) => {
+            tracing::error!("Failed to update integrations: {err}");
+
+            if network::is_transport_error(&err) {
+                network::set_offline();
+            }
+
+            Vec::new()
+        }
+    };
 
     sender.input(LoadingAppMsg::SetProgress(5.0 / TOTAL_STEPS));
     sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-preparing-games")));
@@ -97,39 +116,72 @@ pub fn load_app(sender: &AsyncComponentSender<LoadingApp>) -> Result<LoadingResu
     sender.input(LoadingAppMsg::SetProgress(8.0 / TOTAL_STEPS));
     sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-checking-wine-version")));
 
-    let download_wine = check_wine::get_download().map_err(|err| LoadingAppMsg::DisplayError {
-        title: tr!("loading-checking-wine-version-failed"),
-        message: err.to_string()
-    })?;
+    let used_components = used_components::get_used_components().unwrap_or_else(|err| {
+        tracing::error!("Failed to get games components: {err}");
+
+        vec![config.components.clone()]
+    });
+
+    // Components lists are cached so these checks fail only
+    // if the channel was never reached before
+    let download_wine = check_wine::get_download(&used_components).unwrap_or_else(|err| {
+        tracing::error!("Failed to check wine version: {err}");
+
+        Vec::new()
+    });
 
     sender.input(LoadingAppMsg::SetProgress(9.0 / TOTAL_STEPS));
     sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-checking-dxvk-version")));
 
-    let download_dxvk = check_dxvk::get_download().map_err(|err| LoadingAppMsg::DisplayError {
-        title: tr!("loading-checking-dxvk-version-failed"),
-        message: err.to_string()
-    })?;
+    let download_dxvk = check_dxvk::get_download(&used_components).unwrap_or_else(|err| {
+        tracing::error!("Failed to check dxvk version: {err}");
+
+        Vec::new()
+    });
 
     sender.input(LoadingAppMsg::SetProgress(10.0 / TOTAL_STEPS));
     sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-checking-applied-dxvk")));
 
-    let apply_dxvk = check_dxvk::get_apply().map_err(|err| LoadingAppMsg::DisplayError {
-        title: tr!("loading-checking-applied-dxvk-failed"),
-        message: err.to_string()
-    })?;
+    let apply_dxvk = check_dxvk::get_apply(&used_components).unwrap_or_else(|err| {
+        tracing::error!("Failed to check applied dxvk version: {err}");
+
+        Vec::new()
+    });
 
     sender.input(LoadingAppMsg::SetProgress(11.0 / TOTAL_STEPS));
     sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-checking-wine-prefix")));
 
-    let create_prefix = check_wine_prefix::check_wine_prefix();
+    let create_prefix = check_wine_prefix::check_wine_prefix(&used_components).unwrap_or_else(|err| {
+        tracing::error!("Failed to check wine prefix: {err}");
+
+        Vec::new()
+    });
 
     sender.input(LoadingAppMsg::SetProgress(12.0 / TOTAL_STEPS));
     sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-checking-games-addons")));
 
-    let download_addons = check_addons::get_download(&pool).map_err(|err| LoadingAppMsg::DisplayError {
-        title: tr!("loading-checking-games-addons-failed"),
-        message: err.to_string()
-    })?;
+    let download_addons = check_addons::get_download(&pool).unwrap_or_else(|err| {
+        tracing::error!("Failed to check games addons: {err}");
+
+        if network::is_transport_error(&err) {
+            network::set_offline();
+        }
+
+        Vec::new()
+    });
+
+    sender.input(LoadingAppMsg::SetProgress(13.0 / TOTAL_STEPS));
+    sender.input(LoadingAppMsg::SetActiveStage(tr!("loading-restoring-tasks")));
+
+    let restored_tasks = load_tasks::restore_tasks(&games_list).unwrap_or_else(|err| {
+        tracing::error!("Failed to restore saved tasks: {err}");
+
+        load_tasks::RestoredTasks {
+            interrupted: Vec::new(),
+            queued: Vec::new(),
+            transitions: Vec::new()
+        }
+    });
 
     sender.input(LoadingAppMsg::SetProgress(1.0));
 
@@ -144,7 +196,11 @@ pub fn load_app(sender: &AsyncComponentSender<LoadingApp>) -> Result<LoadingResu
         apply_dxvk,
         create_prefix,
         download_addons,
+        restored_tasks,
+
+        games_list,
+        rejected_integrations,
 
-        games_list
+        offline: network::is_offline()
     })
 }
@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use crate::config;
+
+use crate::ui::components::game_card::CardInfo;
+
+use crate::ui::components::tasks_queue::saved_tasks::{
+    SavedTask,
+    SavedTasks
+};
+
+use super::init_games::{
+    GamesList,
+    GameListEntry
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RestoredTask {
+    DownloadGame(CardInfo),
+    VerifyGame(CardInfo)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestoredTasks {
+    /// Tasks which were running when the launcher was closed
+    pub interrupted: Vec<RestoredTask>,
+
+    pub queued: Vec<RestoredTask>,
+
+    /// Transitions folders which were not finished
+    pub transitions: Vec<PathBuf>
+}
+
+impl RestoredTasks {
+    /// Check if there's an interrupted work which can be resumed or discarded
+    #[inline]
+    pub fn has_interrupted(&self) -> bool {
+        !self.interrupted.is_empty() || !self.transitions.is_empty()
+    }
+}
+
+#[inline]
+fn find_entry<'a>(entries: &'a [GameListEntry], game: &str, edition: &str) -> Option<&'a GameListEntry> {
+    entries.iter().find(|entry| entry.game_name == game && entry.edition.name == edition)
+}
+
+#[inline]
+fn get_card_info(entry: &GameListEntry) -> CardInfo {
+    CardInfo::Game {
+        name: entry.game_name.clone(),
+        title: entry.game_title.clone(),
+        developer: entry.game_developer.clone(),
+        edition: entry.edition.name.clone(),
+        picture_uri: entry.card_picture.clone()
+    }
+}
+
+/// Resolve saved task against the current games state
+/// 
+/// Return `None` if the game doesn't exist anymore
+/// or the task is not needed
+fn restore_task(task: &SavedTask, games_list: &GamesList) -> Option<RestoredTask> {
+    match task {
+        // Games with the latest version installed don't need to be downloaded
+        SavedTask::DownloadGame { game, edition } => find_entry(&games_list.available, game, edition)
+            .or_else(|| find_entry(&games_list.outdated, game, edition))
+            .map(|entry| RestoredTask::DownloadGame(get_card_info(entry))),
+
+        SavedTask::VerifyGame { game, edition } => find_entry(&games_list.installed, game, edition)
+            .or_else(|| find_entry(&games_list.outdated, game, edition))
+            .or_else(|| find_entry(&games_list.unknown, game, edition))
+            .or_else(|| find_entry(&games_list.available, game, edition))
+            .map(|entry| RestoredTask::VerifyGame(get_card_info(entry)))
+    }
+}
+
+/// Get transitions which were created by `Transition::get_in` but never finished
+/// 
+/// Transitions owned by the `restored` tasks are skipped
+/// because these tasks will continue them anyway
+pub fn get_interrupted_transitions(restored: &[SavedTask]) -> anyhow::Result<Vec<PathBuf>> {
+    let path = config::get().general.transitions.path;
+
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    Ok(path.read_dir()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .filter(|path| {
+            SavedTask::get_transition_owner(path)
+                .map(|owner| !restored.contains(&owner))
+                .unwrap_or(true)
+        })
+        .collect())
+}
+
+pub fn restore_tasks(games_list: &GamesList) -> anyhow::Result<RestoredTasks> {
+    let saved = SavedTasks::load()?;
+
+    let restore = |task: &SavedTask| {
+        let restored = restore_task(task, games_list);
+
+        if restored.is_none() {
+            tracing::warn!("Saved task is not needed anymore: {task:?}");
+        }
+
+        restored
+    };
+
+    let interrupted = saved.running.iter()
+        .filter_map(restore)
+        .collect();
+
+    let (queued_saved, queued): (Vec<_>, Vec<_>) = saved.queued.iter()
+        .filter_map(|task| Some((task.clone(), restore(task)?)))
+        .unzip();
+
+    // Queued tasks are restored even if the interrupted work
+    // is discarded, so their transitions must be kept
+    let transitions = get_interrupted_transitions(&queued_saved)?;
+
+    Ok(RestoredTasks {
+        interrupted,
+        queued,
+        transitions
+    })
+}
@@ -9,10 +9,12 @@ pub mod init_config;
 pub mod init_locales;
 pub mod update_integrations;
 pub mod init_games;
+pub mod used_components;
 pub mod check_wine;
 pub mod check_dxvk;
 pub mod check_wine_prefix;
 pub mod check_addons;
+pub mod load_tasks;
 pub mod load_app;
 
 use crate::tr;
@@ -2,32 +2,57 @@ use std::collections::HashMap;
 
 use serde_json::Value as Json;
 
-use anime_game_core::network::minreq;
-
 use crate::config;
+use crate::config::games::prelude::SignaturePolicy;
+use crate::network;
 use crate::games::integrations::manifest::Manifest;
+use crate::games::integrations::signature::{self, SignatureVerdict};
+use crate::games::integrations::versions;
+
+/// Integration was refused because it didn't pass hashes or signature verification
+/// 
+/// Unlike network errors it can mean that the integrations source was tampered,
+/// so it must be shown to the user instead of switching to offline mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationFailed {
+    pub game: String,
+    pub reason: String
+}
+
+impl std::fmt::Display for VerificationFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Refusing to install {} integration: {}", self.game, self.reason)
+    }
+}
+
+impl std::error::Error for VerificationFailed {}
 
 struct IntegrationInfo {
+    /// Source from the config file
+    pub origin: String,
+
     pub source: String,
     pub manifest_body: Vec<u8>,
     pub manifest: Manifest
 }
 
-#[inline]
-pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()> {
+/// Update integrations from the sources
+/// 
+/// Return list of integrations which didn't pass verification.
+/// Their installed versions are kept
+pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<Vec<VerificationFailed>> {
     let config = config::get();
 
     let mut tasks = Vec::with_capacity(config.games.integrations.sources.len());
 
     for source in config.games.integrations.sources {
-        tasks.push(pool.evaluate(move || -> anyhow::Result<HashMap<String, IntegrationInfo>> {
-            let response = minreq::get(format!("{source}/integrations.json"))
+        tasks.push((source.clone(), pool.evaluate(move || -> anyhow::Result<HashMap<String, IntegrationInfo>> {
+            let response = network::get(format!("{source}/integrations.json"))
                 .send()?;
 
             let mut games = HashMap::new();
 
-            // HTTP OK
-            if (200..300).contains(&response.status_code) {
+            if response.is_ok() {
                 let integrations = response.json::<Json>()?;
 
                 let Some(integrations) = integrations.get("games").and_then(Json::as_array) else {
@@ -36,12 +61,13 @@ pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()>
 
                 for game in integrations {
                     if let Some(game) = game.as_str() {
-                        let bytes = minreq::get(format!("{source}/games/{game}/manifest.json"))
+                        let bytes = network::get(format!("{source}/games/{game}/manifest.json"))
                             .send()?.into_bytes();
 
                         let manifest = Manifest::from_json(&serde_json::from_slice(&bytes)?)?;
 
                         games.insert(game.to_string(), IntegrationInfo {
+                            origin: source.clone(),
                             source: format!("{source}/games/{game}"),
                             manifest_body: bytes,
                             manifest
@@ -51,16 +77,16 @@ pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()>
             }
 
             else {
-                let response = minreq::get(format!("{source}/manifest.json"))
+                let response = network::get(format!("{source}/manifest.json"))
                     .send()?;
 
-                // HTTP OK
-                if (200..300).contains(&response.status_code) {
+                if response.is_ok() {
                     let bytes = response.into_bytes();
 
                     let manifest = Manifest::from_json(&serde_json::from_slice(&bytes)?)?;
 
                     games.insert(manifest.game_name.to_string(), IntegrationInfo {
+                        origin: source.clone(),
                         source: source.clone(),
                         manifest_body: bytes,
                         manifest
@@ -73,20 +99,37 @@ pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()>
             }
 
             Ok(games)
-        }));
+        })));
     }
 
     let mut games = HashMap::new();
 
-    for task in tasks {
-        for (game, value) in task.await_complete()? {
-            games.insert(game, value);
+    // Unreachable sources shouldn't prevent the launcher from starting
+    // so their already installed integrations are used instead
+    for (source, task) in tasks {
+        match task.await_complete() {
+            Ok(source_games) => games.extend(source_games),
+
+            Err(err) => {
+                tracing::warn!("Failed to fetch integrations from {source}, using installed ones: {err}");
+
+                if network::is_transport_error(&err) {
+                    network::set_offline();
+                }
+            }
         }
     }
 
     let mut tasks = Vec::with_capacity(games.len());
 
+    let keep_versions = config.games.integrations.keep_versions as usize;
+
     for (game, info) in games {
+        let integrations_path = config.games.integrations.path.clone();
+
+        let trusted_keys = config.games.integrations.get_trusted_keys(&info.origin).to_vec();
+        let policy = config.games.integrations.signatures;
+
         let integration_path = config.games.integrations.path.join(&game);
 
         let manifest_path = integration_path.join("manifest.json");
@@ -94,32 +137,136 @@ pub fn update_integrations(pool: &rusty_pool::ThreadPool) -> anyhow::Result<()>
 
         // Spawning new threads to read a few KBs of data is more time-consuming
         // than doing it in the same thread
+        let mut local_version = String::new();
+
         if integration_path.exists() {
-            let local_manifest = std::fs::read(&manifest_path)?;
-            let local_manifest = serde_json::from_slice(&local_manifest)?;
-            let local_manifest = Manifest::from_json(&local_manifest)?;
+            // Broken local manifest is replaced by the downloaded one
+            let local_manifest = std::fs::read(&manifest_path).map_err(anyhow::Error::from)
+                .and_then(|manifest| Ok(serde_json::from_slice(&manifest)?))
+                .and_then(|manifest| Manifest::from_json(&manifest));
+
+            match local_manifest {
+                Ok(local_manifest) => {
+                    local_version = local_manifest.script_version.clone();
+
+                    // Re-download the integration if its files were modified
+                    if local_manifest.script_version == info.manifest.script_version && info.manifest.verify_files(&integration_path).is_ok() {
+                        continue;
+                    }
+                }
 
-            if local_manifest.script_version == info.manifest.script_version {
-                continue;
+                Err(err) => tracing::warn!("Failed to read installed {game} integration manifest, downloading it again: {err}")
             }
         }
 
-        else {
+        tasks.push((game.clone(), pool.evaluate(move || -> anyhow::Result<()> {
+            let script = network::get(format!("{}/{}", info.source, &info.manifest.script_path))
+                .send()?.into_bytes();
+
+            if let Some(hash) = &info.manifest.script_hash {
+                if !hash.verify(&script) {
+                    return Err(VerificationFailed {
+                        reason: format!("script from {} has wrong hash", info.source),
+                        game
+                    }.into());
+                }
+            }
+
+            let mut files = Vec::with_capacity(info.manifest.files.len() + info.manifest.shared.len());
+
+            // Integration files are stored next to its manifest,
+            // and shared files in the source's `shared` folder
+            let remote_files = info.manifest.files.iter()
+                .map(|file| (file, format!("{}/{}", info.source, file.path), integration_path.join(&file.path)))
+                .chain(info.manifest.shared.iter().map(|file| {
+                    (file, format!("{}/shared/{}", info.origin, file.path), integration_path.join("shared").join(&file.path))
+                }));
+
+            for (file, uri, path) in remote_files {
+                let content = network::get(&uri)
+                    .send()?.into_bytes();
+
+                if !file.hash.verify(&content) {
+                    return Err(VerificationFailed {
+                        reason: format!("file {uri} has wrong hash"),
+                        game
+                    }.into());
+                }
+
+                files.push((path, content));
+            }
+
+            if policy != SignaturePolicy::Ignore {
+                let response = network::get(format!("{}/{}", info.source, signature::SIGNATURE_FILE))
+                    .send()?;
+
+                let signature = response.is_ok().then(|| response.as_bytes());
+
+                let contents = files.iter()
+                    .map(|(_, content)| content.as_slice())
+                    .collect::<Vec<_>>();
+
+                let status = signature::verify(&trusted_keys, &info.manifest_body, &script, &contents, signature);
+
+                match signature::check(policy, &status, &info.origin) {
+                    SignatureVerdict::Accept => (),
+
+                    SignatureVerdict::Warn(reason) => tracing::warn!("Installing {game} integration from {}: {reason}", info.source),
+
+                    SignatureVerdict::Refuse(reason) => return Err(VerificationFailed {
+                        reason,
+                        game
+                    }.into())
+                }
+            }
+
+            // Save current version so it could be reverted
+            if info.manifest.script_version != local_version {
+                versions::archive(&integrations_path, &game, keep_versions)?;
+            }
+
+            // Create integration folder only when all its files are downloaded
+            // so failed downloads don't leave empty integrations behind
             std::fs::create_dir_all(&integration_path)?;
-        }
 
-        tasks.push(pool.evaluate(move || -> anyhow::Result<()> {
-            let script = minreq::get(format!("{}/{}", info.source, &info.manifest.script_path))
-                .send()?.into_bytes();
+            for (path, content) in files {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+
+                std::fs::write(path, content)?;
+            }
 
             std::fs::write(manifest_path, info.manifest_body)?;
             std::fs::write(script_path, script)?;
 
             Ok(())
-        }));
+        })));
     }
 
-    tasks.into_iter().try_for_each(|task| task.await_complete())?;
+    let mut rejected = Vec::new();
+
+    for (game, task) in tasks {
+        let Err(err) = task.await_complete() else {
+            continue;
+        };
+
+        match err.downcast::<VerificationFailed>() {
+            Ok(err) => {
+                tracing::error!("{err}");
+
+                rejected.push(err);
+            }
+
+            Err(err) => {
+                tracing::warn!("Failed to update {game} integration, using installed one: {err}");
+
+                if network::is_transport_error(&err) {
+                    network::set_offline();
+                }
+            }
+        }
+    }
 
-    Ok(())
+    Ok(rejected)
 }
@@ -0,0 +1,29 @@
+use crate::config;
+use crate::config::components::Components;
+
+use crate::games;
+
+/// Get all the distinct components settings used by the games editions
+/// 
+/// Global components settings always go first
+pub fn get_used_components() -> anyhow::Result<Vec<Components>> {
+    let config = config::get();
+
+    let mut used = vec![config.components.clone()];
+
+    for game in games::list()?.values() {
+        let settings = config.games.get_game_settings(game)?;
+
+        for (edition, overrides) in &settings.components {
+            if !overrides.is_empty() {
+                let components = settings.get_components(edition, &config.components);
+
+                if !used.contains(&components) {
+                    used.push(components);
+                }
+            }
+        }
+    }
+
+    Ok(used)
+}
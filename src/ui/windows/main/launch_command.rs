@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Game launching command
+/// 
+/// Produces arguments list which is passed to the process
+/// directly, without any shell, so paths and options
+/// can contain spaces and quotes
+/// 
+/// `[env] [wrappers] <wine> [windows command] <executable> [args]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchCommand {
+    /// Commands which run the wine binary, e.g. `gamemoderun`
+    /// 
+    /// Each wrapper is stored with its own arguments
+    pub wrappers: Vec<Vec<String>>,
+
+    /// Path to the wine binary
+    pub wine: PathBuf,
+
+    /// Windows command which runs the game executable,
+    /// e.g. `explorer /desktop=name,1920x1080`
+    pub windows_command: Vec<String>,
+
+    /// Windows path to the game executable
+    pub executable: String,
+
+    /// Game launch arguments
+    pub args: Vec<String>,
+
+    pub env: BTreeMap<String, String>,
+    pub current_dir: Option<PathBuf>
+}
+
+impl LaunchCommand {
+    #[inline]
+    pub fn new(wine: impl Into<PathBuf>, executable: impl ToString) -> Self {
+        Self {
+            wrappers: Vec::new(),
+            wine: wine.into(),
+            windows_command: Vec::new(),
+            executable: executable.to_string(),
+            args: Vec::new(),
+            env: BTreeMap::new(),
+            current_dir: None
+        }
+    }
+
+    /// Add wrapper command. Wrappers are executed in the order they were added
+    pub fn with_wrapper<T: ToString>(mut self, wrapper: impl IntoIterator<Item = T>) -> Self {
+        let wrapper = wrapper.into_iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>();
+
+        if !wrapper.is_empty() {
+            self.wrappers.push(wrapper);
+        }
+
+        self
+    }
+
+    #[inline]
+    pub fn with_windows_command<T: ToString>(mut self, command: impl IntoIterator<Item = T>) -> Self {
+        self.windows_command.extend(command.into_iter().map(|arg| arg.to_string()));
+
+        self
+    }
+
+    #[inline]
+    pub fn with_args<T: ToString>(mut self, args: impl IntoIterator<Item = T>) -> Self {
+        self.args.extend(args.into_iter().map(|arg| arg.to_string()));
+
+        self
+    }
+
+    /// Set environment variables. Already set variables are overwritten
+    #[inline]
+    pub fn with_env<K: ToString, V: ToString>(mut self, env: impl IntoIterator<Item = (K, V)>) -> Self {
+        self.env.extend(env.into_iter().map(|(key, value)| (key.to_string(), value.to_string())));
+
+        self
+    }
+
+    #[inline]
+    pub fn with_current_dir(mut self, current_dir: impl Into<PathBuf>) -> Self {
+        self.current_dir = Some(current_dir.into());
+
+        self
+    }
+
+    /// Get full arguments list of the command
+    pub fn get_argv(&self) -> Vec<String> {
+        let mut argv = self.wrappers.iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        argv.push(self.wine.to_string_lossy().to_string());
+        argv.extend(self.windows_command.iter().cloned());
+        argv.push(self.executable.clone());
+        argv.extend(self.args.iter().cloned());
+
+        argv
+    }
+
+    /// Build process command
+    pub fn get_command(&self) -> Command {
+        let argv = self.get_argv();
+
+        let mut command = Command::new(&argv[0]);
+
+        command.args(&argv[1..]);
+        command.envs(&self.env);
+
+        if let Some(current_dir) = &self.current_dir {
+            command.current_dir(current_dir);
+        }
+
+        command
+    }
+
+    /// Get shell representation of the command which
+    /// can be copied and run in the terminal
+    pub fn to_shell_string(&self) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(current_dir) = &self.current_dir {
+            parts.push(format!("cd {} &&", shell_quote(&current_dir.to_string_lossy())));
+        }
+
+        for (key, value) in &self.env {
+            parts.push(format!("{key}={}", shell_quote(value)));
+        }
+
+        parts.extend(self.get_argv().iter().map(|arg| shell_quote(arg)));
+
+        parts.join(" ")
+    }
+}
+
+/// Quote string for the POSIX shell if needed
+fn shell_quote(value: &str) -> String {
+    let is_safe = !value.is_empty() && value.chars().all(|c| {
+        c.is_ascii_alphanumeric() || "-_=+/.,:@%".contains(c)
+    });
+
+    if is_safe {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn argv() {
+        let command = LaunchCommand::new("/opt/wine builds/bin/wine64", "C:\\Games\\Game Name\\game.exe")
+            .with_wrapper(["gamescope", "-f", "--"])
+            .with_wrapper(Vec::<String>::new())
+            .with_wrapper(["gamemoderun"])
+            .with_windows_command(["explorer", "/desktop=game,1920x1080"])
+            .with_args(["-screen-fullscreen", "it's \"quoted\"", ""]);
+
+        assert_eq!(command.get_argv(), [
+            "gamescope", "-f", "--",
+            "gamemoderun",
+            "/opt/wine builds/bin/wine64",
+            "explorer", "/desktop=game,1920x1080",
+            "C:\\Games\\Game Name\\game.exe",
+            "-screen-fullscreen", "it's \"quoted\"", ""
+        ]);
+    }
+
+    #[test]
+    fn quote() {
+        assert_eq!(shell_quote("wine64"), "wine64");
+        assert_eq!(shell_quote("/desktop=game,1920x1080"), "/desktop=game,1920x1080");
+
+        assert_eq!(shell_quote(""), "''");
+        assert_eq!(shell_quote("Game Name"), "'Game Name'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+        assert_eq!(shell_quote("\"quoted\""), "'\"quoted\"'");
+        assert_eq!(shell_quote("$HOME"), "'$HOME'");
+    }
+
+    #[test]
+    fn shell_string() {
+        let command = LaunchCommand::new("/usr/bin/wine", "game.exe")
+            .with_env([("WINEPREFIX", "/home/user/Wine Prefix")])
+            .with_current_dir("/games/Game Name")
+            .with_args([""]);
+
+        assert_eq!(command.to_shell_string(), "cd '/games/Game Name' && WINEPREFIX='/home/user/Wine Prefix' /usr/bin/wine game.exe ''");
+    }
+}
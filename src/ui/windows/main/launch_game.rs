@@ -1,5 +1,7 @@
 use std::process::Command;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use adw::prelude::*;
 
@@ -8,17 +10,16 @@ use anime_game_core::filesystem::merge::MergeTree;
 use crate::tr;
 
 use crate::games;
+use crate::games::logs;
 use crate::config;
 
 use crate::config::games::settings::prelude::*;
 use crate::games::integrations::standards::prelude::*;
 
+use crate::config::components::Components;
 use crate::components::wine::Wine;
 
-use crate::games::metadata::{
-    LauncherMetadata,
-    GameLastLaunchMetadata
-};
+use crate::games::metadata::LauncherMetadata;
 
 use crate::games::integrations::Game;
 
@@ -30,13 +31,24 @@ use crate::games::integrations::standards::diff::{
 use crate::ui::components::game_card::CardInfo;
 
 use crate::ui::windows::main::WINDOW as MAIN_WINDOW;
+use crate::ui::windows::main::launch_command::LaunchCommand;
 use crate::ui::windows::loading::check_addons::is_addon_enabled;
 
+/// How often launched process state is checked
+const SESSION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often the integration is asked if the game is still running
+/// after the launched process was closed
+const DRIVER_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often running game session is saved to the metadata file
+const SESSION_UPDATE_INTERVAL: Duration = Duration::from_secs(30);
+
 #[inline]
-pub fn addon_unavailable(addon_title: impl AsRef<str>, group_title: impl AsRef<str>) -> anyhow::Result<String> {
+pub fn addon_unavailable(addon_title: &str, group_title: &str) -> anyhow::Result<String> {
     let message = tr!("launch-required-addon-unavailable", {
-        "addon-title" = addon_title.as_ref().to_string(),
-        "group-title" = group_title.as_ref().to_string()
+        "addon-title" = addon_title.to_string(),
+        "group-title" = group_title.to_string()
     });
 
     let (sender, receiver) = std::sync::mpsc::channel();
@@ -68,8 +80,21 @@ pub fn addon_unavailable(addon_title: impl AsRef<str>, group_title: impl AsRef<s
     Ok(receiver.recv()?)
 }
 
+/// Callback used to ask what to do with an enabled addon which
+/// is not installed or outdated
+/// 
+/// Receives addon and group titles and should return
+/// one of `stop`, `disable` or `continue`
+pub type AddonUnavailableCallback = dyn Fn(&str, &str) -> anyhow::Result<String>;
+
 #[inline]
-pub fn prepare_folders(game: &Game, info: &CardInfo, paths: &GameEditionPaths, enabled_addons: &[GameEditionAddon]) -> anyhow::Result<Option<(PathBuf, PathBuf)>> {
+pub fn prepare_folders(
+    game: &Game,
+    info: &CardInfo,
+    paths: &GameEditionPaths,
+    enabled_addons: &[GameEditionAddon],
+    addon_unavailable: &AddonUnavailableCallback
+) -> anyhow::Result<Option<(PathBuf, PathBuf)>> {
     // TODO: move files of disabled addons
 
     // Init game merge tree filesystem
@@ -154,63 +179,164 @@ pub fn prepare_folders(game: &Game, info: &CardInfo, paths: &GameEditionPaths, e
     )))
 }
 
-#[inline]
-pub fn prepare_bash_command(config: &config::Config, wine: &Wine) -> String {
-    let mut bash_command = String::new();
+/// `[gamescope [args] --] [gamemoderun] [user wrappers]`
+pub fn prepare_wrappers(config: &config::Config) -> Vec<Vec<String>> {
+    let mut wrappers = Vec::new();
+
+    if let Some(gamescope) = config.games.enhancements.gamescope.get_command(config.games.enhancements.hud) {
+        wrappers.push(gamescope);
+    }
 
-    // [gamemoderun]
     if config.games.enhancements.gamemode {
-        bash_command = format!("{bash_command} gamemoderun");
+        wrappers.push(vec![String::from("gamemoderun")]);
     }
 
-    // [gamemoderun] '<wine path>'
-    bash_command = format!("{bash_command} '{}'", wine.get_executable().to_string_lossy());
+    wrappers.extend(config.games.enhancements.wrappers.iter().cloned());
 
-    bash_command
+    wrappers
 }
 
+/// `[explorer /desktop=[desktop_name],[width]x[height]]`
 #[inline]
-pub fn prepare_windows_command(config: &config::Config, info: &CardInfo, options: &GameLaunchOptions) -> String {
-    let mut windows_command = String::new();
+pub fn prepare_windows_command(config: &config::Config, info: &CardInfo) -> Vec<String> {
+    config.games.wine.virtual_desktop
+        .get_command(format!("{}_{}", info.get_name(), info.get_edition()))
+        .unwrap_or_default()
+}
 
-    // [explorer /desktop=[desktop_name],[width]x[height]]
-    if let Some(virtual_desktop) = config.games.wine.virtual_desktop.get_command(format!("{}_{}", info.get_name(), info.get_edition())) {
-        windows_command = format!("{windows_command} {virtual_desktop}");
+/// `[-screen-fullscreen 0 -popupwindow]`
+#[inline]
+pub fn prepare_launch_args(config: &config::Config) -> Vec<String> {
+    if config.games.wine.borderless {
+        vec![
+            String::from("-screen-fullscreen"),
+            String::from("0"),
+            String::from("-popupwindow")
+        ]
+    } else {
+        vec![]
     }
+}
 
-    // [explorer /desktop=[desktop_name],[width]x[height]] '<game executable path>'
-    windows_command = format!("{windows_command} '{}'", options.executable);
+/// Build the game launching command
+/// 
+/// Config must have game edition overrides applied
+pub fn prepare_launch_command(
+    config: &config::Config,
+    info: &CardInfo,
+    components: &Components,
+    wine: &Wine,
+    options: GameLaunchOptions,
+    game_path: &Path
+) -> LaunchCommand {
+    let mut command = LaunchCommand::new(wine.get_executable(), &options.executable)
+        .with_windows_command(prepare_windows_command(config, info))
+        .with_args(prepare_launch_args(config))
+        .with_args(options.options)
+        .with_current_dir(game_path);
+
+    for wrapper in prepare_wrappers(config) {
+        command = command.with_wrapper(wrapper);
+    }
 
-    // [explorer /desktop=[desktop_name],[width]x[height]] '<game executable path>' <launch options>
-    windows_command = format!("{windows_command} {}", options.options.join(" "));
+    if !config.games.logs.wine_debug.is_empty() {
+        command = command.with_env([("WINEDEBUG", &config.games.logs.wine_debug)]);
+    }
 
-    windows_command
+    command
+        .with_env([
+            ("WINEARCH", String::from("win64")),
+            ("WINEPREFIX", components.wine.prefix.path.to_string_lossy().to_string())
+        ])
+        .with_env(config.games.enhancements.hud.get_env_vars(config.games.enhancements.gamescope.enabled))
+        .with_env(config.games.enhancements.fsr.get_env_vars())
+        .with_env(config.games.wine.sync.get_env_vars())
+        .with_env(config.games.wine.language.get_env_vars())
+        .with_env(config.games.wine.shared_libraries.get_env_vars(wine.get_folder()))
+        .with_env(&config.games.environment)
+
+        // Setup environment from the lua script
+        .with_env(options.environment)
 }
 
-#[inline]
-pub fn prepare_launch_args(config: &config::Config) -> String {
-    let mut launch_args = String::new();
+/// Get the game launching command without preparing game folders
+/// 
+/// Used to show the command to the user, so layered addons
+/// are not merged and the game's folder is used as is
+pub fn get_launch_command(info: &CardInfo) -> anyhow::Result<LaunchCommand> {
+    let game = unsafe {
+        games::get_unsafe(info.get_name())
+    };
 
-    // [-screen-fullscreen 0 -popupwindow]
-    if config.games.wine.borderless {
-        launch_args = format!("{launch_args} -screen-fullscreen 0 -popupwindow");
+    let mut config = config::get();
+    let settings = config.games.get_game_settings(game)?;
+
+    config.games = settings.apply_overrides(info.get_edition(), &config.games);
+
+    let Some(paths) = settings.paths.get(info.get_edition()) else {
+        anyhow::bail!("Unable to find {} paths", info.get_title());
+    };
+
+    let options = game.driver.get_launch_options(
+        &paths.game.to_string_lossy(),
+        &paths.addons.to_string_lossy(),
+        info.get_edition()
+    )?;
+
+    let components = settings.get_components(info.get_edition(), &config.components);
+    let wine = Wine::from_components(&components)?;
+
+    Ok(prepare_launch_command(&config, info, &components, &wine, options, &paths.game))
+}
+
+/// Run game launch hook using `bash -c`
+/// 
+/// Hook inherits the game's environment and has
+/// `GAME_*` and `WINE` variables exported
+pub fn run_hook(hook: &str, info: &CardInfo, command: &LaunchCommand, addons_path: &Path) -> anyhow::Result<()> {
+    let mut hook_command = Command::new("bash");
+
+    hook_command.arg("-c").arg(hook)
+        .envs(&command.env)
+        .env("GAME_NAME", info.get_name())
+        .env("GAME_EDITION", info.get_edition())
+        .env("GAME_EXECUTABLE", &command.executable)
+        .env("GAME_ADDONS_PATH", addons_path)
+        .env("WINE", &command.wine);
+
+    if let Some(game_path) = &command.current_dir {
+        hook_command.env("GAME_PATH", game_path)
+            .current_dir(game_path);
     }
 
-    launch_args
+    tracing::debug!("Running launch hook: {hook}");
+
+    let output = hook_command.output()?;
+
+    tracing::debug!("Launch hook output: {}", String::from_utf8_lossy(&output.stdout));
+
+    if !output.status.success() {
+        anyhow::bail!("Hook `{hook}` failed ({}): {}", output.status, String::from_utf8_lossy(&output.stderr).trim());
+    }
+
+    Ok(())
 }
 
 #[inline]
-#[tracing::instrument]
-pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
+#[tracing::instrument(skip(addon_unavailable))]
+pub fn launch_game(info: &CardInfo, addon_unavailable: &AddonUnavailableCallback) -> anyhow::Result<()> {
     // Get game driver
     let game = unsafe {
         games::get_unsafe(info.get_name())
     };
 
     // Get game settings
-    let config = config::get();
+    let mut config = config::get();
     let settings = config.games.get_game_settings(game)?;
 
+    // Apply game edition enhancements and environment overrides
+    config.games = settings.apply_overrides(info.get_edition(), &config.games);
+
     // Get game paths
     let Some(paths) = settings.paths.get(info.get_edition()) else {
         anyhow::bail!("Unable to find {} paths", info.get_title());
@@ -222,7 +348,7 @@ pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
     };
 
     // Prepare game and addons folders
-    let Some((game_path, addons_path)) = prepare_folders(game, info, paths, enabled_addons)? else {
+    let Some((game_path, addons_path)) = prepare_folders(game, info, paths, enabled_addons, addon_unavailable)? else {
         return Ok(())
     };
 
@@ -233,70 +359,102 @@ pub fn launch_game(info: &CardInfo) -> anyhow::Result<()> {
         info.get_edition()
     )?;
 
+    // Get components selected for the game edition
+    let components = settings.get_components(info.get_edition(), &config.components);
+
     // Get selected wine version
-    let wine = Wine::from_config()?;
+    let wine = Wine::from_components(&components)?;
 
     // Prepare game launching command
-    let bash_command = prepare_bash_command(&config, &wine);
-    let windows_command = prepare_windows_command(&config, info, &options);
-    let launch_args = prepare_launch_args(&config);
+    let launch_command = prepare_launch_command(&config, info, &components, &wine, options, &game_path);
 
-    let launch_command = format!("{bash_command} {windows_command} {launch_args}");
+    // Get game edition launch hooks
+    let hooks = settings.hooks.get(info.get_edition())
+        .cloned()
+        .unwrap_or_default();
 
-    // Prepare game launcher
-    let mut command = Command::new("bash");
+    // Run pre-launch hook and abort the launch if it failed
+    if let Some(hook) = &hooks.pre_launch {
+        run_hook(hook, info, &launch_command, &addons_path)
+            .map_err(|err| anyhow::anyhow!("Pre-launch hook failed: {err}"))?;
+    }
 
-    // Setup command options
-    command.arg("-c");
-    command.arg(launch_command);
+    let mut command = launch_command.get_command();
 
-    // Setup command environment
-    command.env("WINEARCH", "win64");
-    command.env("WINEPREFIX", config.components.wine.prefix.path);
+    tracing::debug!("{:?}", &command);
 
-    command.envs(config.games.enhancements.hud.get_env_vars(false));
-    command.envs(config.games.enhancements.fsr.get_env_vars());
+    // Write game output to the session log
+    let (log_path, mut log_file) = logs::create_session_log(info.get_name(), info.get_edition(), config.games.logs.keep_sessions)?;
 
-    command.envs(config.games.wine.sync.get_env_vars());
-    command.envs(config.games.wine.language.get_env_vars());
-    command.envs(config.games.wine.shared_libraries.get_env_vars(wine.get_folder()));
+    tracing::info!("Writing game session log to {log_path:?}");
 
-    command.envs(config.games.environment);
+    writeln!(log_file, "{}\n", launch_command.to_shell_string())?;
 
-    // Setup environment from the lua script
-    command.envs(options.environment);
+    command.stdout(log_file.try_clone()?);
+    command.stderr(log_file);
 
-    tracing::debug!("{:?}", &command);
+    // Run the game
+    let mut child = command.spawn()?;
 
-    // Get game starting timestamp
-    let started_at = chrono::Utc::now().timestamp();
+    // Record new game session
+    let mut launcher_metadata = LauncherMetadata::load_for_game(info.get_name(), info.get_edition())?;
 
-    // Run the game
-    command.current_dir(&game_path)
-        .spawn()?
-        .wait()?;
+    let session = launcher_metadata.start_session(Some(wine.name.clone()));
 
-    // Wait while the game is running
-    while game.driver.is_process_running(&game_path.to_string_lossy(), info.get_edition())? {
-        std::thread::sleep(std::time::Duration::from_secs(5));
-    }
+    launcher_metadata.save_for_game(info.get_name(), info.get_edition())?;
 
-    // Get game stopping timestamp
-    let stopped_at = chrono::Utc::now().timestamp();
+    let mut last_update = Instant::now();
 
-    // Get launcher metadata for the game
-    let mut launcher_metadata = LauncherMetadata::load_for_game(info.get_name(), info.get_edition())?;
+    // Save session heartbeat so the playtime is not lost if the launcher crashes
+    let mut update_session = |poll_interval: Duration| {
+        std::thread::sleep(poll_interval);
 
-    // Update metadata values
-    launcher_metadata.launches.last_launch = Some(GameLastLaunchMetadata {
-        started_at,
-        stopped_at
-    });
+        if last_update.elapsed() >= SESSION_UPDATE_INTERVAL {
+            launcher_metadata.update_session(session);
 
-    launcher_metadata.launches.total_playtime += u64::try_from(stopped_at - started_at)?;
+            if let Err(err) = launcher_metadata.save_for_game(info.get_name(), info.get_edition()) {
+                tracing::warn!("Failed to update game session: {err}");
+            }
+
+            last_update = Instant::now();
+        }
+    };
+
+    // Wait until the launched process is closed
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+
+        update_session(SESSION_POLL_INTERVAL);
+    };
+
+    // Wait while the game is running
+    loop {
+        match game.driver.is_process_running(&game_path.to_string_lossy(), info.get_edition()) {
+            Ok(true) => update_session(DRIVER_POLL_INTERVAL),
+            Ok(false) => break,
+
+            // Session must be finished anyway so the playtime is saved
+            Err(err) => {
+                tracing::error!("Failed to check if the game is running: {err}");
+
+                break;
+            }
+        }
+    }
+
+    // Finish game session
+    launcher_metadata.finish_session(session, status.code());
 
-    // Update metadata file
     launcher_metadata.save_for_game(info.get_name(), info.get_edition())?;
 
+    // Run post-exit hook
+    if let Some(hook) = &hooks.post_exit {
+        if let Err(err) = run_hook(hook, info, &launch_command, &addons_path) {
+            tracing::warn!("Post-exit hook failed: {err}");
+        }
+    }
+
     Ok(())
 }
@@ -21,12 +21,18 @@ use crate::components::dxvk::*;
 
 use crate::config::games::settings::edition_addons::GameEditionAddon;
 
+use crate::games::logs;
 use crate::games::metadata::LauncherMetadata;
 use crate::games::integrations::standards::addons::{
     Addon,
     AddonsGroup
 };
 
+use crate::games::integrations::standards::game::{
+    Status,
+    StatusSeverity
+};
+
 use crate::ui::windows::preferences::PreferencesApp;
 
 use crate::ui::windows::about::{
@@ -35,12 +41,18 @@ use crate::ui::windows::about::{
 };
 
 use crate::ui::windows::loading::load_app::LoadingResult;
+use crate::ui::windows::loading::load_tasks::RestoredTask;
 
 use crate::ui::windows::game_addons_manager::{
     GameAddonsManagerApp,
     GameAddonsManagerAppMsg
 };
 
+use crate::ui::windows::game_preferences::{
+    GamePreferencesApp,
+    GamePreferencesAppMsg
+};
+
 use crate::ui::components::game_card::{
     CardInfo,
     CardComponentInput,
@@ -61,13 +73,15 @@ use crate::ui::components::tasks_queue::{
     TasksQueueComponentOutput,
 
     apply_dxvk_task::ApplyDxvkQueuedTask,
-    create_prefix_task::CreatePrefixQueuedTask
+    create_prefix_task::CreatePrefixQueuedTask,
+    saved_tasks
 };
 
 use crate::LAUNCHER_FOLDER;
 use crate::CONFIG_FILE;
 use crate::DEBUG_FILE;
 
+pub mod launch_command;
 pub mod launch_game;
 pub mod kill_game;
 pub mod download_game_task;
@@ -78,6 +92,7 @@ pub mod verify_game_task;
 pub static mut WINDOW: Option<adw::Window> = None;
 pub static mut PREFERENCES_APP: Option<AsyncController<PreferencesApp>> = None;
 pub static mut GAME_ADDONS_MANAGER_APP: Option<AsyncController<GameAddonsManagerApp>> = None;
+pub static mut GAME_PREFERENCES_APP: Option<AsyncController<GamePreferencesApp>> = None;
 pub static mut ABOUT_DIALOG: Option<Controller<AboutDialog>> = None;
 
 pub struct MainApp {
@@ -102,7 +117,13 @@ pub struct MainApp {
     outdated_games_indexes: HashMap<CardInfo, DynamicIndex>,
     available_games_indexes: HashMap<CardInfo, DynamicIndex>,
 
-    tasks_queue: AsyncController<TasksQueueComponent>
+    /// Installed games which status couldn't be checked during loading
+    unknown_status_games: HashSet<CardInfo>,
+
+    tasks_queue: AsyncController<TasksQueueComponent>,
+
+    /// Some remote resources were unreachable during loading
+    offline: bool
 }
 
 #[derive(Debug)]
@@ -119,6 +140,9 @@ pub enum MainAppMsg {
 
     OpenPreferences,
     OpenAddonsManager(CardInfo),
+    OpenGamePreferences(CardInfo),
+    ShowLaunchCommand(CardInfo),
+    OpenLastLog(CardInfo),
 
     SetEnabledAddons {
         game: CardInfo,
@@ -133,6 +157,9 @@ pub enum MainAppMsg {
     AddVerifyGameTask(CardInfo),
     FinishQueuedTask(CardInfo),
 
+    RestoreTask(RestoredTask),
+    DiscardTransitions(Vec<PathBuf>),
+
     AddDownloadAddonTask {
         game_info: CardInfo,
         addon: Addon,
@@ -147,11 +174,17 @@ pub enum MainAppMsg {
 
     AddDownloadWineTask(Wine),
     AddDownloadDxvkTask(Dxvk),
-    AddApplyDxvkTask(Dxvk),
+    AddApplyDxvkTask {
+        dxvk: Dxvk,
+        wine: Wine,
+        prefix_path: PathBuf
+    },
 
     AddCreatePrefixTask {
         path: PathBuf,
-        install_corefonts: bool
+        install_corefonts: bool,
+        wine: Wine,
+        dxvk: Dxvk
     },
 
     LaunchGame(CardInfo),
@@ -229,6 +262,13 @@ impl SimpleAsyncComponent for MainApp {
                             }
                         },
 
+                        adw::Banner {
+                            set_title: &tr!("main-offline-banner"),
+
+                            #[watch]
+                            set_revealed: model.offline
+                        },
+
                         #[local_ref]
                         flap -> adw::Flap {
                             set_fold_policy: adw::FlapFoldPolicy::Always,
@@ -438,6 +478,15 @@ impl SimpleAsyncComponent for MainApp {
                     GameDetailsComponentOutput::OpenAddonsManager(info)
                         => MainAppMsg::OpenAddonsManager(info),
 
+                    GameDetailsComponentOutput::OpenGamePreferences(info)
+                        => MainAppMsg::OpenGamePreferences(info),
+
+                    GameDetailsComponentOutput::ShowLaunchCommand(info)
+                        => MainAppMsg::ShowLaunchCommand(info),
+
+                    GameDetailsComponentOutput::OpenLastLog(info)
+                        => MainAppMsg::OpenLastLog(info),
+
                     GameDetailsComponentOutput::ShowToast { title, message }
                         => MainAppMsg::ShowToast { title, message }
                 }),
@@ -450,6 +499,8 @@ impl SimpleAsyncComponent for MainApp {
             outdated_games_indexes: HashMap::new(),
             available_games_indexes: HashMap::new(),
 
+            unknown_status_games: HashSet::new(),
+
             running_games: FactoryVecDeque::builder()
                 .launch_default()
                 .forward(sender.input_sender(), |output: CardComponentOutput| -> MainAppMsg {
@@ -497,7 +548,7 @@ impl SimpleAsyncComponent for MainApp {
                 }),
 
             tasks_queue: TasksQueueComponent::builder()
-                .launch(CardInfo::default())
+                .launch(())
                 .forward(sender.input_sender(), |output| match output {
                     TasksQueueComponentOutput::TaskFinished(info)
                         => MainAppMsg::FinishQueuedTask(info),
@@ -508,6 +559,8 @@ impl SimpleAsyncComponent for MainApp {
                     TasksQueueComponentOutput::ShowToast { title, message }
                         => MainAppMsg::ShowToast { title, message }
                 }),
+
+            offline: false
         };
 
         let leaflet = &model.leaflet;
@@ -537,6 +590,10 @@ impl SimpleAsyncComponent for MainApp {
                 .launch(widgets.window.clone())
                 .forward(sender.input_sender(), std::convert::identity));
 
+            GAME_PREFERENCES_APP = Some(GamePreferencesApp::builder()
+                .launch(widgets.window.clone())
+                .detach());
+
             ABOUT_DIALOG = Some(AboutDialog::builder()
                 .transient_for(widgets.window.clone())
                 .launch_with_broker((), &about_dialog_broker)
@@ -635,28 +692,66 @@ impl SimpleAsyncComponent for MainApp {
                     );
                 }
 
+                // Games with unknown status are shown as installed
+                // so they still can be launched
+                for game in init.games_list.unknown {
+                    let card = CardInfo::Game {
+                        name: game.game_name.clone(),
+                        title: game.game_title.clone(),
+                        developer: game.game_developer.clone(),
+                        edition: game.edition.name.clone(),
+                        picture_uri: game.card_picture.clone()
+                    };
+
+                    self.unknown_status_games.insert(card.to_owned());
+
+                    self.installed_games_indexes.insert(
+                        card.to_owned(),
+                        self.installed_games.guard().push_back(card.to_owned())
+                    );
+                }
+
                 self.available_games.broadcast(CardComponentInput::SetInstalled(false));
                 self.outdated_games.broadcast(CardComponentInput::SetInstalled(false));
 
-                if let Some(wine) = init.download_wine {
+                self.offline = init.offline;
+
+                // Possibly tampered integrations must not look like network problems
+                for rejected in init.rejected_integrations {
+                    sender.input(MainAppMsg::ShowToast {
+                        title: tr!("integration-verification-failed", {
+                            "game-name" = rejected.game.clone()
+                        }),
+                        message: Some(rejected.reason)
+                    });
+                }
+
+                for wine in init.download_wine {
                     sender.input(MainAppMsg::AddDownloadWineTask(wine));
                     sender.input(MainAppMsg::ShowTasksFlap);
                 }
 
-                if let Some(dxvk) = init.download_dxvk {
+                for dxvk in init.download_dxvk {
                     sender.input(MainAppMsg::AddDownloadDxvkTask(dxvk));
                     sender.input(MainAppMsg::ShowTasksFlap);
                 }
 
-                if let Some(dxvk) = init.apply_dxvk {
-                    sender.input(MainAppMsg::AddApplyDxvkTask(dxvk));
+                for entry in init.apply_dxvk {
+                    sender.input(MainAppMsg::AddApplyDxvkTask {
+                        dxvk: entry.dxvk,
+                        wine: entry.wine,
+                        prefix_path: entry.prefix.path
+                    });
+
                     sender.input(MainAppMsg::ShowTasksFlap);
                 }
 
-                if let Some(prefix) = init.create_prefix {
+                for entry in init.create_prefix {
                     sender.input(MainAppMsg::AddCreatePrefixTask {
-                        path: prefix.path.clone(),
-                        install_corefonts: prefix.install_corefonts
+                        path: entry.prefix.path,
+                        install_corefonts: entry.prefix.install_corefonts,
+                        wine: entry.wine,
+                        dxvk: entry.dxvk
                     });
 
                     sender.input(MainAppMsg::ShowTasksFlap);
@@ -671,6 +766,57 @@ impl SimpleAsyncComponent for MainApp {
 
                     sender.input(MainAppMsg::ShowTasksFlap);
                 }
+
+                let restored = init.restored_tasks;
+
+                // Ask user what to do with the interrupted work before
+                // restoring the queue so the tasks order is kept
+                if restored.has_interrupted() {
+                    let window = unsafe {
+                        WINDOW.as_ref().unwrap_unchecked()
+                    };
+
+                    let dialog = adw::MessageDialog::new(
+                        Some(window),
+                        Some(&tr!("tasks-interrupted")),
+                        Some(&tr!("tasks-interrupted-description"))
+                    );
+
+                    dialog.add_response("discard", &tr!("tasks-interrupted-discard"));
+                    dialog.add_response("resume", &tr!("tasks-interrupted-resume"));
+
+                    dialog.set_response_appearance("discard", adw::ResponseAppearance::Destructive);
+                    dialog.set_response_appearance("resume", adw::ResponseAppearance::Suggested);
+
+                    dialog.set_default_response(Some("resume"));
+                    dialog.set_close_response("resume");
+
+                    let sender = sender.clone();
+
+                    dialog.connect_response(None, move |_, response| {
+                        if response == "discard" {
+                            sender.input(MainAppMsg::DiscardTransitions(restored.transitions.clone()));
+                        }
+
+                        else {
+                            for task in &restored.interrupted {
+                                sender.input(MainAppMsg::RestoreTask(task.clone()));
+                            }
+                        }
+
+                        for task in &restored.queued {
+                            sender.input(MainAppMsg::RestoreTask(task.clone()));
+                        }
+                    });
+
+                    dialog.present();
+                }
+
+                else {
+                    for task in restored.queued {
+                        sender.input(MainAppMsg::RestoreTask(task));
+                    }
+                }
             }
 
             MainAppMsg::OpenDetails { info, installed, running } => {
@@ -705,6 +851,16 @@ impl SimpleAsyncComponent for MainApp {
                             self.game_details.emit(GameDetailsComponentInput::SetStatus(status));
                         }
 
+                        Err(err) if self.unknown_status_games.contains(&info) => {
+                            tracing::warn!("Failed to get {} status: {err}", info.get_title());
+
+                            self.game_details.emit(GameDetailsComponentInput::SetStatus(Some(Status {
+                                allow_launch: true,
+                                severity: StatusSeverity::Warning,
+                                reason: Some(tr!("details-status-unknown"))
+                            })));
+                        }
+
                         Err(err) => {
                             sender.input(MainAppMsg::ShowToast {
                                 title: tr!("game-get-status-failed", {
@@ -757,6 +913,92 @@ impl SimpleAsyncComponent for MainApp {
                 }
             }
 
+            MainAppMsg::OpenGamePreferences(game_info) => unsafe {
+                let controller = GAME_PREFERENCES_APP.as_ref()
+                    .unwrap_unchecked();
+
+                controller.emit(GamePreferencesAppMsg::SetGameInfo(game_info));
+
+                controller.widget().present();
+            }
+
+            MainAppMsg::ShowLaunchCommand(game_info) => {
+                match launch_game::get_launch_command(&game_info) {
+                    Ok(command) => {
+                        let window = unsafe {
+                            WINDOW.as_ref().unwrap_unchecked()
+                        };
+
+                        let command = command.to_shell_string();
+
+                        let dialog = adw::MessageDialog::new(
+                            Some(window),
+                            Some(&tr!("details-launch-command")),
+                            Some(&command)
+                        );
+
+                        dialog.set_body_use_markup(false);
+
+                        dialog.add_response("close", &tr!("dialog-close"));
+                        dialog.add_response("copy", &tr!("dialog-copy"));
+
+                        dialog.set_response_appearance("copy", adw::ResponseAppearance::Suggested);
+
+                        dialog.connect_response(Some("copy"), move |dialog, _| {
+                            dialog.clipboard().set_text(&command);
+                        });
+
+                        dialog.present();
+                    }
+
+                    Err(err) => {
+                        tracing::error!("Failed to get launch command: {err}");
+
+                        sender.input(MainAppMsg::ShowToast {
+                            title: tr!("game-get-launch-command-failed", {
+                                "game-title" = game_info.get_title()
+                            }),
+                            message: Some(err.to_string())
+                        });
+                    }
+                }
+            }
+
+            MainAppMsg::OpenLastLog(game_info) => {
+                match logs::get_last_log(game_info.get_name(), game_info.get_edition()) {
+                    Ok(Some(path)) => {
+                        if let Err(err) = open::that(&path) {
+                            tracing::error!("Failed to open game log: {err}");
+
+                            sender.input(MainAppMsg::ShowToast {
+                                title: tr!("game-open-log-failed", {
+                                    "game-title" = game_info.get_title()
+                                }),
+                                message: Some(err.to_string())
+                            });
+                        }
+                    }
+
+                    Ok(None) => {
+                        sender.input(MainAppMsg::ShowToast {
+                            title: tr!("details-no-logs"),
+                            message: None
+                        });
+                    }
+
+                    Err(err) => {
+                        tracing::error!("Failed to find game logs: {err}");
+
+                        sender.input(MainAppMsg::ShowToast {
+                            title: tr!("game-open-log-failed", {
+                                "game-title" = game_info.get_title()
+                            }),
+                            message: Some(err.to_string())
+                        });
+                    }
+                }
+            }
+
             // FIXME: doesn't look really safe
             MainAppMsg::SetEnabledAddons { game, addons } => {
                 let property = format!("games.settings.{}.addons.{}", game.get_name(), game.get_edition());
@@ -858,6 +1100,31 @@ impl SimpleAsyncComponent for MainApp {
                 }
             }
 
+            MainAppMsg::RestoreTask(task) => {
+                match task {
+                    RestoredTask::DownloadGame(game_info) => sender.input(MainAppMsg::AddDownloadGameTask(game_info)),
+                    RestoredTask::VerifyGame(game_info) => sender.input(MainAppMsg::AddVerifyGameTask(game_info))
+                }
+
+                sender.input(MainAppMsg::ShowTasksFlap);
+            }
+
+            MainAppMsg::DiscardTransitions(paths) => {
+                for path in paths {
+                    let result = std::fs::remove_dir_all(&path)
+                        .and_then(|_| saved_tasks::release_transition(&path));
+
+                    if let Err(err) = result {
+                        tracing::error!("Failed to discard transition {path:?}: {err}");
+
+                        sender.input(MainAppMsg::ShowToast {
+                            title: tr!("tasks-discard-transition-failed"),
+                            message: Some(err.to_string())
+                        });
+                    }
+                }
+            }
+
             MainAppMsg::AddDownloadAddonTask { game_info, addon, group } => {
                 unsafe {
                     GAME_ADDONS_MANAGER_APP.as_ref()
@@ -922,22 +1189,25 @@ impl SimpleAsyncComponent for MainApp {
                 })));
             }
 
-            MainAppMsg::AddApplyDxvkTask(version) => {
+            MainAppMsg::AddApplyDxvkTask { dxvk, wine, prefix_path } => {
                 self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(ApplyDxvkQueuedTask {
                     card_info: CardInfo::Component {
-                        name: version.name.clone(),
-                        title: version.title.clone(),
+                        name: dxvk.name.clone(),
+                        title: dxvk.title.clone(),
                         developer: String::new()
                     },
-                    dxvk_version: version,
-                    prefix_path: config::get().components.wine.prefix.path
+                    dxvk_version: dxvk,
+                    wine_version: wine,
+                    prefix_path
                 })));
             }
 
-            MainAppMsg::AddCreatePrefixTask { path, install_corefonts } => {
+            MainAppMsg::AddCreatePrefixTask { path, install_corefonts, wine, dxvk } => {
                 self.tasks_queue.emit(TasksQueueComponentInput::AddTask(Box::new(CreatePrefixQueuedTask {
                     path,
-                    install_corefonts
+                    install_corefonts,
+                    wine_version: wine,
+                    dxvk_version: dxvk
                 })));
             }
 
@@ -957,7 +1227,7 @@ impl SimpleAsyncComponent for MainApp {
                     }
 
                     std::thread::spawn(move || {
-                        if let Err(err) = launch_game::launch_game(&info) {
+                        if let Err(err) = launch_game::launch_game(&info, &launch_game::addon_unavailable) {
                             sender.input(MainAppMsg::ShowToast {
                                 title: tr!("game-launch-failed", {
                                     "game-title" = info.get_title()
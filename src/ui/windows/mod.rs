@@ -2,4 +2,5 @@ pub mod loading;
 pub mod main;
 pub mod preferences;
 pub mod game_addons_manager;
+pub mod game_preferences;
 pub mod about;
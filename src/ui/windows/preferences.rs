@@ -107,6 +107,22 @@ impl SimpleAsyncComponent for PreferencesApp {
                         }
                     },
 
+                    adw::SwitchRow {
+                        set_title: &tr!("general-sandbox-integrations"),
+                        set_subtitle: &tr!("general-sandbox-integrations-description"),
+
+                        set_active: config::get().games.integrations.sandbox,
+
+                        connect_active_notify[sender] => move |switch| {
+                            if let Err(err) = config::set("games.integrations.sandbox", switch.is_active()) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
                     // adw::ActionRow {
                     //     set_title: "Update games",
                     //     set_subtitle: "Download updates for installed games when they become available",
@@ -126,6 +142,48 @@ impl SimpleAsyncComponent for PreferencesApp {
                     // }
                 },
 
+                add = &adw::PreferencesGroup {
+                    set_title: &tr!("preferences--tasks"),
+
+                    adw::SpinRow {
+                        set_title: &tr!("tasks-max-downloads"),
+                        set_subtitle: &tr!("tasks-max-downloads-description"),
+
+                        set_adjustment: Some(&gtk::Adjustment::new(
+                            config::get().general.tasks.max_downloads as f64,
+                            1.0, 16.0, 1.0, 1.0, 0.0
+                        )),
+
+                        connect_value_notify[sender] => move |row| {
+                            if let Err(err) = config::set("general.tasks.max_downloads", row.value() as u64) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
+                    adw::SpinRow {
+                        set_title: &tr!("tasks-max-disk-tasks"),
+                        set_subtitle: &tr!("tasks-max-disk-tasks-description"),
+
+                        set_adjustment: Some(&gtk::Adjustment::new(
+                            config::get().general.tasks.max_disk_tasks as f64,
+                            1.0, 16.0, 1.0, 1.0, 0.0
+                        )),
+
+                        connect_value_notify[sender] => move |row| {
+                            if let Err(err) = config::set("general.tasks.max_disk_tasks", row.value() as u64) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    }
+                },
+
                 add = &adw::PreferencesGroup {
                     set_title: &tr!("preferences--wine"),
 
@@ -263,6 +321,44 @@ impl SimpleAsyncComponent for PreferencesApp {
                     }
                 },
 
+                add = &adw::PreferencesGroup {
+                    set_title: &tr!("preferences--logs"),
+
+                    adw::EntryRow {
+                        set_title: &tr!("logs-wine-debug"),
+                        set_text: &config::get().games.logs.wine_debug,
+                        set_show_apply_button: true,
+
+                        connect_apply[sender] => move |row| {
+                            if let Err(err) = config::set("games.logs.wine_debug", row.text().trim()) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    },
+
+                    adw::SpinRow {
+                        set_title: &tr!("logs-keep-sessions"),
+                        set_subtitle: &tr!("logs-keep-sessions-description"),
+
+                        set_adjustment: Some(&gtk::Adjustment::new(
+                            config::get().games.logs.keep_sessions as f64,
+                            1.0, 100.0, 1.0, 1.0, 0.0
+                        )),
+
+                        connect_value_notify[sender] => move |row| {
+                            if let Err(err) = config::set("games.logs.keep_sessions", row.value() as u64) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
+                    }
+                },
+
                 add = &adw::PreferencesGroup {
                     set_title: &tr!("preferences--gaming"),
 
@@ -390,6 +486,268 @@ impl SimpleAsyncComponent for PreferencesApp {
                                 })
                             }
                         }
+                    },
+
+                    adw::ExpanderRow {
+                        set_title: &tr!("game-gamescope"),
+                        set_subtitle: &tr!("game-gamescope-description"),
+
+                        add_row = &adw::SwitchRow {
+                            set_title: &tr!("game-gamescope-enabled"),
+
+                            set_active: config::get().games.enhancements.gamescope.enabled,
+
+                            connect_active_notify[sender] => move |switch| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.enabled", switch.is_active()) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("game-gamescope-output-width"),
+
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().games.enhancements.gamescope.output.width as f64,
+                                0.0, 7680.0, 10.0, 1.0, 0.0
+                            )),
+
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.output.width", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("game-gamescope-output-height"),
+
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().games.enhancements.gamescope.output.height as f64,
+                                0.0, 4320.0, 10.0, 1.0, 0.0
+                            )),
+
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.output.height", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("game-gamescope-internal-width"),
+                            set_subtitle: &tr!("game-gamescope-internal-description"),
+
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().games.enhancements.gamescope.internal.width as f64,
+                                0.0, 7680.0, 10.0, 1.0, 0.0
+                            )),
+
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.internal.width", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("game-gamescope-internal-height"),
+                            set_subtitle: &tr!("game-gamescope-internal-description"),
+
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().games.enhancements.gamescope.internal.height as f64,
+                                0.0, 4320.0, 10.0, 1.0, 0.0
+                            )),
+
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.internal.height", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SpinRow {
+                            set_title: &tr!("game-gamescope-refresh-rate"),
+                            set_subtitle: &tr!("game-gamescope-refresh-rate-description"),
+
+                            set_adjustment: Some(&gtk::Adjustment::new(
+                                config::get().games.enhancements.gamescope.refresh_rate as f64,
+                                0.0, 360.0, 1.0, 1.0, 0.0
+                            )),
+
+                            connect_value_notify[sender] => move |row| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.refresh_rate", row.value() as u64) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::ComboRow {
+                            set_title: &tr!("game-gamescope-upscaler"),
+
+                            set_model: Some(&gtk::StringList::new(&GamescopeUpscaler::list()
+                                .iter()
+                                .map(GamescopeUpscaler::name)
+                                .collect::<Vec<_>>())),
+
+                            set_selected: GamescopeUpscaler::list().iter()
+                                .position(|upscaler| upscaler == &config::get().games.enhancements.gamescope.upscaler)
+                                .unwrap_or(0) as u32,
+
+                            connect_selected_notify[sender] => move |row| {
+                                let upscaler = GamescopeUpscaler::list()[row.selected() as usize];
+
+                                let value = serde_json::to_value(upscaler).unwrap();
+
+                                if let Err(err) = config::set("games.enhancements.gamescope.upscaler", value) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::ComboRow {
+                            set_title: &tr!("game-gamescope-filter"),
+
+                            set_model: Some(&gtk::StringList::new(&GamescopeFilter::list()
+                                .iter()
+                                .map(GamescopeFilter::name)
+                                .collect::<Vec<_>>())),
+
+                            set_selected: GamescopeFilter::list().iter()
+                                .position(|filter| filter == &config::get().games.enhancements.gamescope.filter)
+                                .unwrap_or(0) as u32,
+
+                            connect_selected_notify[sender] => move |row| {
+                                let filter = GamescopeFilter::list()[row.selected() as usize];
+
+                                let value = serde_json::to_value(filter).unwrap();
+
+                                if let Err(err) = config::set("games.enhancements.gamescope.filter", value) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::ComboRow {
+                            set_title: &tr!("game-gamescope-window-mode"),
+
+                            set_model: Some(&gtk::StringList::new(&[
+                                &tr!("game-gamescope-window-mode-windowed"),
+                                &tr!("game-gamescope-window-mode-borderless"),
+                                &tr!("game-gamescope-window-mode-fullscreen")
+                            ])),
+
+                            set_selected: match config::get().games.enhancements.gamescope.window_mode {
+                                GamescopeWindowMode::Windowed   => 0,
+                                GamescopeWindowMode::Borderless => 1,
+                                GamescopeWindowMode::Fullscreen => 2
+                            },
+
+                            connect_selected_notify[sender] => move |row| {
+                                let window_mode = [
+                                    GamescopeWindowMode::Windowed,
+                                    GamescopeWindowMode::Borderless,
+                                    GamescopeWindowMode::Fullscreen
+                                ][row.selected() as usize];
+
+                                let value = serde_json::to_value(window_mode).unwrap();
+
+                                if let Err(err) = config::set("games.enhancements.gamescope.window_mode", value) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::SwitchRow {
+                            set_title: &tr!("game-gamescope-hdr"),
+
+                            set_active: config::get().games.enhancements.gamescope.hdr,
+
+                            connect_active_notify[sender] => move |switch| {
+                                if let Err(err) = config::set("games.enhancements.gamescope.hdr", switch.is_active()) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        },
+
+                        add_row = &adw::EntryRow {
+                            set_title: &tr!("game-gamescope-extra-args"),
+                            set_text: &config::get().games.enhancements.gamescope.extra_args.join(" "),
+                            set_show_apply_button: true,
+
+                            connect_apply[sender] => move |row| {
+                                let args = row.text()
+                                    .split_whitespace()
+                                    .map(String::from)
+                                    .collect::<Vec<_>>();
+
+                                if let Err(err) = config::set("games.enhancements.gamescope.extra_args", args) {
+                                    sender.input(PreferencesAppMsg::ShowToast {
+                                        title: tr!("config-property-update-failed"),
+                                        message: Some(err.to_string())
+                                    })
+                                }
+                            }
+                        }
+                    },
+
+                    adw::EntryRow {
+                        set_title: &tr!("game-wrappers"),
+
+                        set_text: &config::get().games.enhancements.wrappers.iter()
+                            .map(|wrapper| wrapper.join(" "))
+                            .collect::<Vec<_>>()
+                            .join("; "),
+
+                        set_show_apply_button: true,
+
+                        connect_apply[sender] => move |row| {
+                            // Wrappers are separated by semicolons, their arguments by spaces
+                            let wrappers = row.text()
+                                .split(';')
+                                .map(|wrapper| wrapper.split_whitespace()
+                                    .map(String::from)
+                                    .collect::<Vec<_>>())
+                                .filter(|wrapper| !wrapper.is_empty())
+                                .collect::<Vec<_>>();
+
+                            if let Err(err) = config::set("games.enhancements.wrappers", serde_json::to_value(wrappers).unwrap()) {
+                                sender.input(PreferencesAppMsg::ShowToast {
+                                    title: tr!("config-property-update-failed"),
+                                    message: Some(err.to_string())
+                                })
+                            }
+                        }
                     }
                 },
 